@@ -38,7 +38,11 @@ impl<A: Aleo> Inject for Value<A> {
     fn new(mode: Mode, value: Self::Primitive) -> Self {
         match value {
             console::Value::Plaintext(plaintext) => Value::Plaintext(Plaintext::new(mode, plaintext)),
-            console::Value::Record(record) => Value::Record(Record::new(Mode::Private, record)),
+            console::Value::Record(record) => {
+                // Note: The record is only copied if it is shared.
+                let record = std::sync::Arc::try_unwrap(record).unwrap_or_else(|record| (*record).clone());
+                Value::Record(Record::new(Mode::Private, record))
+            }
         }
     }
 }
@@ -58,7 +62,7 @@ impl<A: Aleo> Eject for Value<A> {
     fn eject_value(&self) -> Self::Primitive {
         match self {
             Value::Plaintext(plaintext) => console::Value::Plaintext(plaintext.eject_value()),
-            Value::Record(record) => console::Value::Record(record.eject_value()),
+            Value::Record(record) => console::Value::from(record.eject_value()),
         }
     }
 }
@@ -42,7 +42,7 @@ use snarkvm_algorithms::{
 };
 use snarkvm_console_algorithms::{Poseidon2, Poseidon4, BHP1024, BHP512};
 use snarkvm_console_collections::merkle_tree::{MerklePath, MerkleTree};
-use snarkvm_console_types::{Field, Group, Scalar};
+use snarkvm_console_types::{Address, Field, Group, Scalar};
 use snarkvm_curves::PairingEngine;
 
 use indexmap::IndexMap;
@@ -88,6 +88,9 @@ pub trait Network:
     /// The function name for the inclusion circuit.
     const INCLUSION_FUNCTION_NAME: &'static str;
 
+    /// The hash of the canonical genesis block, used to verify the loaded genesis block bytes.
+    /// If `None`, the genesis block is verified against the block hash it was serialized with.
+    const GENESIS_BLOCK_HASH: Option<&'static str> = None;
     /// The fixed timestamp of the genesis block.
     const GENESIS_TIMESTAMP: i64 = 1663718400; // 2022-09-21 00:00:00 UTC
     /// The genesis block coinbase target.
@@ -116,6 +119,10 @@ pub trait Network:
     #[allow(clippy::cast_possible_truncation)]
     const MAX_DATA_SIZE_IN_FIELDS: u32 = ((128 * 1024 * 8) / Field::<Self>::SIZE_IN_DATA_BITS) as u32;
 
+    /// The maximum size of a program string, in bytes.
+    const MAX_PROGRAM_SIZE: usize = 1 << 20; // 1 MiB
+    /// The maximum number of definitions (mappings, structs, records, closures, functions, and globals) in a program.
+    const MAX_PROGRAM_DEFINITIONS: usize = u8::MAX as usize;
     /// The maximum number of functions in a program.
     const MAX_FUNCTIONS: usize = 15;
     /// The maximum number of operands in an instruction.
@@ -125,10 +132,24 @@ pub trait Network:
     /// The maximum number of commands in finalize.
     const MAX_COMMANDS: usize = u8::MAX as usize;
 
+    /// The maximum index of a register, i.e. `r{MAX_REGISTER_INDEX}`.
+    const MAX_REGISTER_INDEX: u64 = u32::MAX as u64;
+
     /// The maximum number of inputs per transition.
     const MAX_INPUTS: usize = 8;
     /// The maximum number of outputs per transition.
     const MAX_OUTPUTS: usize = 8;
+    /// The maximum number of record outputs per transition.
+    /// By default, every output may be a record, so this only bounds a network that overrides it with a smaller value.
+    const MAX_OUTPUT_RECORDS: usize = Self::MAX_OUTPUTS;
+
+    /// If `true`, the `owner` entry of every record must be declared as private.
+    const REQUIRE_PRIVATE_OWNER: bool = true;
+    /// If `true`, a function may not output one of its input registers directly.
+    /// Otherwise, doing so is only reported by the `output-is-input` lint.
+    const STRICT_OUTPUT_REGISTERS: bool = false;
+    /// If `true`, a function must have at least one output, unless it contains an `assert` instruction or a finalize scope.
+    const REQUIRE_OUTPUTS: bool = false;
 
     /// The state root type.
     type StateRoot: Bech32ID<Field<Self>>;
@@ -139,6 +160,96 @@ pub trait Network:
     /// The transition ID type.
     type TransitionID: Bech32ID<Field<Self>>;
 
+    /// Returns the cost weight of the given opcode, used to estimate the complexity of a program.
+    ///
+    /// The default table weighs each opcode relative to a field addition (a weight of 1):
+    ///   - BHP and Pedersen commitments and hashes have a weight of 100.
+    ///   - Poseidon hashes have a weight of 50.
+    ///   - Divisions, inversions, exponentiations, square roots, remainders, and shifts have a weight of 10.
+    ///   - Multiplications, squarings, and comparisons have a weight of 2.
+    ///   - Calls have a weight of 0, as the cost of the callee is accounted for separately.
+    ///   - All other opcodes have a weight of 1.
+    fn opcode_cost(opcode: &'static str) -> u64 {
+        match opcode {
+            "commit.bhp256" | "commit.bhp512" | "commit.bhp768" | "commit.bhp1024" | "commit.ped64"
+            | "commit.ped128" | "hash.bhp256" | "hash.bhp512" | "hash.bhp768" | "hash.bhp1024" | "hash.ped64"
+            | "hash.ped128" => 100,
+            "hash.psd2" | "hash.psd4" | "hash.psd8" => 50,
+            "div" | "div.w" | "inv" | "pow" | "pow.w" | "sqrt" | "mod" | "rem" | "rem.w" | "shl" | "shl.w" | "shr"
+            | "shr.w" => 10,
+            "mul" | "mul.w" | "square" | "gt" | "gte" | "lt" | "lte" => 2,
+            "call" => 0,
+            _ => 1,
+        }
+    }
+
+    /// Returns `true` if the given opcode may be used by programs on the network.
+    /// A network may override this to phase in new opcodes. By default, all opcodes are allowed.
+    fn is_opcode_allowed(_opcode: &str) -> bool {
+        true
+    }
+
+    /// Checks that the constants of the network satisfy their documented invariants.
+    ///
+    /// # Errors
+    /// This method will halt if any of the network constants is inconsistent.
+    fn verify_constants() -> Result<()> {
+        // Ensure the genesis targets are well-formed.
+        ensure!(Self::GENESIS_PROOF_TARGET > 0, "The genesis proof target must be positive");
+        ensure!(
+            Self::GENESIS_PROOF_TARGET <= Self::GENESIS_COINBASE_TARGET,
+            "The genesis proof target must not exceed the genesis coinbase target"
+        );
+        ensure!(Self::STARTING_SUPPLY > 0, "The starting supply must be positive");
+
+        // Ensure the consensus constants are well-formed.
+        ensure!(Self::ANCHOR_TIME > 0, "The anchor time must be positive");
+        ensure!(
+            Self::COINBASE_PUZZLE_DEGREE.checked_add(1).map_or(false, u32::is_power_of_two),
+            "The coinbase puzzle degree must be one less than a power of two"
+        );
+        ensure!(Self::MAX_PROVER_SOLUTIONS > 0, "The maximum number of prover solutions must be positive");
+        ensure!(Self::NUM_BLOCKS_PER_EPOCH > 0, "The number of blocks per epoch must be positive");
+
+        // Ensure the data limits are within range.
+        ensure!(
+            Self::MAX_DATA_DEPTH > 0 && Self::MAX_DATA_DEPTH < u8::MAX as usize,
+            "The maximum data depth must be in the range [1, {})",
+            u8::MAX
+        );
+        ensure!(Self::MAX_DATA_ENTRIES > 0, "The maximum number of data entries must be positive");
+        ensure!(
+            Self::MAX_DATA_SIZE_IN_FIELDS > 0 && Self::MAX_DATA_SIZE_IN_FIELDS <= u16::MAX as u32,
+            "The maximum data size in fields must be in the range [1, {}]",
+            u16::MAX
+        );
+
+        // Ensure the program limits are consistent.
+        ensure!(Self::MAX_PROGRAM_SIZE > 0, "The maximum program size must be positive");
+        ensure!(
+            Self::MAX_FUNCTIONS > 0 && Self::MAX_FUNCTIONS <= Self::MAX_PROGRAM_DEFINITIONS,
+            "The maximum number of functions must be in the range [1, {}]",
+            Self::MAX_PROGRAM_DEFINITIONS
+        );
+        ensure!(Self::MAX_INSTRUCTIONS > 0, "The maximum number of instructions must be positive");
+        ensure!(Self::MAX_COMMANDS > 0, "The maximum number of commands must be positive");
+        ensure!(Self::MAX_OPERANDS > 0, "The maximum number of operands must be positive");
+        ensure!(
+            Self::MAX_REGISTER_INDEX >= Self::MAX_INSTRUCTIONS as u64,
+            "The maximum register index must not be less than the maximum number of instructions"
+        );
+
+        // Ensure the transition limits are consistent.
+        ensure!(Self::MAX_INPUTS > 0, "The maximum number of inputs must be positive");
+        ensure!(Self::MAX_OUTPUTS > 0, "The maximum number of outputs must be positive");
+        ensure!(
+            Self::MAX_OUTPUT_RECORDS <= Self::MAX_OUTPUTS,
+            "The maximum number of output records must not exceed the maximum number of outputs"
+        );
+
+        Ok(())
+    }
+
     /// Returns the genesis block bytes.
     fn genesis_bytes() -> &'static [u8];
 
@@ -154,6 +265,12 @@ pub trait Network:
     /// Returns the `verifying key` for the inclusion circuit.
     fn inclusion_verifying_key() -> &'static Arc<MarlinVerifyingKey<Self>>;
 
+    /// Returns the maximum polynomial degree supported by the universal SRS.
+    /// By default, this is the degree supported by the powers of the universal SRS, which are shared across networks.
+    fn max_srs_degree() -> usize {
+        snarkvm_parameters::testnet3::MAX_NUM_POWERS - 1
+    }
+
     /// Returns the powers of `G`.
     fn g_powers() -> &'static Vec<Group<Self>>;
 
@@ -253,6 +370,14 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
     fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>>;
 
+    /// Returns the program address for the given program ID, as `HashToGroup(program_id)`,
+    /// where the program ID is given as its list of field elements.
+    ///
+    /// As the program address is derived deterministically, a program may own records without a private key.
+    fn program_address(program_id: &[Field<Self>]) -> Result<Address<Self>> {
+        Ok(Address::new(Self::hash_to_group_psd4(program_id)?))
+    }
+
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>>;
 
@@ -131,6 +131,9 @@ impl Network for Testnet3 {
 
     /// The network edition.
     const EDITION: u16 = 0;
+    /// The hash of the canonical genesis block.
+    const GENESIS_BLOCK_HASH: Option<&'static str> =
+        Some("ab1pfhf6r4e2cv3v9scmkgs8nrp3gfk2rs38rgl409p3ma9wkaprvzqpwlgpz");
     /// The network ID.
     const ID: u16 = 3;
     /// The function name for the inclusion circuit.
@@ -385,9 +388,15 @@ impl Network for Testnet3 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use snarkvm_algorithms::snark::marlin::UniversalSRS;
 
     type CurrentNetwork = Testnet3;
 
+    #[test]
+    fn test_verify_constants() {
+        assert!(CurrentNetwork::verify_constants().is_ok());
+    }
+
     #[test]
     fn test_g_scalar_multiply() {
         // Compute G^r.
@@ -395,4 +404,15 @@ mod tests {
         let group = CurrentNetwork::g_scalar_multiply(&scalar);
         assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
     }
+
+    #[test]
+    fn test_max_srs_degree() {
+        // Ensure the maximum degree is positive.
+        let max_degree = CurrentNetwork::max_srs_degree();
+        assert!(max_degree > 0);
+
+        // Ensure the maximum degree matches the loaded universal SRS.
+        let universal_srs = UniversalSRS::<<CurrentNetwork as Environment>::PairingCurve>::load().unwrap();
+        assert_eq!(max_degree, universal_srs.max_degree());
+    }
 }
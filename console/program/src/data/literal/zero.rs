@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Literal<N> {
+    /// Returns the zero literal of the given literal type, i.e. `0` for numeric types,
+    /// `false` for booleans, the zero group element for addresses, and the empty string.
+    pub fn zero(literal_type: LiteralType) -> Self {
+        match literal_type {
+            LiteralType::Address => Literal::Address(Address::new(Group::zero())),
+            LiteralType::Boolean => Literal::Boolean(Boolean::new(false)),
+            LiteralType::Field => Literal::Field(Field::zero()),
+            LiteralType::Group => Literal::Group(Group::zero()),
+            LiteralType::I8 => Literal::I8(I8::zero()),
+            LiteralType::I16 => Literal::I16(I16::zero()),
+            LiteralType::I32 => Literal::I32(I32::zero()),
+            LiteralType::I64 => Literal::I64(I64::zero()),
+            LiteralType::I128 => Literal::I128(I128::zero()),
+            LiteralType::U8 => Literal::U8(U8::zero()),
+            LiteralType::U16 => Literal::U16(U16::zero()),
+            LiteralType::U32 => Literal::U32(U32::zero()),
+            LiteralType::U64 => Literal::U64(U64::zero()),
+            LiteralType::U128 => Literal::U128(U128::zero()),
+            LiteralType::Scalar => Literal::Scalar(Scalar::zero()),
+            LiteralType::String => Literal::String(StringType::new("")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_zero() -> Result<()> {
+        // Ensure the zero literal has the given literal type.
+        for literal_type in [LiteralType::Address, LiteralType::Boolean, LiteralType::Group, LiteralType::String] {
+            assert_eq!(Literal::<CurrentNetwork>::zero(literal_type).to_type(), literal_type);
+        }
+        // Ensure the zero literals are as expected.
+        assert_eq!(Literal::<CurrentNetwork>::zero(LiteralType::Boolean), Literal::from_str("false")?);
+        assert_eq!(Literal::<CurrentNetwork>::zero(LiteralType::Field), Literal::from_str("0field")?);
+        assert_eq!(Literal::<CurrentNetwork>::zero(LiteralType::I8), Literal::from_str("0i8")?);
+        assert_eq!(Literal::<CurrentNetwork>::zero(LiteralType::U128), Literal::from_str("0u128")?);
+        assert_eq!(Literal::<CurrentNetwork>::zero(LiteralType::Scalar), Literal::from_str("0scalar")?);
+        assert_eq!(Literal::<CurrentNetwork>::zero(LiteralType::String), Literal::from_str("\"\"")?);
+        Ok(())
+    }
+}
@@ -65,4 +65,72 @@ impl<N: Network> Plaintext<N> {
             }
         }
     }
+
+    /// Returns the plaintext member from the given dotted path (e.g. `first.second`).
+    ///
+    /// # Errors
+    /// This method will halt if the path is empty, or if a segment is not a valid identifier.
+    /// This method will halt if a segment does not exist, or if it descends into a literal.
+    pub fn get_by_path(&self, path: &str) -> Result<Plaintext<N>> {
+        // Ensure the path is not empty.
+        ensure!(!path.is_empty(), "Attempted to find member with an empty path.");
+
+        // Initialize the output, starting from the top-level.
+        let mut output = self;
+        // Descend through each segment of the path.
+        for segment in path.split('.') {
+            // Parse the segment as an identifier.
+            let identifier = match Identifier::<N>::from_str(segment) {
+                Ok(identifier) => identifier,
+                Err(error) => bail!("Invalid segment '{segment}' in path '{path}': {error}"),
+            };
+            // Retrieve the member for the segment.
+            output = match output {
+                // Halts if the value is not a struct.
+                Self::Literal(..) => {
+                    bail!("Failed to locate member '{segment}' in path '{path}': '{output}' is not a struct")
+                }
+                // Retrieve the member (from the struct).
+                Self::Struct(members, ..) => match members.get(&identifier) {
+                    Some(member) => member,
+                    // Halts if the member does not exist.
+                    None => bail!("Failed to locate member '{segment}' in path '{path}'"),
+                },
+            };
+        }
+        // Return the output.
+        Ok(output.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_get_by_path() -> Result<()> {
+        let value = Plaintext::<CurrentNetwork>::from_str("{ first: 2field, second: 3field }")?;
+        assert_eq!(value.get_by_path("first")?, Plaintext::from_str("2field")?);
+        assert_eq!(value.get_by_path("second")?, Plaintext::from_str("3field")?);
+
+        // Ensure nested members are resolved.
+        let value = Plaintext::<CurrentNetwork>::from_str("{ inner: { first: 2field, second: 3field }, flag: true }")?;
+        assert_eq!(value.get_by_path("inner.second")?, Plaintext::from_str("3field")?);
+        assert_eq!(value.get_by_path("inner")?, Plaintext::from_str("{ first: 2field, second: 3field }")?);
+
+        // Ensure a miss reports the failing segment.
+        let error = value.get_by_path("inner.third").unwrap_err().to_string();
+        assert!(error.contains("'third'"), "{error}");
+        let error = value.get_by_path("flag.first").unwrap_err().to_string();
+        assert!(error.contains("'first'"), "{error}");
+
+        // Ensure malformed paths are rejected.
+        assert!(value.get_by_path("").is_err());
+        assert!(value.get_by_path("inner..first").is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_str("2field")?.get_by_path("first").is_err());
+        Ok(())
+    }
 }
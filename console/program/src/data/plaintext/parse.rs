@@ -168,6 +168,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_negative_literal() -> Result<()> {
+        // Ensure a negative signed integer round-trips with its sign.
+        let candidate = Plaintext::<CurrentNetwork>::from_str("-5i8")?;
+        assert_eq!(Plaintext::from(Literal::I8(I8::new(-5))), candidate);
+        assert_eq!("-5i8", candidate.to_string());
+
+        // Ensure the minimum signed integer is accepted.
+        let expected = format!("{}i8", i8::MIN);
+        let candidate = Plaintext::<CurrentNetwork>::from_str(&expected)?;
+        assert_eq!(Plaintext::from(Literal::I8(I8::new(i8::MIN))), candidate);
+        assert_eq!(expected, candidate.to_string());
+
+        // Ensure a value below the minimum signed integer is rejected.
+        assert!(Plaintext::<CurrentNetwork>::from_str("-129i8").is_err());
+        // Ensure a negative unsigned integer is rejected.
+        assert!(Plaintext::<CurrentNetwork>::from_str("-1u8").is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_str("-5u32").is_err());
+
+        // Ensure a negative field element is the additive inverse of its absolute value.
+        let candidate = Plaintext::<CurrentNetwork>::from_str("-1field")?;
+        assert_eq!(Plaintext::from(Literal::Field(-Field::one())), candidate);
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_struct() -> Result<()> {
         // Sanity check.
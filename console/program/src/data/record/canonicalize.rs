@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns this record with its entries in canonical order.
+    ///
+    /// The canonical order of the record entries is their declaration order in the record type.
+    /// As the record commitment is computed over the entries in order, two records with identical
+    /// entries in a different order only commit to the same value once they are in canonical order.
+    ///
+    /// # Errors
+    /// This method will halt if the record entries do not match the entries declared in the record type.
+    pub fn to_canonical(&self, record_type: &RecordType<N>) -> Result<Self> {
+        // Ensure the number of record entries matches the record type.
+        ensure!(
+            self.data.len() == record_type.entries().len(),
+            "'{}' expected {} entries, found {} entries",
+            record_type.name(),
+            record_type.entries().len(),
+            self.data.len()
+        );
+
+        // Reorder the record entries in the declaration order of the record type.
+        let data = record_type
+            .entries()
+            .keys()
+            .map(|name| match self.data.get(name) {
+                Some(entry) => Ok((*name, entry.clone())),
+                None => bail!("Record is missing entry '{name}' of '{}'", record_type.name()),
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+
+        // Return the canonical record.
+        Ok(Self { owner: self.owner.clone(), gates: self.gates.clone(), data, nonce: self.nonce })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const OWNER: &str = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+
+    #[test]
+    fn test_to_canonical() -> Result<()> {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+        let record_type = RecordType::<CurrentNetwork>::from_str(
+            "record token:\n    owner as address.private;\n    gates as u64.private;\n    amount as u64.private;\n    flag as boolean.public;",
+        )?;
+
+        // Initialize two records with identical entries, in a different order.
+        let first = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {OWNER}.private, gates: 5u64.private, amount: 100u64.private, flag: true.public, _nonce: 0group.public }}"
+        ))?;
+        let second = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {OWNER}.private, gates: 5u64.private, flag: true.public, amount: 100u64.private, _nonce: 0group.public }}"
+        ))?;
+
+        // Ensure the entries are in declaration order once canonical.
+        let first = first.to_canonical(&record_type)?;
+        let second = second.to_canonical(&record_type)?;
+        assert!(first.data().keys().eq(record_type.entries().keys()));
+        assert!(second.data().keys().eq(record_type.entries().keys()));
+
+        // Ensure both records commit to the same value.
+        assert_eq!(
+            first.to_commitment(&program_id, record_type.name())?,
+            second.to_commitment(&program_id, record_type.name())?
+        );
+
+        // Ensure a record with a missing entry is rejected.
+        let missing = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {OWNER}.private, gates: 5u64.private, amount: 100u64.private, other: true.public, _nonce: 0group.public }}"
+        ))?;
+        assert!(missing.to_canonical(&record_type).is_err());
+        Ok(())
+    }
+}
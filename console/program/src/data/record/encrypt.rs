@@ -30,6 +30,22 @@ impl<N: Network> Record<N, Plaintext<N>> {
         }
     }
 
+    /// Transfers `self` to the given owner, returning the re-owned record and its encryption under the given randomizer.
+    /// The visibility of the owner, and the gates and entries of the record, are preserved.
+    pub fn reencrypt(&self, new_owner: &Address<N>, randomizer: Scalar<N>) -> Result<(Self, Record<N, Ciphertext<N>>)> {
+        // Construct the owner, with the same visibility as the current owner.
+        let owner = match self.owner.is_public() {
+            true => Owner::Public(*new_owner),
+            false => Owner::Private(Plaintext::from(Literal::Address(*new_owner))),
+        };
+        // Construct the record, with a nonce corresponding to the randomizer.
+        let record =
+            Self::from_plaintext(owner, self.gates.clone(), self.data.clone(), N::g_scalar_multiply(&randomizer))?;
+        // Encrypt the record for the new owner.
+        let ciphertext = record.encrypt(randomizer)?;
+        Ok((record, ciphertext))
+    }
+
     /// Encrypts `self` under the given record view key.
     pub fn encrypt_symmetric(&self, record_view_key: &Field<N>) -> Result<Record<N, Ciphertext<N>>> {
         // Determine the number of randomizers needed to encrypt the record.
@@ -102,3 +118,53 @@ impl<N: Network> Record<N, Plaintext<N>> {
         Self::from_ciphertext(owner, gates, encrypted_data, self.nonce)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_reencrypt() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the current owner and the new owner.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(&private_key)?;
+            let new_private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let new_view_key = ViewKey::try_from(&new_private_key)?;
+            let new_address = Address::try_from(&new_private_key)?;
+
+            // Prepare a record owned by the current owner.
+            let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+                Owner::Private(Plaintext::from(Literal::Address(address))),
+                Balance::Private(Plaintext::from(Literal::U64(U64::new(u64::rand(&mut rng) >> 12)))),
+                IndexMap::from([
+                    (
+                        Identifier::from_str("a")?,
+                        Entry::Private(Plaintext::from(Literal::Field(Field::rand(&mut rng)))),
+                    ),
+                    (Identifier::from_str("b")?, Entry::Public(Plaintext::from(Literal::Field(Field::rand(&mut rng))))),
+                ]),
+                CurrentNetwork::g_scalar_multiply(&Scalar::rand(&mut rng)),
+            )?;
+
+            // Re-encrypt the record to the new owner.
+            let (reencrypted, ciphertext) = record.reencrypt(&new_address, Scalar::rand(&mut rng))?;
+            assert_eq!(reencrypted.owner(), &Owner::Private(Plaintext::from(Literal::Address(new_address))));
+            assert_eq!(reencrypted.gates(), record.gates());
+            assert_eq!(reencrypted.data(), record.data());
+
+            // Ensure the new owner can decrypt the record.
+            assert!(ciphertext.is_owner(&new_view_key));
+            assert!(!ciphertext.is_owner(&ViewKey::try_from(&private_key)?));
+            assert_eq!(reencrypted, ciphertext.decrypt(&new_view_key)?);
+        }
+        Ok(())
+    }
+}
@@ -21,6 +21,7 @@ mod helpers;
 pub use helpers::{Balance, Owner};
 
 mod bytes;
+mod canonicalize;
 mod decrypt;
 mod encrypt;
 mod equal;
@@ -36,7 +37,7 @@ mod to_bits;
 mod to_commitment;
 mod to_fields;
 
-use crate::{Ciphertext, Identifier, Literal, Plaintext, ProgramID};
+use crate::{Ciphertext, Identifier, Literal, Plaintext, ProgramID, RecordType};
 use snarkvm_console_account::{Address, PrivateKey, ViewKey};
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Boolean, Field, Group, Scalar, U64};
@@ -112,6 +113,20 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
     }
 }
 
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the address of the owner of the program record.
+    ///
+    /// # Errors
+    /// This method returns an error, rather than halting, if the owner of the program record is not an address.
+    pub fn owner_address(&self) -> Result<Address<N>> {
+        match &self.owner {
+            Owner::Public(address) => Ok(*address),
+            Owner::Private(Plaintext::Literal(Literal::Address(address), ..)) => Ok(*address),
+            Owner::Private(plaintext) => bail!("Expected the record owner to be an address, found '{plaintext}'"),
+        }
+    }
+}
+
 impl<N: Network, Private: Visibility> Record<N, Private> {
     /// Returns the owner of the program record, and consumes `self`.
     pub fn into_owner(self) -> Owner<N, Private> {
@@ -133,3 +148,46 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         self.nonce
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_owner_address() -> Result<()> {
+        let expected =
+            Address::<CurrentNetwork>::from_str("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah")?;
+
+        // Initialize the `token` record used across the record tests, and ensure it is a `token` record.
+        let token = RecordType::<CurrentNetwork>::from_str(
+            "record token:\n    owner as address.private;\n    gates as u64.private;\n    token_amount as u64.private;",
+        )?;
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 5u64.private, token_amount: 100u64.private, _nonce: 0group.public }",
+        )?;
+        assert_eq!(record, record.to_canonical(&token)?);
+
+        // Ensure the address of its private owner is recovered.
+        assert_eq!(expected, record.owner_address()?);
+
+        // Ensure the address of a public owner is recovered.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {expected}.public, gates: 5u64.private, token_amount: 100u64.private, _nonce: 0group.public }}"
+        ))?;
+        assert_eq!(expected, record.owner_address()?);
+
+        // Ensure an owner that is not an address returns an error.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+            Owner::Private(Plaintext::from_str("5u64")?),
+            Balance::Public(U64::new(5)),
+            IndexMap::new(),
+            Group::zero(),
+        )?;
+        let error = record.owner_address().unwrap_err();
+        assert_eq!(error.to_string(), "Expected the record owner to be an address, found '5u64'");
+        Ok(())
+    }
+}
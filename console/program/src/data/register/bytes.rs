@@ -21,6 +21,10 @@ impl<N: Network> FromBytes for Register<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let variant = u8::read_le(&mut reader)?;
         let locator = read_variable_length_integer(&mut reader)?;
+        // Ensure the locator is within `N::MAX_REGISTER_INDEX`.
+        if locator > N::MAX_REGISTER_INDEX {
+            return Err(error(format!("Register 'r{locator}' exceeds the maximum register index")));
+        }
         match variant {
             0 => Ok(Self::Locator(locator)),
             1 => {
@@ -23,9 +23,15 @@ impl<N: Network> Parser for Register<N> {
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the register character from the string.
         let (string, _) = tag("r")(string)?;
-        // Parse the locator from the string.
+        // Parse the locator from the string, ensuring it is within `N::MAX_REGISTER_INDEX`.
         let (string, locator) =
-            map_res(recognize(many1(one_of("0123456789"))), |locator: &str| locator.parse::<u64>())(string)?;
+            map_res(recognize(many1(one_of("0123456789"))), |locator: &str| match locator.parse::<u64>() {
+                Ok(locator) if locator <= N::MAX_REGISTER_INDEX => Ok(locator),
+                _ => Err(error(format!(
+                    "Register 'r{locator}' exceeds the maximum register index ({})",
+                    N::MAX_REGISTER_INDEX
+                ))),
+            })(string)?;
         // Parse the identifier from the string, if it is a register member.
         let (string, identifiers): (&str, Vec<Identifier<N>>) =
             map_res(many0(pair(tag("."), Identifier::parse)), |identifiers| {
@@ -94,6 +100,31 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
+    #[test]
+    fn test_register_max_index() -> Result<()> {
+        let max = CurrentNetwork::MAX_REGISTER_INDEX;
+
+        // Ensure a register at the maximum index parses.
+        assert_eq!(Register::<CurrentNetwork>::Locator(max), Register::from_str(&format!("r{max}"))?);
+        assert_eq!(
+            Register::<CurrentNetwork>::Member(max, vec![Identifier::from_str("owner")?]),
+            Register::from_str(&format!("r{max}.owner"))?
+        );
+
+        // Ensure a register over the maximum index is rejected.
+        assert!(Register::<CurrentNetwork>::parse(&format!("r{}", max + 1)).is_err());
+        assert!(Register::<CurrentNetwork>::parse(&format!("r{}.owner", max + 1)).is_err());
+        assert!(Register::<CurrentNetwork>::parse("r4294967296").is_err());
+        // Ensure a register that overflows a `u64` is rejected, rather than wrapping or panicking.
+        assert!(Register::<CurrentNetwork>::parse("r18446744073709551616").is_err());
+        assert!(Register::<CurrentNetwork>::parse(&format!("r{}", "9".repeat(100))).is_err());
+
+        // Ensure a register over the maximum index cannot be read from bytes.
+        let bytes = Register::<CurrentNetwork>::Locator(max + 1).to_bytes_le()?;
+        assert!(Register::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_register_display() -> Result<()> {
         // Register::Locator
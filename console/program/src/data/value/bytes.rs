@@ -24,7 +24,7 @@ impl<N: Network> FromBytes for Value<N> {
         // Read the entry.
         let entry = match index {
             0 => Self::Plaintext(Plaintext::read_le(&mut reader)?),
-            1 => Self::Record(Record::read_le(&mut reader)?),
+            1 => Self::from(Record::<N, Plaintext<N>>::read_le(&mut reader)?),
             2.. => return Err(error(format!("Failed to decode value variant {index}"))),
         };
         Ok(entry)
@@ -71,7 +71,7 @@ mod tests {
     #[test]
     fn test_value_record_bytes() -> Result<()> {
         // Construct a new record value.
-        let expected = Value::Record(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+        let expected = Value::from(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
             "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 5u64.private, token_amount: 100u64.private, _nonce: 0group.public }",
         )?);
 
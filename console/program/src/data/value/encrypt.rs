@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Encrypts `self` to the given address under the given randomizer.
+    ///
+    /// Note: A record value is encrypted under its own nonce, using `Record::encrypt` instead.
+    pub fn encrypt(&self, address: &Address<N>, randomizer: Scalar<N>) -> Result<Ciphertext<N>> {
+        match self {
+            Self::Plaintext(plaintext) => plaintext.encrypt(address, randomizer),
+            Self::Record(..) => bail!("Cannot encrypt a record value to an address, use 'Record::encrypt' instead"),
+        }
+    }
+
+    /// Decrypts the given ciphertext into a value using the given account view key & nonce,
+    /// where the nonce is the randomizer (used to encrypt the value) multiplied by the generator.
+    pub fn decrypt(ciphertext: &Ciphertext<N>, view_key: &ViewKey<N>, nonce: Group<N>) -> Result<Self> {
+        Ok(Self::Plaintext(ciphertext.decrypt(*view_key, nonce)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_encrypt_and_decrypt() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let view_key = ViewKey::try_from(private_key)?;
+            let address = Address::try_from(view_key)?;
+
+            // Prepare a private field value.
+            let value = Value::<CurrentNetwork>::from_str(&format!("{}", Field::<CurrentNetwork>::rand(&mut rng)))?;
+
+            // Encrypt the value.
+            let randomizer = Uniform::rand(&mut rng);
+            let ciphertext = value.encrypt(&address, randomizer)?;
+
+            // Decrypt the value.
+            let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+            assert_eq!(value, Value::decrypt(&ciphertext, &view_key, nonce)?);
+
+            // Ensure a record value is not encrypted to an address.
+            let record = Value::<CurrentNetwork>::from_str(&format!(
+                "{{ owner: {address}.private, gates: 5u64.private, _nonce: {}.public }}",
+                CurrentNetwork::g_scalar_multiply(&randomizer)
+            ))?;
+            assert!(record.encrypt(&address, randomizer).is_err());
+        }
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod encrypt;
 mod equal;
 mod find;
 mod parse;
@@ -22,16 +23,34 @@ mod serialize;
 mod to_bits;
 mod to_fields;
 
-use crate::{Entry, Identifier, Plaintext, Record};
+use crate::{Ciphertext, Entry, Identifier, Plaintext, Record};
+use snarkvm_console_account::ViewKey;
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
+use std::sync::Arc;
+
 #[derive(Clone)]
 pub enum Value<N: Network> {
     /// A plaintext value.
     Plaintext(Plaintext<N>),
     /// A record value.
-    Record(Record<N, Plaintext<N>>),
+    /// Note: The record is shared, so that cloning the value (e.g. when loading a register) does not copy the record.
+    Record(Arc<Record<N, Plaintext<N>>>),
+}
+
+impl<N: Network> From<Plaintext<N>> for Value<N> {
+    /// Initializes a value from a plaintext.
+    fn from(plaintext: Plaintext<N>) -> Self {
+        Self::Plaintext(plaintext)
+    }
+}
+
+impl<N: Network> From<Record<N, Plaintext<N>>> for Value<N> {
+    /// Initializes a value from a record.
+    fn from(record: Record<N, Plaintext<N>>) -> Self {
+        Self::Record(Arc::new(record))
+    }
 }
 
 impl<N: Network> From<&Value<N>> for Value<N> {
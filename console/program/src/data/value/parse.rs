@@ -20,7 +20,7 @@ impl<N: Network> Parser for Value<N> {
     /// Parses a string into a value.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
-        alt((map(Plaintext::parse, Value::Plaintext), map(Record::parse, Value::Record)))(string)
+        alt((map(Plaintext::parse, Value::Plaintext), map(Record::<N, Plaintext<N>>::parse, Value::from)))(string)
     }
 }
 
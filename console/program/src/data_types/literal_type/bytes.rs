@@ -16,15 +16,141 @@
 
 use super::*;
 
+impl LiteralType {
+    /// Returns the on-wire tag of the literal type.
+    ///
+    /// Note: The tag assignment is frozen, and must never change, as it is part of the byte encoding.
+    pub const fn to_tag(&self) -> u8 {
+        match self {
+            Self::Address => 0,
+            Self::Boolean => 1,
+            Self::Field => 2,
+            Self::Group => 3,
+            Self::I8 => 4,
+            Self::I16 => 5,
+            Self::I32 => 6,
+            Self::I64 => 7,
+            Self::I128 => 8,
+            Self::U8 => 9,
+            Self::U16 => 10,
+            Self::U32 => 11,
+            Self::U64 => 12,
+            Self::U128 => 13,
+            Self::Scalar => 14,
+            Self::String => 15,
+        }
+    }
+
+    /// Returns the literal type with the given on-wire tag, or `None` if the tag is unknown.
+    pub const fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(Self::Address),
+            1 => Some(Self::Boolean),
+            2 => Some(Self::Field),
+            3 => Some(Self::Group),
+            4 => Some(Self::I8),
+            5 => Some(Self::I16),
+            6 => Some(Self::I32),
+            7 => Some(Self::I64),
+            8 => Some(Self::I128),
+            9 => Some(Self::U8),
+            10 => Some(Self::U16),
+            11 => Some(Self::U32),
+            12 => Some(Self::U64),
+            13 => Some(Self::U128),
+            14 => Some(Self::Scalar),
+            15 => Some(Self::String),
+            _ => None,
+        }
+    }
+}
+
 impl FromBytes for LiteralType {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let index = u16::read_le(&mut reader)?;
-        FromPrimitive::from_u16(index).ok_or_else(|| error("Failed to deserialize literal type variant {index}"))
+        u8::try_from(index)
+            .ok()
+            .and_then(Self::from_tag)
+            .ok_or_else(|| error(format!("Failed to deserialize literal type variant {index}")))
     }
 }
 
 impl ToBytes for LiteralType {
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        (*self as u16).write_le(&mut writer)
+        u16::from(self.to_tag()).write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The literal types, in the order of their tags.
+    const LITERAL_TYPES: [LiteralType; 16] = [
+        LiteralType::Address,
+        LiteralType::Boolean,
+        LiteralType::Field,
+        LiteralType::Group,
+        LiteralType::I8,
+        LiteralType::I16,
+        LiteralType::I32,
+        LiteralType::I64,
+        LiteralType::I128,
+        LiteralType::U8,
+        LiteralType::U16,
+        LiteralType::U32,
+        LiteralType::U64,
+        LiteralType::U128,
+        LiteralType::Scalar,
+        LiteralType::String,
+    ];
+
+    #[test]
+    fn test_tag() {
+        // Ensure the tags match the golden vector.
+        // Note: If this test fails, the tag assignment has changed, which breaks the byte encoding.
+        let expected = [
+            ("address", 0),
+            ("boolean", 1),
+            ("field", 2),
+            ("group", 3),
+            ("i8", 4),
+            ("i16", 5),
+            ("i32", 6),
+            ("i64", 7),
+            ("i128", 8),
+            ("u8", 9),
+            ("u16", 10),
+            ("u32", 11),
+            ("u64", 12),
+            ("u128", 13),
+            ("scalar", 14),
+            ("string", 15),
+        ];
+        let candidate = LITERAL_TYPES.iter().map(|literal_type| (literal_type.type_name(), literal_type.to_tag()));
+        assert!(candidate.eq(expected));
+
+        // Ensure each literal type round-trips through its tag.
+        for literal_type in LITERAL_TYPES {
+            assert_eq!(Some(literal_type), LiteralType::from_tag(literal_type.to_tag()));
+        }
+        // Ensure unknown tags are rejected.
+        for tag in 16..=u8::MAX {
+            assert_eq!(None, LiteralType::from_tag(tag));
+        }
+    }
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for literal_type in LITERAL_TYPES {
+            // Ensure the byte encoding is the tag, as a `u16`.
+            let bytes = literal_type.to_bytes_le()?;
+            assert_eq!(bytes, u16::from(literal_type.to_tag()).to_le_bytes());
+            assert_eq!(literal_type, LiteralType::from_bytes_le(&bytes)?);
+        }
+        // Ensure unknown variants are rejected.
+        assert!(LiteralType::from_bytes_le(&16u16.to_le_bytes()).is_err());
+        assert!(LiteralType::from_bytes_le(&256u16.to_le_bytes()).is_err());
+        Ok(())
     }
 }
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl LiteralType {
+    /// Returns `true` if the literal type is an integer type.
+    pub const fn is_integer(&self) -> bool {
+        self.is_signed() || matches!(self, Self::U8 | Self::U16 | Self::U32 | Self::U64 | Self::U128)
+    }
+
+    /// Returns `true` if the literal type is a signed integer type.
+    pub const fn is_signed(&self) -> bool {
+        matches!(self, Self::I8 | Self::I16 | Self::I32 | Self::I64 | Self::I128)
+    }
+
+    /// Returns `true` if the literal type supports arithmetic, i.e. is an integer, field, group, or scalar type.
+    pub const fn is_numeric(&self) -> bool {
+        self.is_integer() || matches!(self, Self::Field | Self::Group | Self::Scalar)
+    }
+
+    /// Returns the bit width of the literal type, if it is an integer type.
+    /// For the bit size of any literal type on a given network, see `LiteralType::size_in_bits`.
+    pub const fn bit_width(&self) -> Option<u16> {
+        match self {
+            Self::I8 | Self::U8 => Some(8),
+            Self::I16 | Self::U16 => Some(16),
+            Self::I32 | Self::U32 => Some(32),
+            Self::I64 | Self::U64 => Some(64),
+            Self::I128 | Self::U128 => Some(128),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the given opcode accepts an operand of this literal type as its first operand.
+    /// Returns `false` if the opcode does not operate on literals.
+    pub fn supports_opcode(&self, opcode: &str) -> bool {
+        match opcode {
+            // Any literal may be compared for equality, cast, hashed, or committed to.
+            "assert.eq" | "assert.neq" | "is.eq" | "is.neq" | "cast" => true,
+            opcode if opcode.starts_with("hash.") || opcode.starts_with("commit.") => true,
+            // Arithmetic on all numeric types.
+            "add" | "mul" => self.is_numeric(),
+            // Arithmetic on all numeric types, excluding scalars.
+            "sub" => self.is_integer() || matches!(self, Self::Field | Self::Group),
+            // Arithmetic on integers and fields.
+            "div" | "pow" => self.is_integer() || matches!(self, Self::Field),
+            // Negation on signed integers, fields, and groups.
+            "neg" => self.is_signed() || matches!(self, Self::Field | Self::Group),
+            // Absolute values on signed integers.
+            "abs" | "abs.w" => self.is_signed(),
+            // Ordering on integers, fields, and scalars.
+            "gt" | "gte" | "lt" | "lte" => self.is_integer() || matches!(self, Self::Field | Self::Scalar),
+            // Doubling on fields and groups.
+            "double" => matches!(self, Self::Field | Self::Group),
+            // Field-only operations.
+            "inv" | "square" | "sqrt" => matches!(self, Self::Field),
+            // Modulo on unsigned integers.
+            "mod" => self.is_integer() && !self.is_signed(),
+            // Logical operations on booleans and integers.
+            "and" | "or" | "xor" | "not" => self.is_integer() || matches!(self, Self::Boolean),
+            // Boolean-only operations.
+            "nand" | "nor" | "ternary" => matches!(self, Self::Boolean),
+            // Integer-only operations.
+            "add.w" | "sub.w" | "mul.w" | "div.w" | "pow.w" | "rem" | "rem.w" | "shl" | "shl.w" | "shr" | "shr.w" => {
+                self.is_integer()
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        // Ensure integers are classified.
+        assert!(LiteralType::U32.is_integer());
+        assert!(LiteralType::U32.is_numeric());
+        assert!(!LiteralType::U32.is_signed());
+        assert_eq!(LiteralType::U32.bit_width(), Some(32));
+        assert!(LiteralType::I128.is_signed());
+        assert_eq!(LiteralType::I128.bit_width(), Some(128));
+
+        // Ensure non-integers are classified.
+        assert!(!LiteralType::Boolean.is_numeric());
+        assert!(!LiteralType::Boolean.is_integer());
+        assert_eq!(LiteralType::Boolean.bit_width(), None);
+        assert!(LiteralType::Field.is_numeric());
+        assert!(!LiteralType::Field.is_integer());
+        assert!(!LiteralType::Address.is_numeric());
+        assert!(!LiteralType::String.is_numeric());
+    }
+
+    #[test]
+    fn test_supports_opcode() {
+        assert!(LiteralType::U32.supports_opcode("add"));
+        assert!(LiteralType::U32.supports_opcode("shl.w"));
+        assert!(LiteralType::U32.supports_opcode("mod"));
+        assert!(!LiteralType::I32.supports_opcode("mod"));
+        assert!(!LiteralType::U32.supports_opcode("abs"));
+        assert!(LiteralType::Field.supports_opcode("inv"));
+        assert!(!LiteralType::Scalar.supports_opcode("sub"));
+        assert!(LiteralType::Boolean.supports_opcode("nand"));
+        assert!(!LiteralType::Boolean.supports_opcode("add"));
+        assert!(LiteralType::Address.supports_opcode("is.eq"));
+        assert!(LiteralType::U32.supports_opcode("hash.bhp256"));
+        assert!(!LiteralType::U32.supports_opcode("call"));
+        assert!(!LiteralType::U32.supports_opcode("unknown"));
+    }
+}
@@ -15,14 +15,15 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod classify;
 mod parse;
 mod serialize;
+mod size_in_bits;
 
 use snarkvm_console_network::prelude::*;
 
 use core::fmt::{self, Debug, Display};
 use num_derive::FromPrimitive;
-use num_traits::FromPrimitive;
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, FromPrimitive)]
 pub enum LiteralType {
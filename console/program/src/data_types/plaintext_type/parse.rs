@@ -18,13 +18,17 @@ use super::*;
 
 impl<N: Network> Parser for PlaintextType<N> {
     /// Parses a string into a plaintext type.
+    ///
+    /// The full type name is parsed before it is resolved to a literal type, so that a struct name
+    /// which begins with a literal type (e.g. `u8_pair`) is not parsed as the literal type.
+    /// This ensures every plaintext type has exactly one spelling, which parses back to itself.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
-        // Parse to determine the plaintext type (order matters).
-        alt((
-            map(LiteralType::parse, |type_| Self::Literal(type_)),
-            map(Identifier::parse, |identifier| Self::Struct(identifier)),
-        ))(string)
+        // Parse the type name, and determine the plaintext type from it.
+        map(Identifier::parse, |identifier| match LiteralType::from_str(&identifier.to_string()) {
+            Ok(literal_type) => Self::Literal(literal_type),
+            Err(..) => Self::Struct(identifier),
+        })(string)
     }
 }
 
@@ -155,4 +159,42 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_parse_display_idempotent() -> Result<()> {
+        let literal_types = [
+            LiteralType::Address,
+            LiteralType::Boolean,
+            LiteralType::Field,
+            LiteralType::Group,
+            LiteralType::I8,
+            LiteralType::I16,
+            LiteralType::I32,
+            LiteralType::I64,
+            LiteralType::I128,
+            LiteralType::U8,
+            LiteralType::U16,
+            LiteralType::U32,
+            LiteralType::U64,
+            LiteralType::U128,
+            LiteralType::Scalar,
+            LiteralType::String,
+        ];
+        // Ensure parse -> display -> parse is idempotent for every literal type.
+        for literal_type in literal_types {
+            let expected = PlaintextType::<CurrentNetwork>::Literal(literal_type);
+            let candidate = PlaintextType::<CurrentNetwork>::from_str(&literal_type.to_string())?;
+            assert_eq!(expected, candidate);
+            assert_eq!(expected, PlaintextType::from_str(&candidate.to_string())?);
+        }
+
+        // Ensure a struct name beginning with a literal type is a struct, and not the literal type.
+        for name in ["u8_pair", "fields", "address_book", "i128x"] {
+            let expected = PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str(name)?);
+            let candidate = PlaintextType::<CurrentNetwork>::from_str(name)?;
+            assert_eq!(expected, candidate);
+            assert_eq!(expected, PlaintextType::from_str(&candidate.to_string())?);
+        }
+        Ok(())
+    }
 }
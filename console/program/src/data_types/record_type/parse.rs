@@ -127,11 +127,17 @@ impl<N: Network> FromStr for RecordType<N> {
     type Err = Error;
 
     /// Returns an record type from a string literal.
+    /// On failure, the error reports the entry that failed to parse, if any.
     fn from_str(string: &str) -> Result<Self> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. In record '{}', failed parsing entry {}: \"{remainder}\"",
+                    object.name,
+                    object.entries.len()
+                );
                 // Return the object.
                 Ok(object)
             }
@@ -267,4 +273,14 @@ record message:
         let candidate = RecordType::<CurrentNetwork>::parse(&string);
         assert!(candidate.is_err());
     }
+
+    #[test]
+    fn test_from_str_context() {
+        // Ensure a malformed entry is reported with its index.
+        let error = RecordType::<CurrentNetwork>::from_str(
+            "record token:\n    owner as address.private;\n    gates as u64.private;\n    amount as u64.private;\n    flag boolean.public;",
+        )
+        .unwrap_err();
+        assert!(error.to_string().starts_with("Failed to parse string. In record 'token', failed parsing entry 1"));
+    }
 }
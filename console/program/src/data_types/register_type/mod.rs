@@ -33,6 +33,18 @@ pub enum RegisterType<N: Network> {
     ExternalRecord(Locator<N>),
 }
 
+impl<N: Network> RegisterType<N> {
+    /// Initializes a plaintext register type.
+    pub const fn plaintext(plaintext_type: PlaintextType<N>) -> Self {
+        Self::Plaintext(plaintext_type)
+    }
+
+    /// Initializes a record register type, given the record name.
+    pub const fn record(record_name: Identifier<N>) -> Self {
+        Self::Record(record_name)
+    }
+}
+
 impl<N: Network> From<ValueType<N>> for RegisterType<N> {
     /// Converts a value type to a register type.
     fn from(value: ValueType<N>) -> Self {
@@ -148,4 +148,21 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_constructors_round_trip() -> Result<()> {
+        // Plaintext type.
+        for string in ["field", "u64", "signature"] {
+            let register_type = RegisterType::<CurrentNetwork>::plaintext(PlaintextType::from_str(string)?);
+            assert_eq!(register_type.to_string(), string);
+            assert_eq!(RegisterType::from_str(&register_type.to_string())?, register_type);
+        }
+
+        // Record type.
+        let register_type = RegisterType::<CurrentNetwork>::record(Identifier::from_str("token")?);
+        assert_eq!(register_type, RegisterType::Record(Identifier::from_str("token")?));
+        assert_eq!(register_type.to_string(), "token.record");
+        assert_eq!(RegisterType::from_str(&register_type.to_string())?, register_type);
+        Ok(())
+    }
 }
@@ -16,37 +16,9 @@
 
 use super::*;
 
-impl<N: Network> Parser for Struct<N> {
-    /// Parses a struct as:
-    /// ```text
-    ///   struct message:
-    ///       owner as address;
-    ///       amount as u64;
-    /// ```
-    #[inline]
-    fn parse(string: &str) -> ParserResult<Self> {
-        /// Parses a string into a tuple.
-        fn parse_tuple<N: Network>(string: &str) -> ParserResult<(Identifier<N>, PlaintextType<N>)> {
-            // Parse the whitespace and comments from the string.
-            let (string, _) = Sanitizer::parse(string)?;
-            // Parse the identifier from the string.
-            let (string, identifier) = Identifier::parse(string)?;
-            // Parse the whitespace from the string.
-            let (string, _) = Sanitizer::parse_whitespaces(string)?;
-            // Parse the "as" from the string.
-            let (string, _) = tag("as")(string)?;
-            // Parse the whitespace from the string.
-            let (string, _) = Sanitizer::parse_whitespaces(string)?;
-            // Parse the plaintext type from the string.
-            let (string, plaintext_type) = PlaintextType::parse(string)?;
-            // Parse the whitespace from the string.
-            let (string, _) = Sanitizer::parse_whitespaces(string)?;
-            // Parse the semicolon ';' keyword from the string.
-            let (string, _) = tag(";")(string)?;
-            // Return the identifier and plaintext type.
-            Ok((string, (identifier, plaintext_type)))
-        }
-
+impl<N: Network> Struct<N> {
+    /// Parses the struct header, i.e. `struct {name}:`, and returns the struct name.
+    fn parse_header(string: &str) -> ParserResult<'_, Identifier<N>> {
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
         // Parse the type name from the string.
@@ -59,8 +31,46 @@ impl<N: Network> Parser for Struct<N> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the colon ':' keyword from the string.
         let (string, _) = tag(":")(string)?;
+        // Return the struct name.
+        Ok((string, name))
+    }
+
+    /// Parses a struct member, i.e. `{identifier} as {plaintext_type};`.
+    fn parse_member(string: &str) -> ParserResult<'_, (Identifier<N>, PlaintextType<N>)> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the identifier from the string.
+        let (string, identifier) = Identifier::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the plaintext type from the string.
+        let (string, plaintext_type) = PlaintextType::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon ';' keyword from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the identifier and plaintext type.
+        Ok((string, (identifier, plaintext_type)))
+    }
+}
+
+impl<N: Network> Parser for Struct<N> {
+    /// Parses a struct as:
+    /// ```text
+    ///   struct message:
+    ///       owner as address;
+    ///       amount as u64;
+    /// ```
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the struct header from the string.
+        let (string, name) = Self::parse_header(string)?;
         // Parse the members from the string.
-        let (string, members) = map_res(many1(parse_tuple), |members| {
+        let (string, members) = map_res(many1(Self::parse_member), |members| {
             // Ensure the members has no duplicate names.
             if has_duplicates(members.iter().map(|(identifier, _)| identifier)) {
                 return Err(error(format!("Duplicate identifier found in struct '{}'", name)));
@@ -80,15 +90,27 @@ impl<N: Network> FromStr for Struct<N> {
     type Err = Error;
 
     /// Returns a struct from a string literal.
+    /// On failure, the error reports the member that failed to parse, if any.
     fn from_str(string: &str) -> Result<Self> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. In struct '{}', failed parsing member {}: \"{remainder}\"",
+                    object.name,
+                    object.members.len()
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => match Self::parse_header(string) {
+                // If the header is well-formed, but the first member is not, report the first member.
+                Ok((remainder, name)) if Self::parse_member(remainder).is_err() => {
+                    bail!("Failed to parse string. In struct '{name}', failed parsing member 0: \"{remainder}\"")
+                }
+                _ => bail!("Failed to parse string. {error}"),
+            },
         }
     }
 }
@@ -212,4 +234,16 @@ struct message:
         }
         assert!(Struct::<CurrentNetwork>::parse(&string).is_err());
     }
+
+    #[test]
+    fn test_from_str_context() {
+        // Ensure a malformed member is reported with its index.
+        let error =
+            Struct::<CurrentNetwork>::from_str("struct message:\n    first as field;\n    second field;").unwrap_err();
+        assert!(error.to_string().starts_with("Failed to parse string. In struct 'message', failed parsing member 1"));
+
+        // Ensure a malformed first member is reported.
+        let error = Struct::<CurrentNetwork>::from_str("struct message:\n    first as;").unwrap_err();
+        assert!(error.to_string().starts_with("Failed to parse string. In struct 'message', failed parsing member 0"));
+    }
 }
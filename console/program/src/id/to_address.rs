@@ -20,8 +20,38 @@ impl<N: Network> ProgramID<N> {
     /// Returns the program address for this program ID.
     pub fn to_address(&self) -> Result<Address<N>> {
         // Compute the program address as `HashToGroup(program_id)`.
-        let group = N::hash_to_group_psd4(&[self.name().to_field()?, self.network().to_field()?])?;
-        // Return the program address.
-        Ok(Address::new(group))
+        N::program_address(&self.to_fields()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_address() -> Result<()> {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+
+        // Ensure the program address is deterministic.
+        let expected = program_id.to_address()?;
+        assert_eq!(expected, ProgramID::<CurrentNetwork>::from_str("token.aleo")?.to_address()?);
+        assert_eq!(expected, CurrentNetwork::program_address(&program_id.to_fields()?)?);
+        assert_eq!(
+            expected,
+            Address::new(CurrentNetwork::hash_to_group_psd4(&[
+                program_id.name().to_field()?,
+                program_id.network().to_field()?
+            ])?)
+        );
+
+        // Ensure distinct program IDs map to distinct program addresses.
+        for other in ["tokens.aleo", "credits.aleo", "token.other"] {
+            let other = ProgramID::<CurrentNetwork>::from_str(other)?;
+            assert_ne!(expected, other.to_address()?);
+        }
+        Ok(())
     }
 }
@@ -58,7 +58,7 @@ impl<N: Network> Response<N> {
         tcm: &Field<N>,
         outputs: Vec<Value<N>>,
         output_types: &[ValueType<N>],
-        output_registers: &[Register<N>],
+        output_registers: &[Option<Register<N>>],
     ) -> Result<Self> {
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
         let function_id =
@@ -145,6 +145,11 @@ impl<N: Network> Response<N> {
                         // Compute the record commitment.
                         let commitment = record.to_commitment(program_id, record_name)?;
 
+                        // Retrieve the output register.
+                        let output_register = match output_register {
+                            Some(output_register) => output_register,
+                            None => bail!("Expected a register to be paired with a record output"),
+                        };
                         // Construct the (console) output index as a field element.
                         let index = Field::from_u64(output_register.locator());
                         // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
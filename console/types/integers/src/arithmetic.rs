@@ -423,3 +423,74 @@ impl<E: Environment, I: IntegerType> Square for Integer<E, I> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    type U128 = Integer<CurrentEnvironment, u128>;
+    type I128 = Integer<CurrentEnvironment, i128>;
+
+    #[test]
+    fn test_u128_boundaries() {
+        let zero = U128::new(0);
+        let one = U128::new(1);
+        let two = U128::new(2);
+        let max = U128::new(u128::MAX);
+        let half = U128::new(u128::MAX / 2);
+
+        // Ensure the checked operations compute the values at the boundaries.
+        assert_eq!(max, (max - one) + one);
+        assert_eq!(zero, max - max);
+        assert_eq!(max - one, half * two);
+        assert_eq!(max, max * one);
+
+        // Ensure the checked operations halt past the boundaries.
+        assert!(std::panic::catch_unwind(|| max + one).is_err());
+        assert!(std::panic::catch_unwind(|| zero - one).is_err());
+        assert!(std::panic::catch_unwind(|| max * two).is_err());
+        assert!(std::panic::catch_unwind(|| (half + one) * two).is_err());
+
+        // Ensure the wrapped operations wrap around past the boundaries.
+        assert_eq!(zero, max.add_wrapped(&one));
+        assert_eq!(max, zero.sub_wrapped(&one));
+        assert_eq!(max - one, max.mul_wrapped(&two));
+    }
+
+    #[test]
+    fn test_i128_boundaries() {
+        let zero = I128::new(0);
+        let one = I128::new(1);
+        let two = I128::new(2);
+        let minus_one = I128::new(-1);
+        let min = I128::new(i128::MIN);
+        let max = I128::new(i128::MAX);
+        let half_min = I128::new(i128::MIN / 2);
+
+        // Ensure the checked operations compute the values at the boundaries.
+        assert_eq!(max, (max - one) + one);
+        assert_eq!(min, (min + one) - one);
+        assert_eq!(minus_one, min + max);
+        assert_eq!(min, half_min * two);
+        assert_eq!(min + one, max * minus_one);
+        assert_eq!(zero, min - min);
+
+        // Ensure the checked operations halt past the boundaries.
+        assert!(std::panic::catch_unwind(|| max + one).is_err());
+        assert!(std::panic::catch_unwind(|| min - one).is_err());
+        assert!(std::panic::catch_unwind(|| min + minus_one).is_err());
+        assert!(std::panic::catch_unwind(|| max - minus_one).is_err());
+        assert!(std::panic::catch_unwind(|| min * minus_one).is_err());
+        assert!(std::panic::catch_unwind(|| max * two).is_err());
+        assert!(std::panic::catch_unwind(|| -min).is_err());
+
+        // Ensure the wrapped operations wrap around past the boundaries.
+        assert_eq!(min, max.add_wrapped(&one));
+        assert_eq!(max, min.sub_wrapped(&one));
+        assert_eq!(min, min.mul_wrapped(&minus_one));
+        assert_eq!(I128::new(-2), max.mul_wrapped(&two));
+    }
+}
@@ -123,6 +123,27 @@ mod tests {
         check_display::<CurrentEnvironment, i128>(&mut rng);
     }
 
+    #[test]
+    fn test_display_128_bit_boundaries() {
+        /// Ensures the given value is displayed in full, and recovered from its display.
+        fn check_display<I: IntegerType>(value: I, expected: &str) {
+            let candidate = Integer::<CurrentEnvironment, I>::new(value);
+            assert_eq!(expected, format!("{candidate}"));
+            assert_eq!(candidate, Integer::<CurrentEnvironment, I>::from_str(expected).unwrap());
+        }
+
+        check_display(u128::MIN, "0u128");
+        check_display(u128::MAX, "340282366920938463463374607431768211455u128");
+        check_display(i128::MIN, "-170141183460469231731687303715884105728i128");
+        check_display(i128::MAX, "170141183460469231731687303715884105727i128");
+
+        // Ensure values past the boundaries are rejected.
+        assert!(Integer::<CurrentEnvironment, u128>::from_str("340282366920938463463374607431768211456u128").is_err());
+        assert!(Integer::<CurrentEnvironment, u128>::from_str("-1u128").is_err());
+        assert!(Integer::<CurrentEnvironment, i128>::from_str("170141183460469231731687303715884105728i128").is_err());
+        assert!(Integer::<CurrentEnvironment, i128>::from_str("-170141183460469231731687303715884105729i128").is_err());
+    }
+
     #[test]
     fn test_display_zero() {
         let zero = i8::zero();
@@ -282,4 +282,21 @@ mod tests {
         let result = std::panic::catch_unwind(|| one / zero);
         assert!(result.is_err()); // Probe further for specific error type here, if desired
     }
+
+    #[test]
+    fn test_modulus_boundary() {
+        let one = Scalar::<CurrentEnvironment>::one();
+        let two = one + one;
+        let minus_one = -one;
+
+        // Ensure addition reduces at the scalar field modulus.
+        assert_eq!(Scalar::zero(), minus_one + one);
+        assert_eq!(minus_one, Scalar::zero() - one);
+        assert_eq!(-two, minus_one + minus_one);
+
+        // Ensure multiplication reduces at the scalar field modulus.
+        assert_eq!(one, minus_one * minus_one);
+        assert_eq!(-two, minus_one * two);
+        assert_eq!(Scalar::zero(), minus_one * Scalar::zero());
+    }
 }
@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[macro_use]
+extern crate criterion;
+
+use console::{
+    account::Address,
+    network::Testnet3,
+    prelude::*,
+    program::{Identifier, Plaintext, Record, Register, Value},
+    types::Field,
+};
+use snarkvm_synthesizer::{Authorization, CallStack, Closure, Operand, Process, Program, Registers, Stack};
+
+use criterion::Criterion;
+
+type CurrentNetwork = Testnet3;
+type CurrentAleo = circuit::network::AleoV0;
+
+/// Initializes a stack for a function that reads the members of a struct input.
+fn sample_stack() -> Stack<CurrentNetwork> {
+    // Initialize a program with heavy register reuse.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program registers.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function compute:
+    input r0 as message.private;
+    add r0.first r0.second into r1;
+    add r0.first r1 into r2;
+    add r0.second r2 into r3;
+    output r3 as field.private;",
+    )
+    .unwrap();
+
+    // Initialize a new process.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    // Add the program to the process.
+    process.add_program(&program).unwrap();
+    // Return the stack.
+    process.get_stack(program.id()).unwrap().clone()
+}
+
+/// Initializes a stack for a closure with a large struct input, and returns the closure and its input.
+fn sample_closure_stack() -> (Stack<CurrentNetwork>, Closure<CurrentNetwork>, Value<CurrentNetwork>) {
+    // Initialize a struct with many members, and a closure that reads one of them.
+    let members = (0..32).map(|i| format!("\n    member{i} as field;")).collect::<String>();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        r"
+program closures.aleo;
+
+struct large:{members}
+
+closure compute:
+    input r0 as large;
+    add r0.member0 r0.member31 into r1;
+    output r1 as field;
+
+function main:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;"
+    ))
+    .unwrap();
+
+    // Initialize the input.
+    let entries = (0..32).map(|i| format!("member{i}: {i}field")).collect::<Vec<_>>().join(", ");
+    let input = Value::Plaintext(Plaintext::from_str(&format!("{{ {entries} }}")).unwrap());
+
+    // Initialize a new process.
+    let mut process = Process::<CurrentNetwork>::load_without_keys().unwrap();
+    // Add the program to the process.
+    process.add_program(&program).unwrap();
+    // Return the stack, closure, and input.
+    let stack = process.get_stack(program.id()).unwrap().clone();
+    (stack, program.get_closure(&Identifier::from_str("compute").unwrap()).unwrap(), input)
+}
+
+/// Initializes a stack for a function that reads the entries of a large record input, and returns the record.
+fn sample_record_stack() -> (Stack<CurrentNetwork>, Record<CurrentNetwork, Plaintext<CurrentNetwork>>) {
+    // Initialize a record with many entries, and a function that reads a few of them.
+    let entries = (0..32).map(|i| format!("\n    entry{i} as u64.private;")).collect::<String>();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        r"
+program records.aleo;
+
+record large:
+    owner as address.private;
+    gates as u64.private;{entries}
+
+function compute:
+    input r0 as large.record;
+    add r0.entry0 r0.entry1 into r1;
+    add r0.entry2 r1 into r2;
+    add r0.entry3 r2 into r3;
+    output r3 as u64.private;"
+    ))
+    .unwrap();
+
+    // Initialize the record.
+    let owner = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+    let entries = (0..32).map(|i| format!(", entry{i}: {i}u64.private")).collect::<String>();
+    let record =
+        Record::from_str(&format!("{{ owner: {owner}.private, gates: 5u64.private{entries}, _nonce: 0group.public }}"))
+            .unwrap();
+
+    // Initialize a new process.
+    let mut process = Process::<CurrentNetwork>::load_without_keys().unwrap();
+    // Add the program to the process.
+    process.add_program(&program).unwrap();
+    // Return the stack and the record.
+    (process.get_stack(program.id()).unwrap().clone(), record)
+}
+
+/// Initializes the registers, with the struct input stored in `r0`.
+fn sample_registers(stack: &Stack<CurrentNetwork>) -> Registers<CurrentNetwork, CurrentAleo> {
+    // Initialize the registers.
+    let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+        CallStack::evaluate(Authorization::new(&[])).unwrap(),
+        stack.get_register_types(&Identifier::from_str("compute").unwrap()).unwrap().clone(),
+    );
+    // Store the input.
+    let input = Value::Plaintext(Plaintext::from_str("{ first: 2field, second: 3field }").unwrap());
+    registers.store(stack, &Register::Locator(0), input).unwrap();
+    // Return the registers.
+    registers
+}
+
+fn registers_load(c: &mut Criterion) {
+    let stack = sample_stack();
+    let operand = Operand::Register(Register::from_str("r0.first").unwrap());
+
+    // Benchmark loading a register member, which walks the path and checks the type.
+    let registers = sample_registers(&stack);
+    c.bench_function("Registers::load (register member)", |b| b.iter(|| registers.load(&stack, &operand).unwrap()));
+}
+
+fn registers_load_record(c: &mut Criterion) {
+    let (stack, record) = sample_record_stack();
+
+    // Initialize the registers, with the record input stored in `r0`.
+    let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+        CallStack::evaluate(Authorization::new(&[])).unwrap(),
+        stack.get_register_types(&Identifier::from_str("compute").unwrap()).unwrap().clone(),
+    );
+    registers.store(&stack, &Register::Locator(0), Value::from(record.clone())).unwrap();
+
+    // Benchmark a deep copy of the record, which is the cost of passing it through an instruction without sharing.
+    c.bench_function("Record::clone (34 entries)", |b| b.iter(|| record.clone()));
+
+    // Benchmark loading the record register, which shares the record instead of copying it.
+    let operand = Operand::Register(Register::Locator(0));
+    c.bench_function("Registers::load (record, 34 entries)", |b| b.iter(|| registers.load(&stack, &operand).unwrap()));
+}
+
+fn stack_evaluate_closure(c: &mut Criterion) {
+    let (stack, closure, input) = sample_closure_stack();
+    let caller = Address::from_str("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah").unwrap();
+    let tvk = Field::from_u64(1);
+    let call_stack = || CallStack::evaluate(Authorization::new(&[])).unwrap();
+
+    // Benchmark evaluating a closure on borrowed inputs, which are cloned into the registers.
+    let inputs = vec![input.clone()];
+    c.bench_function("Stack::evaluate_closure (large struct input)", |b| {
+        b.iter_batched(
+            call_stack,
+            |call_stack| stack.evaluate_closure::<CurrentAleo>(&closure, &inputs, call_stack, caller, tvk).unwrap(),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+
+    // Benchmark evaluating a closure on owned inputs, which are moved into the registers.
+    // Note: The owned inputs are constructed outside of the measurement.
+    c.bench_function("Stack::evaluate_closure_owned (large struct input)", |b| {
+        b.iter_batched(
+            || (vec![input.clone()], call_stack()),
+            |(inputs, call_stack)| {
+                stack.evaluate_closure_owned::<CurrentAleo>(&closure, inputs, call_stack, caller, tvk).unwrap()
+            },
+            criterion::BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group! {
+    name = registers;
+    config = Criterion::default().sample_size(10);
+    targets = registers_load, registers_load_record, stack_evaluate_closure
+}
+
+criterion_main!(registers);
@@ -52,6 +52,36 @@ impl<N: Network> Block<N> {
         }
     }
 
+    /// Verifies that the genesis block bytes of the network decode to the canonical genesis block,
+    /// by recomputing its block hash and checking it against `N::GENESIS_BLOCK_HASH`
+    /// (or, if the network does not set one, against the block hash it was serialized with).
+    ///
+    /// # Errors
+    /// This method will halt if the genesis block bytes cannot be deserialized.
+    /// This method will halt if the deserialized block is not a genesis block.
+    /// This method will halt if the recomputed block hash does not match the canonical genesis block hash.
+    pub fn verify_genesis_block() -> Result<()> {
+        // Load the genesis block.
+        let block = Self::read_le(N::genesis_bytes())?;
+        // Ensure the block is a genesis block.
+        ensure!(block.is_genesis(), "The genesis block bytes do not contain a genesis block");
+
+        // Recompute the block hash.
+        let preimage = [block.previous_hash.to_bits_le(), block.header.to_root()?.to_bits_le()].concat();
+        let block_hash: N::BlockHash = N::hash_bhp1024(&preimage)?.into();
+        // Retrieve the canonical genesis block hash, which defaults to the serialized block hash.
+        let expected = match N::GENESIS_BLOCK_HASH {
+            Some(expected) => expected.to_string(),
+            None => block.hash().to_string(),
+        };
+        // Ensure the block hash matches the canonical genesis block hash.
+        ensure!(
+            block_hash.to_string() == expected,
+            "The genesis block hash '{block_hash}' does not match the expected genesis block hash '{expected}'"
+        );
+        Ok(())
+    }
+
     /// Returns `true` if the block is a genesis block.
     pub fn is_genesis(&self) -> bool {
         // Ensure the previous block hash is zero.
@@ -85,4 +115,15 @@ mod tests {
         // println!("{}", serde_json::to_string_pretty(&block).unwrap());
         assert!(new_genesis_block.is_genesis());
     }
+
+    #[test]
+    fn test_verify_genesis_block() -> Result<()> {
+        // Ensure the genesis block bytes decode to the canonical genesis block.
+        Block::<CurrentNetwork>::verify_genesis_block()?;
+
+        // Ensure the genesis block hash constant matches the loaded genesis block.
+        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?;
+        assert_eq!(CurrentNetwork::GENESIS_BLOCK_HASH, Some(genesis_block.hash().to_string().as_str()));
+        Ok(())
+    }
 }
@@ -110,4 +110,20 @@ mod tests {
         assert_eq!(metadata.last_coinbase_timestamp(), CurrentNetwork::GENESIS_TIMESTAMP);
         assert_eq!(metadata.timestamp(), CurrentNetwork::GENESIS_TIMESTAMP);
     }
+
+    #[test]
+    fn test_genesis_metadata_targets() -> Result<()> {
+        // Prepare the genesis metadata.
+        let metadata = Metadata::<CurrentNetwork>::genesis()?;
+
+        // Ensure the targets are sourced from the network.
+        assert_eq!(metadata.coinbase_target(), CurrentNetwork::GENESIS_COINBASE_TARGET);
+        assert_eq!(metadata.proof_target(), CurrentNetwork::GENESIS_PROOF_TARGET);
+        assert_eq!(metadata.last_coinbase_target(), CurrentNetwork::GENESIS_COINBASE_TARGET);
+
+        // Ensure the targets are consistent with the requirements of a block header.
+        assert!(metadata.proof_target() > 0);
+        assert!(metadata.coinbase_target() > metadata.proof_target());
+        Ok(())
+    }
 }
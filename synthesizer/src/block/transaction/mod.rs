@@ -17,6 +17,7 @@
 mod bytes;
 mod merkle;
 mod serialize;
+pub use serialize::to_json;
 mod string;
 
 use crate::{
@@ -177,6 +178,10 @@ impl<N: Network> Transaction<N> {
     }
 
     /// Returns the transaction fee, which is the sum of the transition fees.
+    ///
+    /// As the fee of each transition is the sum of its input record gates, minus the sum of its
+    /// output record gates (enforced in the transition proof), this is the net value balance of
+    /// the transaction, i.e. the amount of gates released to the network.
     pub fn fee(&self) -> Result<i64> {
         // Compute the sum of the transition fees.
         self.transitions().map(Transition::fee).try_fold(0i64, |cumulative, fee| {
@@ -357,3 +362,24 @@ impl<N: Network> Transaction<N> {
         self.into_transitions().flat_map(Transition::into_nonces)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Ensure the deployment releases its fee of 10 gates, which is the net value balance of its fee transition.
+        let transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        assert_eq!(transaction.transitions().map(|transition| *transition.fee()).collect::<Vec<_>>(), vec![10]);
+        assert_eq!(transaction.fee()?, 10);
+
+        // Ensure the transfer releases no gates, as its input record gates are all carried to its output records.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+        assert_eq!(transaction.transitions().map(|transition| *transition.fee()).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(transaction.fee()?, 0);
+        Ok(())
+    }
+}
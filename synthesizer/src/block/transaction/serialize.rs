@@ -16,6 +16,94 @@
 
 use super::*;
 
+use blake2::Digest;
+
+/// Returns the JSON value of the given transaction. If `include_proofs` is `false`,
+/// each proof is replaced with a summary of its serialized size and BLAKE2s hash.
+///
+/// # Errors
+/// This method will halt if the transaction or one of its proofs fails to serialize.
+pub fn to_json<N: Network>(tx: &Transaction<N>, include_proofs: bool) -> Result<serde_json::Value> {
+    if include_proofs {
+        return Ok(serde_json::to_value(tx)?);
+    }
+    match tx {
+        Transaction::Deploy(id, deployment, additional_fee) => Ok(serde_json::json!({
+            "type": "deploy",
+            "id": id,
+            "deployment": deployment_to_json(deployment)?,
+            "additional_fee": fee_to_json(additional_fee)?,
+        })),
+        Transaction::Execute(id, execution, additional_fee) => {
+            let mut value = serde_json::json!({
+                "type": "execute",
+                "id": id,
+                "execution": execution_to_json(execution)?,
+            });
+            if let Some(additional_fee) = additional_fee {
+                value["additional_fee"] = fee_to_json(additional_fee)?;
+            }
+            Ok(value)
+        }
+    }
+}
+
+/// Returns the JSON value of the given deployment, with the certificate of each verifying key summarized.
+fn deployment_to_json<N: Network>(deployment: &Deployment<N>) -> Result<serde_json::Value> {
+    let mut verifying_keys = serde_json::Map::new();
+    for (function_name, (verifying_key, certificate)) in deployment.verifying_keys() {
+        verifying_keys
+            .insert(function_name.to_string(), serde_json::json!([verifying_key, summarize_proof(certificate)?]));
+    }
+    Ok(serde_json::json!({
+        "edition": deployment.edition(),
+        "program": deployment.program(),
+        "verifying_keys": verifying_keys,
+    }))
+}
+
+/// Returns the JSON value of the given execution, with its transition and inclusion proofs summarized.
+fn execution_to_json<N: Network>(execution: &Execution<N>) -> Result<serde_json::Value> {
+    let transitions = execution.transitions().map(transition_to_json).collect::<Result<Vec<_>>>()?;
+    let mut value = serde_json::json!({
+        "transitions": transitions,
+        "global_state_root": execution.global_state_root(),
+    });
+    if let Some(inclusion_proof) = execution.inclusion_proof() {
+        value["inclusion"] = summarize_proof(inclusion_proof)?;
+    }
+    Ok(value)
+}
+
+/// Returns the JSON value of the given fee, with its transition and inclusion proofs summarized.
+fn fee_to_json<N: Network>(fee: &Fee<N>) -> Result<serde_json::Value> {
+    let mut value = serde_json::json!({
+        "transition": transition_to_json(fee.transition())?,
+        "global_state_root": fee.global_state_root(),
+    });
+    if let Some(inclusion_proof) = fee.inclusion_proof() {
+        value["inclusion"] = summarize_proof(inclusion_proof)?;
+    }
+    Ok(value)
+}
+
+/// Returns the JSON value of the given transition, with its proof summarized.
+fn transition_to_json<N: Network>(transition: &Transition<N>) -> Result<serde_json::Value> {
+    let mut value = serde_json::to_value(transition)?;
+    value["proof"] = summarize_proof(transition.proof())?;
+    Ok(value)
+}
+
+/// Returns the summary of the given proof, as its serialized size (in bytes) and BLAKE2s hash.
+fn summarize_proof<T: ToBytes>(proof: &T) -> Result<serde_json::Value> {
+    let bytes = proof.to_bytes_le()?;
+    let hash = blake2::Blake2s256::digest(&bytes);
+    Ok(serde_json::json!({
+        "size": bytes.len(),
+        "hash": hash.iter().map(|byte| format!("{byte:02x}")).collect::<String>(),
+    }))
+}
+
 impl<N: Network> Serialize for Transaction<N> {
     /// Serializes the transaction to a JSON-string or buffer.
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -119,6 +207,60 @@ mod tests {
         Ok(())
     }
 
+    /// Returns `true` if the given JSON value contains the given key, at any depth.
+    fn contains_key(value: &serde_json::Value, key: &str) -> bool {
+        match value {
+            serde_json::Value::Object(object) => {
+                object.contains_key(key) || object.values().any(|value| contains_key(value, key))
+            }
+            serde_json::Value::Array(array) => array.iter().any(|value| contains_key(value, key)),
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_to_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::vm::test_helpers::sample_deployment_transaction(rng),
+            crate::vm::test_helpers::sample_execution_transaction(rng),
+        ]
+        .into_iter()
+        {
+            // Ensure the full JSON matches the serialized transaction, including its proofs.
+            let expected = serde_json::to_value(&transaction)?;
+            let candidate = to_json(&transaction, true)?;
+            assert_eq!(expected, candidate);
+            assert!(!contains_key(&candidate, "size"));
+            assert!(!contains_key(&candidate, "hash"));
+
+            // Ensure the lightweight JSON replaces the proofs with their summaries.
+            let candidate = to_json(&transaction, false)?;
+            assert_ne!(expected, candidate);
+            assert!(contains_key(&candidate, "size"));
+            assert!(contains_key(&candidate, "hash"));
+            assert_eq!(expected["id"], candidate["id"]);
+            assert_eq!(expected["type"], candidate["type"]);
+        }
+
+        // Ensure the proof of each transition in an execution is summarized.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let candidate = to_json(&transaction, false)?;
+        let transitions = candidate["execution"]["transitions"].as_array().unwrap();
+        let execution = match &transaction {
+            Transaction::Execute(_, execution, _) => execution,
+            _ => unreachable!("The sample transaction is an execution"),
+        };
+        assert_eq!(transitions.len(), execution.len());
+        for (transition, candidate) in execution.transitions().zip_eq(transitions) {
+            assert_eq!(candidate["id"], serde_json::to_value(transition.id())?);
+            assert_eq!(candidate["proof"]["size"], transition.proof().to_bytes_le()?.len());
+            assert_eq!(candidate["proof"]["hash"].as_str().unwrap().len(), 64);
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_bincode() -> Result<()> {
         let rng = &mut TestRng::default();
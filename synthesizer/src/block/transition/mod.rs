@@ -98,7 +98,7 @@ impl<N: Network> Transition<N> {
         response: &Response<N>,
         finalize: Option<Vec<Value<N>>>,
         output_types: &[ValueType<N>],
-        output_registers: &[Register<N>],
+        output_registers: &[Option<Register<N>>],
         proof: Proof<N>,
         fee: i64,
     ) -> Result<Self> {
@@ -214,6 +214,11 @@ impl<N: Network> Transition<N> {
                         // Ensure the commitment matches.
                         ensure!(*commitment == candidate_cm, "The output record commitment is incorrect");
 
+                        // Retrieve the output register.
+                        let output_register = match output_register {
+                            Some(output_register) => output_register,
+                            None => bail!("Expected a register to be paired with a record output"),
+                        };
                         // Construct the (console) output index as a field element.
                         let index = Field::from_u64(output_register.locator());
                         // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
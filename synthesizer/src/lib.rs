@@ -53,16 +53,267 @@ pub(crate) mod test_helpers {
         vm::VM,
     };
     use console::{
-        network::Testnet3,
-        prelude::{Network, TestRng},
+        algorithms::{Poseidon2, Poseidon4, BHP1024, BHP512},
+        collections::merkle_tree::{MerklePath, MerkleTree},
+        network::{AleoID, BHPMerkleTree, Environment, FiatShamirParameters, PoseidonMerkleTree, Testnet3},
+        prelude::{Network, ProjectiveCurve, TestRng},
         program::StatePath,
-        types::Field,
+        types::{Field, Group, Scalar},
     };
+    use snarkvm_algorithms::snark::marlin::{CircuitProvingKey, CircuitVerifyingKey, MarlinHidingMode};
+
+    use once_cell::sync::Lazy;
+    use serde::{Deserialize, Serialize};
+    use std::sync::Arc;
 
     use anyhow::{bail, Result};
 
     type CurrentNetwork = Testnet3;
 
+    type ProvingKey<N> = CircuitProvingKey<<N as Environment>::PairingCurve, MarlinHidingMode>;
+    type VerifyingKey<N> = CircuitVerifyingKey<<N as Environment>::PairingCurve, MarlinHidingMode>;
+
+    /// A network with the parameters of `Testnet3`, under a different network ID,
+    /// for testing code that handles programs of several networks.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    pub struct MirrorNetwork;
+
+    /// The Merkle tree hashers of the mirror network.
+    static BHP_512: Lazy<BHP512<MirrorNetwork>> = Lazy::new(|| BHP512::setup("AleoBHP512").unwrap());
+    static BHP_1024: Lazy<BHP1024<MirrorNetwork>> = Lazy::new(|| BHP1024::setup("AleoBHP1024").unwrap());
+    static POSEIDON_2: Lazy<Poseidon2<MirrorNetwork>> = Lazy::new(|| Poseidon2::setup("AleoPoseidon2").unwrap());
+    static POSEIDON_4: Lazy<Poseidon4<MirrorNetwork>> = Lazy::new(|| Poseidon4::setup("AleoPoseidon4").unwrap());
+    /// The powers of `G` of the mirror network.
+    static G_POWERS: Lazy<Vec<Group<MirrorNetwork>>> = Lazy::new(|| Testnet3::g_powers().iter().map(group).collect());
+
+    /// Converts a field element of `Testnet3` into one of the mirror network, and vice versa.
+    fn field<E: Environment<Field = <Testnet3 as Environment>::Field>, F: Environment<Field = E::Field>>(
+        field: &Field<E>,
+    ) -> Field<F> {
+        Field::new(**field)
+    }
+
+    /// Converts field elements of the mirror network into those of `Testnet3`.
+    fn fields(input: &[Field<MirrorNetwork>]) -> Vec<Field<Testnet3>> {
+        input.iter().map(field).collect()
+    }
+
+    /// Converts a group element of `Testnet3` into one of the mirror network, and vice versa.
+    fn group<E: Environment<Affine = <Testnet3 as Environment>::Affine>, F: Environment<Affine = E::Affine>>(
+        group: &Group<E>,
+    ) -> Group<F> {
+        Group::new(group.to_affine())
+    }
+
+    /// Converts a scalar of the mirror network into one of `Testnet3`, and vice versa.
+    fn scalar<E: Environment<Scalar = <Testnet3 as Environment>::Scalar>, F: Environment<Scalar = E::Scalar>>(
+        scalar: &Scalar<E>,
+    ) -> Scalar<F> {
+        Scalar::new(**scalar)
+    }
+
+    impl Environment for MirrorNetwork {
+        type Affine = <Testnet3 as Environment>::Affine;
+        type BigInteger = <Testnet3 as Environment>::BigInteger;
+        type Field = <Testnet3 as Environment>::Field;
+        type PairingCurve = <Testnet3 as Environment>::PairingCurve;
+        type Projective = <Testnet3 as Environment>::Projective;
+        type Scalar = <Testnet3 as Environment>::Scalar;
+
+        const EDWARDS_A: Self::Field = Testnet3::EDWARDS_A;
+        const EDWARDS_D: Self::Field = Testnet3::EDWARDS_D;
+        const MONTGOMERY_A: Self::Field = Testnet3::MONTGOMERY_A;
+        const MONTGOMERY_B: Self::Field = Testnet3::MONTGOMERY_B;
+    }
+
+    impl Network for MirrorNetwork {
+        type BlockHash = AleoID<Field<Self>, { u16::from_le_bytes(*b"ab") }>;
+        type StateRoot = AleoID<Field<Self>, { u16::from_le_bytes(*b"ar") }>;
+        type TransactionID = AleoID<Field<Self>, { u16::from_le_bytes(*b"at") }>;
+        type TransitionID = AleoID<Field<Self>, { u16::from_le_bytes(*b"as") }>;
+
+        const EDITION: u16 = Testnet3::EDITION;
+        const ID: u16 = u16::MAX;
+        const INCLUSION_FUNCTION_NAME: &'static str = Testnet3::INCLUSION_FUNCTION_NAME;
+        const NAME: &'static str = "Mirror Network";
+
+        fn genesis_bytes() -> &'static [u8] {
+            Testnet3::genesis_bytes()
+        }
+
+        fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<ProvingKey<Self>>> {
+            Testnet3::get_credits_proving_key(function_name)
+        }
+
+        fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VerifyingKey<Self>>> {
+            Testnet3::get_credits_verifying_key(function_name)
+        }
+
+        fn inclusion_proving_key() -> &'static Arc<ProvingKey<Self>> {
+            Testnet3::inclusion_proving_key()
+        }
+
+        fn inclusion_verifying_key() -> &'static Arc<VerifyingKey<Self>> {
+            Testnet3::inclusion_verifying_key()
+        }
+
+        fn g_powers() -> &'static Vec<Group<Self>> {
+            &G_POWERS
+        }
+
+        fn g_scalar_multiply(input: &Scalar<Self>) -> Group<Self> {
+            group(&Testnet3::g_scalar_multiply(&scalar(input)))
+        }
+
+        fn marlin_fs_parameters() -> &'static FiatShamirParameters<Self> {
+            Testnet3::marlin_fs_parameters()
+        }
+
+        fn bcm_domain() -> Field<Self> {
+            field(&Testnet3::bcm_domain())
+        }
+
+        fn encryption_domain() -> Field<Self> {
+            field(&Testnet3::encryption_domain())
+        }
+
+        fn graph_key_domain() -> Field<Self> {
+            field(&Testnet3::graph_key_domain())
+        }
+
+        fn randomizer_domain() -> Field<Self> {
+            field(&Testnet3::randomizer_domain())
+        }
+
+        fn r_bcm_domain() -> Field<Self> {
+            field(&Testnet3::r_bcm_domain())
+        }
+
+        fn serial_number_domain() -> Field<Self> {
+            field(&Testnet3::serial_number_domain())
+        }
+
+        fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+            Testnet3::commit_bhp256(input, &scalar(randomizer)).map(|output| field(&output))
+        }
+
+        fn commit_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+            Testnet3::commit_bhp512(input, &scalar(randomizer)).map(|output| field(&output))
+        }
+
+        fn commit_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+            Testnet3::commit_bhp768(input, &scalar(randomizer)).map(|output| field(&output))
+        }
+
+        fn commit_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+            Testnet3::commit_bhp1024(input, &scalar(randomizer)).map(|output| field(&output))
+        }
+
+        fn commit_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+            Testnet3::commit_ped64(input, &scalar(randomizer)).map(|output| group(&output))
+        }
+
+        fn commit_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+            Testnet3::commit_ped128(input, &scalar(randomizer)).map(|output| group(&output))
+        }
+
+        fn hash_bhp256(input: &[bool]) -> Result<Field<Self>> {
+            Testnet3::hash_bhp256(input).map(|output| field(&output))
+        }
+
+        fn hash_bhp512(input: &[bool]) -> Result<Field<Self>> {
+            Testnet3::hash_bhp512(input).map(|output| field(&output))
+        }
+
+        fn hash_bhp768(input: &[bool]) -> Result<Field<Self>> {
+            Testnet3::hash_bhp768(input).map(|output| field(&output))
+        }
+
+        fn hash_bhp1024(input: &[bool]) -> Result<Field<Self>> {
+            Testnet3::hash_bhp1024(input).map(|output| field(&output))
+        }
+
+        fn hash_ped64(input: &[bool]) -> Result<Field<Self>> {
+            Testnet3::hash_ped64(input).map(|output| field(&output))
+        }
+
+        fn hash_ped128(input: &[bool]) -> Result<Field<Self>> {
+            Testnet3::hash_ped128(input).map(|output| field(&output))
+        }
+
+        fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
+            Testnet3::hash_psd2(&fields(input)).map(|output| field(&output))
+        }
+
+        fn hash_psd4(input: &[Field<Self>]) -> Result<Field<Self>> {
+            Testnet3::hash_psd4(&fields(input)).map(|output| field(&output))
+        }
+
+        fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>> {
+            Testnet3::hash_psd8(&fields(input)).map(|output| field(&output))
+        }
+
+        fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+            Testnet3::hash_many_psd2(&fields(input), num_outputs).iter().map(field).collect()
+        }
+
+        fn hash_many_psd4(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+            Testnet3::hash_many_psd4(&fields(input), num_outputs).iter().map(field).collect()
+        }
+
+        fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+            Testnet3::hash_many_psd8(&fields(input), num_outputs).iter().map(field).collect()
+        }
+
+        fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>> {
+            Testnet3::hash_to_group_psd2(&fields(input)).map(|output| group(&output))
+        }
+
+        fn hash_to_group_psd4(input: &[Field<Self>]) -> Result<Group<Self>> {
+            Testnet3::hash_to_group_psd4(&fields(input)).map(|output| group(&output))
+        }
+
+        fn hash_to_group_psd8(input: &[Field<Self>]) -> Result<Group<Self>> {
+            Testnet3::hash_to_group_psd8(&fields(input)).map(|output| group(&output))
+        }
+
+        fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+            Testnet3::hash_to_scalar_psd2(&fields(input)).map(|output| scalar(&output))
+        }
+
+        fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+            Testnet3::hash_to_scalar_psd4(&fields(input)).map(|output| scalar(&output))
+        }
+
+        fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+            Testnet3::hash_to_scalar_psd8(&fields(input)).map(|output| scalar(&output))
+        }
+
+        fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
+            MerkleTree::new(&*BHP_1024, &*BHP_512, leaves)
+        }
+
+        fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>> {
+            MerkleTree::new(&*POSEIDON_4, &*POSEIDON_2, leaves)
+        }
+
+        fn verify_merkle_path_bhp<const DEPTH: u8>(
+            path: &MerklePath<Self, DEPTH>,
+            root: &Field<Self>,
+            leaf: &Vec<bool>,
+        ) -> bool {
+            path.verify(&*BHP_1024, &*BHP_512, root, leaf)
+        }
+
+        fn verify_merkle_path_psd<const DEPTH: u8>(
+            path: &MerklePath<Self, DEPTH>,
+            root: &Field<Self>,
+            leaf: &Vec<Field<Self>>,
+        ) -> bool {
+            path.verify(&*POSEIDON_4, &*POSEIDON_2, root, leaf)
+        }
+    }
+
     #[derive(Clone)]
     pub struct TestLedger<N: Network> {
         /// The VM state.
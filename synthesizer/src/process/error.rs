@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// An error that occurred while evaluating a program function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EvaluationError<N: Network> {
+    /// The number of inputs does not match the number of inputs of the function.
+    ArityMismatch { expected: usize, found: usize },
+    /// A value does not match its declared type.
+    TypeMismatch(String),
+    /// A checked arithmetic operation overflowed or underflowed.
+    Overflow(String),
+    /// An arithmetic operation divided by zero.
+    DivisionByZero(String),
+    /// An `assert` instruction failed.
+    AssertionFailed(String),
+    /// The evaluation did not complete within its budget.
+    BudgetExceeded(String),
+    /// The evaluation did not complete before its deadline.
+    Timeout(String),
+    /// The function does not exist in the process.
+    MissingFunction { program_id: ProgramID<N>, function_name: Identifier<N> },
+    /// The evaluation failed for any other reason.
+    Other(String),
+}
+
+impl<N: Network> EvaluationError<N> {
+    /// Returns the evaluation error for the given error, as returned by the evaluation of a function.
+    /// If the error was not raised as an `EvaluationError`, it is returned as `EvaluationError::Other`.
+    pub(super) fn from_error(error: Error) -> Self {
+        match error.downcast::<Self>() {
+            Ok(error) => error,
+            Err(error) => Self::Other(error.to_string()),
+        }
+    }
+}
+
+impl<N: Network> Display for EvaluationError<N> {
+    /// Prints the evaluation error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::ArityMismatch { expected, found } => write!(f, "Expected {expected} inputs, found {found} inputs"),
+            Self::TypeMismatch(message) => write!(f, "Type mismatch: {message}"),
+            Self::Overflow(message) => write!(f, "Overflow: {message}"),
+            Self::DivisionByZero(message) => write!(f, "Division by zero: {message}"),
+            Self::AssertionFailed(message) => write!(f, "Assertion failed: {message}"),
+            Self::BudgetExceeded(message) => write!(f, "Budget exceeded: {message}"),
+            Self::Timeout(message) => write!(f, "Timed out: {message}"),
+            Self::MissingFunction { program_id, function_name } => {
+                write!(f, "Function '{program_id}/{function_name}' does not exist")
+            }
+            Self::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl<N: Network> std::error::Error for EvaluationError<N> {}
@@ -16,25 +16,92 @@
 
 use super::*;
 
+use std::time::Instant;
+
 impl<N: Network> Process<N> {
     /// Evaluates a program function on the given request.
     #[inline]
     pub fn evaluate<A: circuit::Aleo<Network = N>>(&self, authorization: Authorization<N>) -> Result<Response<N>> {
+        Ok(self.try_evaluate::<A>(authorization)?)
+    }
+
+    /// Evaluates a program function on the given request, halting with a timeout error
+    /// if the given deadline passes before the evaluation completes.
+    #[inline]
+    pub fn evaluate_until<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+        deadline: Instant,
+    ) -> Result<Response<N>> {
+        Ok(self.try_evaluate_until::<A>(authorization, deadline)?)
+    }
+
+    /// Evaluates a program function on the given request, returning an `EvaluationError` on failure.
+    #[inline]
+    pub fn try_evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+    ) -> Result<Response<N>, EvaluationError<N>> {
         let timer = timer!("Process::evaluate");
 
         // Retrieve the main request (without popping it).
-        let request = authorization.peek_next()?;
+        let request = authorization.peek_next().map_err(EvaluationError::from_error)?;
 
         #[cfg(feature = "aleo-cli")]
         println!("{}", format!(" • Evaluating '{}/{}'...", request.program_id(), request.function_name()).dimmed());
 
         // Evaluate the function.
-        let response =
-            self.get_stack(request.program_id())?.evaluate_function::<A>(CallStack::evaluate(authorization)?);
+        let response = self.try_evaluate_with_deadline::<A>(&request, authorization, None);
         lap!(timer, "Evaluate the function");
 
         finish!(timer);
 
         response
     }
+
+    /// Evaluates a program function on the given request, returning an `EvaluationError` on failure,
+    /// and `EvaluationError::Timeout` if the given deadline passes before the evaluation completes.
+    #[inline]
+    pub fn try_evaluate_until<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+        deadline: Instant,
+    ) -> Result<Response<N>, EvaluationError<N>> {
+        let timer = timer!("Process::evaluate_until");
+
+        // Retrieve the main request (without popping it).
+        let request = authorization.peek_next().map_err(EvaluationError::from_error)?;
+
+        // Evaluate the function.
+        let response = self.try_evaluate_with_deadline::<A>(&request, authorization, Some(deadline));
+        lap!(timer, "Evaluate the function");
+
+        finish!(timer);
+
+        response
+    }
+
+    /// Evaluates a program function on the given request, with an optional deadline.
+    fn try_evaluate_with_deadline<A: circuit::Aleo<Network = N>>(
+        &self,
+        request: &Request<N>,
+        authorization: Authorization<N>,
+        deadline: Option<Instant>,
+    ) -> Result<Response<N>, EvaluationError<N>> {
+        // Ensure the function exists in the process.
+        let missing_function = || EvaluationError::MissingFunction {
+            program_id: *request.program_id(),
+            function_name: *request.function_name(),
+        };
+        let stack = self.get_stack(request.program_id()).map_err(|_| missing_function())?;
+        stack.get_function(request.function_name()).map_err(|_| missing_function())?;
+
+        // Evaluate the function.
+        let call_stack = CallStack::evaluate(authorization).map_err(EvaluationError::from_error)?;
+        match deadline {
+            Some(deadline) => stack.evaluate_function_until::<A>(call_stack, deadline),
+            None => stack.evaluate_function::<A>(call_stack),
+        }
+        .map_err(EvaluationError::from_error)
+    }
 }
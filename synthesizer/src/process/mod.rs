@@ -17,6 +17,9 @@
 mod stack;
 pub use stack::*;
 
+mod error;
+pub use error::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
@@ -149,6 +152,22 @@ impl<N: Network> Process<N> {
         Ok(process)
     }
 
+    /// Initializes a new process with the 'credits.aleo' program, without loading its circuit keys.
+    /// This process is suitable for evaluating programs, but not for executing them.
+    #[inline]
+    pub fn load_without_keys() -> Result<Self> {
+        // Initialize the process.
+        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        // Initialize the 'credits.aleo' program.
+        let program = Program::credits()?;
+        // Compute the 'credits.aleo' program stack.
+        let stack = Stack::new(&process, &program)?;
+        // Add the 'credits.aleo' stack to the process.
+        process.stacks.insert(*program.id(), stack);
+        // Return the process.
+        Ok(process)
+    }
+
     /// Initializes a new process with a cache of previously used keys. This version is suitable for tests
     /// (which often use nested loops that keep reusing those), as their deserialization is slow.
     #[cfg(test)]
@@ -215,6 +234,20 @@ impl<N: Network> Process<N> {
         self.get_stack(program_id).map(Stack::program)
     }
 
+    /// Sets the RNG for randomized opcodes in the given program.
+    /// Supplying a seeded RNG makes the evaluation of randomized opcodes reproducible.
+    ///
+    /// Note: The RNG is also used when the program is called by other programs in the process.
+    #[inline]
+    pub fn set_rng(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        rng: impl Rng + CryptoRng + Send + 'static,
+    ) -> Result<()> {
+        self.get_stack(program_id)?.set_rng(rng);
+        Ok(())
+    }
+
     /// Returns the proving key for the given program ID and function name.
     #[inline]
     pub fn get_proving_key(
@@ -407,7 +440,7 @@ mod tests {
     use console::{
         account::{Address, PrivateKey, ViewKey},
         network::Testnet3,
-        program::{Identifier, Literal, Value},
+        program::{Identifier, Literal, RegisterType, Value},
         types::Field,
     };
 
@@ -1515,4 +1548,517 @@ function mint:
             store.get_value(program0.id(), &mapping_name, &Plaintext::from(Literal::Address(caller))).unwrap().unwrap();
         assert_eq!(candidate, Value::from_str("100u64").unwrap());
     }
+
+    #[test]
+    fn test_process_evaluate_until() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program deadline.aleo;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        )
+        .unwrap();
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Authorize the function call.
+        let inputs = [Value::<CurrentNetwork>::from_str("1field").unwrap()];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+
+        // Ensure an immediate deadline returns a timeout error.
+        let deadline = std::time::Instant::now();
+        let error = process.evaluate_until::<CurrentAleo>(authorization.replicate(), deadline).unwrap_err();
+        assert!(error.to_string().contains("Timed out"), "{error}");
+
+        // Ensure a distant deadline evaluates the function.
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(3600);
+        let response = process.evaluate_until::<CurrentAleo>(authorization, deadline).unwrap();
+        assert_eq!(response.outputs(), [Value::from_str("2field").unwrap()]);
+    }
+
+    #[test]
+    fn test_process_try_evaluate() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program errors.aleo;
+
+function increment:
+    input r0 as u8.public;
+    add r0 1u8 into r1;
+    output r1 as u8.public;
+
+function divide:
+    input r0 as u8.public;
+    input r1 as u8.public;
+    div r0 r1 into r2;
+    output r2 as u8.public;
+
+function check:
+    input r0 as u8.public;
+    assert.eq r0 1u8;
+    output r0 as u8.public;",
+        )
+        .unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Authorizes a call to the given function on the given inputs.
+        let mut authorize = |function_name: &str, inputs: &[&str]| {
+            let inputs = inputs.iter().map(|input| Value::<CurrentNetwork>::from_str(input).unwrap()).collect_vec();
+            process
+                .authorize::<CurrentAleo, _>(
+                    &caller_private_key,
+                    program.id(),
+                    Identifier::from_str(function_name).unwrap(),
+                    inputs.iter(),
+                    rng,
+                )
+                .unwrap()
+        };
+
+        // Ensure a successful evaluation returns the response.
+        let response = process.try_evaluate::<CurrentAleo>(authorize("increment", &["1u8"])).unwrap();
+        assert_eq!(response.outputs(), [Value::from_str("2u8").unwrap()]);
+
+        // Ensure an overflow is reported.
+        let error = process.try_evaluate::<CurrentAleo>(authorize("increment", &["255u8"])).unwrap_err();
+        assert!(matches!(error, EvaluationError::Overflow(..)), "{error}");
+        // Ensure a division by zero is reported.
+        let error = process.try_evaluate::<CurrentAleo>(authorize("divide", &["1u8", "0u8"])).unwrap_err();
+        assert!(matches!(error, EvaluationError::DivisionByZero(..)), "{error}");
+        // Ensure a failed assertion is reported.
+        let error = process.try_evaluate::<CurrentAleo>(authorize("check", &["2u8"])).unwrap_err();
+        assert!(matches!(error, EvaluationError::AssertionFailed(..)), "{error}");
+        // Ensure an exceeded deadline is reported.
+        let deadline = std::time::Instant::now();
+        let error = process.try_evaluate_until::<CurrentAleo>(authorize("increment", &["1u8"]), deadline).unwrap_err();
+        assert!(matches!(error, EvaluationError::Timeout(..)), "{error}");
+
+        // Ensure a function that does not exist in the process is reported.
+        let other = Program::from_str(
+            r"program other.aleo;
+
+function increment:
+    input r0 as u8.public;
+    add r0 1u8 into r1;
+    output r1 as u8.public;",
+        )
+        .unwrap();
+        let error = super::test_helpers::sample_process(&other)
+            .try_evaluate::<CurrentAleo>(authorize("increment", &["1u8"]))
+            .unwrap_err();
+        assert_eq!(error, EvaluationError::MissingFunction {
+            program_id: *program.id(),
+            function_name: Identifier::from_str("increment").unwrap()
+        });
+
+        // Ensure `evaluate` converts the evaluation error.
+        let error = process.evaluate::<CurrentAleo>(authorize("divide", &["1u8", "0u8"])).unwrap_err();
+        assert!(error.to_string().starts_with("Division by zero"), "{error}");
+    }
+
+    #[test]
+    fn test_process_try_evaluate_halts() {
+        // The opcode, the type, the inputs, and the expected error of each checked operation that halts.
+        let cases = [
+            ("abs", "i8", vec!["-128i8"], "overflow"),
+            ("neg", "i8", vec!["-128i8"], "overflow"),
+            ("add", "u8", vec!["255u8", "1u8"], "overflow"),
+            ("sub", "u8", vec!["0u8", "1u8"], "overflow"),
+            ("mul", "u8", vec!["128u8", "2u8"], "overflow"),
+            ("div", "i8", vec!["-128i8", "-1i8"], "overflow"),
+            ("rem", "i8", vec!["-128i8", "-1i8"], "overflow"),
+            ("pow", "u8", vec!["2u8", "8u8"], "overflow"),
+            ("shl", "u8", vec!["1u8", "8u8"], "overflow"),
+            ("shr", "u8", vec!["1u8", "8u8"], "overflow"),
+            ("div.w", "u8", vec!["1u8", "0u8"], "division by zero"),
+            ("rem.w", "u8", vec!["1u8", "0u8"], "division by zero"),
+            ("mod", "u8", vec!["1u8", "0u8"], "division by zero"),
+            ("inv", "field", vec!["0field"], "division by zero"),
+        ];
+
+        // Initialize a new program, with a function for each case.
+        let mut program = String::from("program halts.aleo;\n");
+        for (index, (opcode, output_type, inputs, _)) in cases.iter().enumerate() {
+            program.push_str(&format!("\nfunction halt_{index}:\n"));
+            for register in 0..inputs.len() {
+                program.push_str(&format!("    input r{register} as {output_type}.public;\n"));
+            }
+            let operands = (0..inputs.len()).map(|register| format!("r{register}")).collect::<Vec<_>>().join(" ");
+            let destination = inputs.len();
+            program.push_str(&format!("    {opcode} {operands} into r{destination};\n"));
+            program.push_str(&format!("    output r{destination} as {output_type}.public;\n"));
+        }
+        let program = Program::<CurrentNetwork>::from_str(&program).unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Ensure each checked operation reports its error, instead of halting.
+        for (index, (opcode, _, inputs, expected)) in cases.iter().enumerate() {
+            let inputs = inputs.iter().map(|input| Value::<CurrentNetwork>::from_str(input).unwrap()).collect_vec();
+            let authorization = process
+                .authorize::<CurrentAleo, _>(
+                    &caller_private_key,
+                    program.id(),
+                    Identifier::from_str(&format!("halt_{index}")).unwrap(),
+                    inputs.iter(),
+                    rng,
+                )
+                .unwrap();
+            let error = process.try_evaluate::<CurrentAleo>(authorization).unwrap_err();
+            match *expected {
+                "overflow" => assert!(matches!(error, EvaluationError::Overflow(..)), "'{opcode}': {error}"),
+                _ => assert!(matches!(error, EvaluationError::DivisionByZero(..)), "'{opcode}': {error}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_stack_resolve_operand_type() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program resolve.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+function compute:
+    input r0 as point.private;
+    add r0.x r0.y into r1;
+    add r1 1field into r2;
+    output r2 as field.private;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id()).unwrap();
+
+        let function_name = Identifier::from_str("compute").unwrap();
+        let resolve = |operand: &str| stack.resolve_operand_type(&function_name, &Operand::from_str(operand).unwrap());
+
+        // Ensure a literal operand is typed as its literal type.
+        assert_eq!(resolve("1field").unwrap(), RegisterType::from_str("field").unwrap());
+        assert_eq!(resolve("1u8").unwrap(), RegisterType::from_str("u8").unwrap());
+        // Ensure a register operand is typed from the register types of the function.
+        assert_eq!(resolve("r0").unwrap(), RegisterType::from_str("point").unwrap());
+        assert_eq!(resolve("r0.x").unwrap(), RegisterType::from_str("field").unwrap());
+        assert_eq!(resolve("r1").unwrap(), RegisterType::from_str("field").unwrap());
+        assert_eq!(resolve("r2").unwrap(), RegisterType::from_str("field").unwrap());
+        // Ensure an undefined register fails.
+        assert!(resolve("r3").is_err());
+        // Ensure an undefined function fails.
+        let operand = Operand::from_str("r0").unwrap();
+        assert!(stack.resolve_operand_type(&Identifier::from_str("missing").unwrap(), &operand).is_err());
+    }
+
+    #[test]
+    fn test_stack_call_depth() {
+        // Initialize a chain of programs, where each function calls the function of the program it imports.
+        let base = Program::<CurrentNetwork>::from_str(
+            r"program base.aleo;
+
+closure twice:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+
+function compute_twice:
+    input r0 as field.private;
+    call twice r0 into r1;
+    output r1 as field.private;",
+        )
+        .unwrap();
+        let middle = Program::<CurrentNetwork>::from_str(
+            r"import base.aleo;
+
+program middle.aleo;
+
+function compute:
+    input r0 as field.private;
+    call base.aleo/compute r0 into r1;
+    output r1 as field.private;",
+        )
+        .unwrap();
+        let top = Program::<CurrentNetwork>::from_str(
+            r"import middle.aleo;
+
+program top.aleo;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    call middle.aleo/compute r1 into r2;
+    output r2 as field.private;
+
+function leaf:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let mut process = Process::<CurrentNetwork>::load_without_keys().unwrap();
+        process.add_program(&base).unwrap();
+        process.add_program(&middle).unwrap();
+        process.add_program(&top).unwrap();
+
+        // Ensure the depth counts each nested call along the three-deep chain.
+        let compute = Identifier::from_str("compute").unwrap();
+        assert_eq!(process.get_stack(base.id()).unwrap().call_depth(&compute).unwrap(), 0);
+        assert_eq!(process.get_stack(middle.id()).unwrap().call_depth(&compute).unwrap(), 1);
+        assert_eq!(process.get_stack(top.id()).unwrap().call_depth(&compute).unwrap(), 2);
+        // Ensure a closure call counts as a nested call.
+        let stack = process.get_stack(base.id()).unwrap();
+        assert_eq!(stack.call_depth(&Identifier::from_str("compute_twice").unwrap()).unwrap(), 1);
+        // Ensure a leaf function has a depth of zero.
+        let stack = process.get_stack(top.id()).unwrap();
+        assert_eq!(stack.call_depth(&Identifier::from_str("leaf").unwrap()).unwrap(), 0);
+        // Ensure an undefined function fails.
+        assert!(stack.call_depth(&Identifier::from_str("missing").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_stack_with_rng() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program seeded.aleo;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        )
+        .unwrap();
+
+        // Construct the stack.
+        let process = Process::<CurrentNetwork>::load_without_keys().unwrap();
+        let stack = Stack::new(&process, &program).unwrap();
+
+        // Ensure sampling fails without an RNG.
+        assert!(!stack.has_rng());
+        assert!(stack.sample_with_rng(|rng| rng.next_u64()).is_err());
+
+        // Ensure a seeded RNG is accepted and stored.
+        let first = stack.clone().with_rng(TestRng::fixed(123456789));
+        let second = stack.with_rng(TestRng::fixed(123456789));
+        assert!(first.has_rng());
+        assert!(second.has_rng());
+
+        // Ensure the seeded RNGs produce the same samples.
+        for _ in 0..3 {
+            let expected = first.sample_with_rng(|rng| rng.next_u64()).unwrap();
+            assert_eq!(expected, second.sample_with_rng(|rng| rng.next_u64()).unwrap());
+        }
+
+        // Ensure a clone of the stack shares its RNG.
+        let clone = first.clone();
+        let expected = second.sample_with_rng(|rng| rng.next_u64()).unwrap();
+        assert_eq!(expected, clone.sample_with_rng(|rng| rng.next_u64()).unwrap());
+        let expected = second.sample_with_rng(|rng| rng.next_u64()).unwrap();
+        assert_eq!(expected, first.sample_with_rng(|rng| rng.next_u64()).unwrap());
+
+        // Ensure setting the RNG replaces it for the stack and its clones.
+        clone.set_rng(TestRng::fixed(987654321));
+        let expected = TestRng::fixed(987654321).gen::<u64>();
+        assert_eq!(expected, first.sample_with_rng(|rng| rng.next_u64()).unwrap());
+    }
+
+    #[test]
+    fn test_process_set_rng() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program seeded.aleo;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let mut process = Process::<CurrentNetwork>::load_without_keys().unwrap();
+        process.add_program(&program).unwrap();
+        assert!(!process.get_stack(program.id()).unwrap().has_rng());
+
+        // Ensure a seeded RNG is set on the stack of the program.
+        process.set_rng(program.id(), TestRng::fixed(123456789)).unwrap();
+        let stack = process.get_stack(program.id()).unwrap();
+        let expected = TestRng::fixed(123456789).gen::<u64>();
+        assert_eq!(expected, stack.sample_with_rng(|rng| rng.next_u64()).unwrap());
+
+        // Ensure setting an RNG on a program that does not exist fails.
+        assert!(process.set_rng("missing.aleo", TestRng::fixed(123456789)).is_err());
+    }
+
+    #[test]
+    fn test_stack_constraints_generated() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program constraints.aleo;
+
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    add r2 r2 into r3;
+    output r3 as field.private;",
+        )
+        .unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Construct the process.
+        let mut process = Process::<CurrentNetwork>::load_without_keys().unwrap();
+        process.add_program(&program).unwrap();
+        let stack = process.get_stack(program.id()).unwrap();
+
+        // Prepare the authorization.
+        let rng = &mut TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let inputs = [Value::<CurrentNetwork>::from_str("1field").unwrap(), Value::from_str("2field").unwrap()];
+        let authorize = |rng: &mut TestRng| {
+            process.authorize::<CurrentAleo, _>(&private_key, program.id(), function_name, inputs.iter(), rng)
+        };
+
+        // Ensure the dynamic count matches the static estimate, for a straight-line function.
+        let expected = program.complexity(&function_name).unwrap() as usize;
+        let call_stack = CallStack::evaluate(authorize(rng).unwrap()).unwrap();
+        let (_, constraints) = stack.evaluate_function_with_constraints::<CurrentAleo>(call_stack).unwrap();
+        assert_eq!(constraints, expected);
+
+        // Ensure the count is not carried over between evaluations.
+        let call_stack = CallStack::evaluate(authorize(rng).unwrap()).unwrap();
+        let (_, constraints) = stack.evaluate_function_with_constraints::<CurrentAleo>(call_stack).unwrap();
+        assert_eq!(constraints, expected);
+    }
+
+    #[test]
+    fn test_process_address_arithmetic() {
+        // Adds the given function to a new process.
+        let add_function = |function: &str| {
+            let program =
+                Program::<CurrentNetwork>::from_str(&format!("program addresses.aleo;\n\n{function}")).unwrap();
+            let mut process = Process::<CurrentNetwork>::load_without_keys().unwrap();
+            process.add_program(&program)
+        };
+
+        // Ensure an 'add' on addresses is rejected with a specific error.
+        let result = add_function(
+            r"function compute:
+    input r0 as address.public;
+    input r1 as address.private;
+    add r0 r1 into r2;
+    output r2 as address.private;",
+        );
+        assert!(result.unwrap_err().to_string().contains("Type 'address' does not support 'add'"));
+
+        // Ensure a comparison on a string is rejected with a specific error.
+        let result = add_function(
+            r#"function compute:
+    input r0 as u8.public;
+    lt r0 "hello" into r1;
+    output r1 as boolean.private;"#,
+        );
+        assert!(result.unwrap_err().to_string().contains("Type 'string' does not support 'lt'"));
+
+        // Ensure an 'is.eq' on addresses is accepted.
+        let result = add_function(
+            r"function compute:
+    input r0 as address.public;
+    input r1 as address.private;
+    is.eq r0 r1 into r2;
+    output r2 as boolean.private;",
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_process_authorize_record_entry_order() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program token_order.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    amount as u64.private;
+    flag as boolean.private;
+
+function consume:
+    input r0 as token.record;
+    add r0.amount r0.amount into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let mut process = Process::<CurrentNetwork>::load_without_keys().unwrap();
+        process.add_program(&program).unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Authorizes the function call with the given record.
+        let mut authorize = |record: &str| {
+            let input = Value::<CurrentNetwork>::from_str(record).unwrap();
+            process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "consume", [input].iter(), rng)
+        };
+
+        // Ensure a record with its entries in declaration order is accepted.
+        let in_order = format!(
+            "{{ owner: {caller}.private, gates: 5u64.private, amount: 100u64.private, flag: true.private, _nonce: 0group.public }}"
+        );
+        assert!(authorize(&in_order).is_ok());
+
+        // Ensure a record with its entries out of order is rejected.
+        let out_of_order = format!(
+            "{{ owner: {caller}.private, gates: 5u64.private, flag: true.private, amount: 100u64.private, _nonce: 0group.public }}"
+        );
+        assert!(authorize(&out_of_order).is_err());
+
+        // Ensure the record is accepted once its entries are in canonical order.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&out_of_order).unwrap();
+        let canonical =
+            record.to_canonical(&program.get_record(&Identifier::from_str("token").unwrap()).unwrap()).unwrap();
+        assert!(authorize(&canonical.to_string()).is_ok());
+    }
 }
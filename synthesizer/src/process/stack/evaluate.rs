@@ -16,8 +16,14 @@
 
 use super::*;
 
+use std::time::Instant;
+
+/// The number of instructions evaluated between each check of the evaluation deadline.
+const DEADLINE_CHECK_INTERVAL: usize = 64;
+
 impl<N: Network> Stack<N> {
     /// Evaluates a program closure on the given inputs.
+    /// To avoid cloning the inputs into the registers, use `Stack::evaluate_closure_owned`.
     ///
     /// # Errors
     /// This method will halt if the given inputs are not the same length as the input statements.
@@ -29,12 +35,48 @@ impl<N: Network> Stack<N> {
         call_stack: CallStack<N>,
         caller: Address<N>,
         tvk: Field<N>,
+    ) -> Result<Vec<Value<N>>> {
+        self.evaluate_closure_owned::<A>(closure, inputs.to_vec(), call_stack, caller, tvk)
+    }
+
+    /// Evaluates a program closure on the given inputs, moving the inputs into the registers.
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    #[inline]
+    pub fn evaluate_closure_owned<A: circuit::Aleo<Network = N>>(
+        &self,
+        closure: &Closure<N>,
+        inputs: Vec<Value<N>>,
+        call_stack: CallStack<N>,
+        caller: Address<N>,
+        tvk: Field<N>,
+    ) -> Result<Vec<Value<N>>> {
+        self.evaluate_closure_with_deadline::<A>(closure, inputs, call_stack, caller, tvk, None)
+    }
+
+    /// Evaluates a program closure on the given inputs, moving the inputs into the registers,
+    /// and halting if the given deadline passes.
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    /// This method will halt if the deadline passes before the evaluation completes.
+    pub(crate) fn evaluate_closure_with_deadline<A: circuit::Aleo<Network = N>>(
+        &self,
+        closure: &Closure<N>,
+        inputs: Vec<Value<N>>,
+        call_stack: CallStack<N>,
+        caller: Address<N>,
+        tvk: Field<N>,
+        deadline: Option<Instant>,
     ) -> Result<Vec<Value<N>>> {
         let timer = timer!("Stack::evaluate_closure");
 
         // Ensure the number of inputs matches the number of input statements.
         if closure.inputs().len() != inputs.len() {
-            bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
+            return Err(
+                EvaluationError::<N>::ArityMismatch { expected: closure.inputs().len(), found: inputs.len() }.into()
+            );
         }
 
         // Initialize the registers.
@@ -43,20 +85,26 @@ impl<N: Network> Stack<N> {
         registers.set_caller(caller);
         // Set the transition view key.
         registers.set_tvk(tvk);
+        // Set the deadline, so that it also applies to any closures and functions that are called.
+        registers.set_deadline(deadline);
         lap!(timer, "Initialize the registers");
 
         // Store the inputs.
         closure.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
             // Assign the input value to the register.
-            registers.store(self, register, input.clone())
+            registers.store(self, register, input)
         })?;
         lap!(timer, "Store the inputs");
 
         // Evaluate the instructions.
-        for instruction in closure.instructions() {
+        for (index, instruction) in closure.instructions().iter().enumerate() {
+            // If a deadline is set, periodically ensure it has not passed.
+            self.ensure_before_deadline(closure.name(), index, deadline)?;
             // If the evaluation fails, bail and return the error.
             if let Err(error) = instruction.evaluate(self, &mut registers) {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
+                // Note: The error is kept as the source, so that an `EvaluationError` can be recovered from it.
+                let message = format!("Failed to evaluate instruction ({instruction}): {error}");
+                return Err(error.context(message));
             }
         }
         lap!(timer, "Evaluate the instructions");
@@ -79,6 +127,84 @@ impl<N: Network> Stack<N> {
     /// This method will halt if the given inputs are not the same length as the input statements.
     #[inline]
     pub fn evaluate_function<A: circuit::Aleo<Network = N>>(&self, call_stack: CallStack<N>) -> Result<Response<N>> {
+        self.evaluate_function_with_deadline::<A>(call_stack, None).map(|(response, ..)| response)
+    }
+
+    /// Evaluates a program function on the given inputs, and returns the response, along with the number of
+    /// constraints generated by the instructions that were evaluated, weighted by `N::opcode_cost`.
+    ///
+    /// Note: Unlike the static estimate in `Program::complexity`, the count reflects only the instructions
+    /// that were actually evaluated (e.g. up to an early `return`).
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    #[inline]
+    pub fn evaluate_function_with_constraints<A: circuit::Aleo<Network = N>>(
+        &self,
+        call_stack: CallStack<N>,
+    ) -> Result<(Response<N>, usize)> {
+        self.evaluate_function_with_deadline::<A>(call_stack, None)
+            .map(|(response, registers, _)| (response, registers.constraints_generated()))
+    }
+
+    /// Evaluates a program function on the given inputs, and returns the response,
+    /// along with the final value of every register assigned during evaluation.
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn evaluate_function_with_registers<A: circuit::Aleo<Network = N>>(
+        &self,
+        call_stack: CallStack<N>,
+    ) -> Result<(Response<N>, IndexMap<Register<N>, Value<N>>)> {
+        self.evaluate_function_with_deadline::<A>(call_stack, None)
+            .map(|(response, registers, _)| (response, registers.console_registers()))
+    }
+
+    /// Evaluates a program function on the given inputs, and returns the response, along with
+    /// the input values stored and the output values loaded during evaluation, in the order of
+    /// the input and output statements (or of the registers of an early `return`).
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn evaluate_function_with_values<A: circuit::Aleo<Network = N>>(
+        &self,
+        call_stack: CallStack<N>,
+    ) -> Result<(Response<N>, Vec<Value<N>>, Vec<Value<N>>)> {
+        self.evaluate_function_with_deadline::<A>(call_stack, None).map(|(response, _, request)| {
+            let outputs = response.outputs().to_vec();
+            (response, request.inputs().to_vec(), outputs)
+        })
+    }
+
+    /// Evaluates a program function on the given inputs, halting if the given deadline passes.
+    ///
+    /// The deadline is checked before the first instruction, and every `DEADLINE_CHECK_INTERVAL` instructions thereafter.
+    /// The deadline also applies to any closures and functions called by the function.
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    /// This method will halt if the deadline passes before the evaluation completes.
+    #[inline]
+    pub fn evaluate_function_until<A: circuit::Aleo<Network = N>>(
+        &self,
+        call_stack: CallStack<N>,
+        deadline: Instant,
+    ) -> Result<Response<N>> {
+        self.evaluate_function_with_deadline::<A>(call_stack, Some(deadline)).map(|(response, ..)| response)
+    }
+
+    /// Evaluates a program function on the given inputs, with an optional deadline, and returns the response,
+    /// along with the registers used during evaluation, and the request, whose inputs were stored in the registers.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn evaluate_function_with_deadline<A: circuit::Aleo<Network = N>>(
+        &self,
+        call_stack: CallStack<N>,
+        deadline: Option<Instant>,
+    ) -> Result<(Response<N>, Registers<N, A>, Request<N>)> {
         let timer = timer!("Stack::evaluate_function");
 
         // Retrieve the next request, based on the call stack mode.
@@ -105,13 +231,9 @@ impl<N: Network> Stack<N> {
 
         // Ensure the number of inputs matches.
         if function.inputs().len() != inputs.len() {
-            bail!(
-                "Function '{}' in the program '{}' expects {} inputs, but {} were provided.",
-                function.name(),
-                self.program.id(),
-                function.inputs().len(),
-                inputs.len()
-            )
+            return Err(
+                EvaluationError::<N>::ArityMismatch { expected: function.inputs().len(), found: inputs.len() }.into()
+            );
         }
         lap!(timer, "Perform input checks");
 
@@ -121,6 +243,8 @@ impl<N: Network> Stack<N> {
         registers.set_caller(caller);
         // Set the transition view key.
         registers.set_tvk(tvk);
+        // Set the deadline, so that it also applies to any closures and functions that are called.
+        registers.set_deadline(deadline);
         lap!(timer, "Initialize the registers");
 
         // Ensure the request is well-formed.
@@ -134,25 +258,43 @@ impl<N: Network> Stack<N> {
         })?;
         lap!(timer, "Store the inputs");
 
-        // Evaluate the instructions.
-        for instruction in function.instructions() {
+        // Evaluate the instructions, stopping at the first 'return' instruction.
+        let mut returned_registers = None;
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            // If a deadline is set, periodically ensure it has not passed.
+            self.ensure_before_deadline(function.name(), index, deadline)?;
             // If the evaluation fails, bail and return the error.
             if let Err(error) = instruction.evaluate(self, &mut registers) {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
+                // Note: The error is kept as the source, so that an `EvaluationError` can be recovered from it.
+                let message = format!("Failed to evaluate instruction ({instruction}): {error}");
+                return Err(error.context(message));
+            }
+            // Tally the constraints generated by the instruction.
+            registers.tally_constraints(instruction);
+            // If the instruction is a 'return', then stop evaluating the function.
+            if let Instruction::Return(return_) = instruction {
+                returned_registers = Some(return_.registers());
+                break;
             }
         }
         lap!(timer, "Evaluate the instructions");
 
-        // Retrieve the output registers.
-        let output_registers = &function.outputs().iter().map(|output| output.register().clone()).collect::<Vec<_>>();
+        // Retrieve the output operands and registers, which are the returned registers if the function returned early.
+        let (output_operands, output_registers) = match returned_registers {
+            Some(registers) => {
+                (registers.iter().cloned().map(Operand::Register).collect(), registers.into_iter().map(Some).collect())
+            }
+            None => (function.output_operands(), function.output_registers()),
+        };
+        let output_registers = &output_registers;
         lap!(timer, "Retrieve the output registers");
 
         // Load the outputs.
-        let outputs = output_registers
+        let outputs = output_operands
             .iter()
-            .map(|register| {
-                // Retrieve the stack value from the register.
-                registers.load(self, &Operand::Register(register.clone()))
+            .map(|operand| {
+                // Retrieve the stack value from the register, or the literal.
+                registers.load(self, operand)
             })
             .collect::<Result<Vec<_>>>()?;
         lap!(timer, "Load the outputs");
@@ -160,7 +302,7 @@ impl<N: Network> Stack<N> {
         finish!(timer);
 
         // Compute the response.
-        Response::new(
+        let response = Response::new(
             request.network_id(),
             self.program.id(),
             function.name(),
@@ -170,6 +312,137 @@ impl<N: Network> Stack<N> {
             outputs,
             &function.output_types(),
             output_registers,
-        )
+        )?;
+        Ok((response, registers, request))
+    }
+
+    /// Ensures the given deadline (if any) has not passed, before evaluating the instruction at the given index
+    /// of the given closure or function. The deadline is only checked every `DEADLINE_CHECK_INTERVAL` instructions.
+    fn ensure_before_deadline(&self, name: &Identifier<N>, index: usize, deadline: Option<Instant>) -> Result<()> {
+        if let Some(deadline) = deadline {
+            if index % DEADLINE_CHECK_INTERVAL == 0 && Instant::now() >= deadline {
+                return Err(EvaluationError::<N>::Timeout(format!(
+                    "Timed out evaluating '{}/{name}' after {index} instructions",
+                    self.program.id()
+                ))
+                .into());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, Process};
+    use circuit::network::AleoV0;
+    use console::{network::Testnet3, program::Plaintext};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_evaluate_closure_owned() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program closure_inputs.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+closure compute:
+    input r0 as message;
+    input r1 as field;
+    add r0.first r0.second into r2;
+    mul r2 r1 into r3;
+    output r3 as field;
+
+function main:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        )?;
+
+        // Initialize a new process, and retrieve the stack and closure.
+        let mut process = Process::<CurrentNetwork>::load_without_keys()?;
+        process.add_program(&program)?;
+        let stack = process.get_stack(program.id())?;
+        let closure = program.get_closure(&Identifier::from_str("compute")?)?;
+
+        // Initialize the inputs.
+        let inputs = vec![
+            Value::Plaintext(Plaintext::from_str("{ first: 2field, second: 3field }")?),
+            Value::from_str("4field")?,
+        ];
+        let caller = Address::from_str("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah")?;
+        let tvk = Field::from_u64(1);
+        let call_stack = || CallStack::evaluate(Authorization::new(&[]));
+
+        // Ensure the owned and borrowed evaluations match.
+        let expected = vec![Value::from_str("20field")?];
+        let borrowed = stack.evaluate_closure::<CurrentAleo>(&closure, &inputs, call_stack()?, caller, tvk)?;
+        assert_eq!(expected, borrowed);
+        let owned = stack.evaluate_closure_owned::<CurrentAleo>(&closure, inputs, call_stack()?, caller, tvk)?;
+        assert_eq!(expected, owned);
+
+        // Ensure the wrong number of inputs is rejected.
+        let inputs = vec![Value::from_str("4field")?];
+        let error =
+            stack.evaluate_closure_owned::<CurrentAleo>(&closure, inputs, call_stack()?, caller, tvk).unwrap_err();
+        let error = EvaluationError::<CurrentNetwork>::from_error(error);
+        assert_eq!(error, EvaluationError::ArityMismatch { expected: 2, found: 1 });
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluate_call_with_deadline() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program call_deadline.aleo;
+
+closure twice:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+function main:
+    input r0 as field.private;
+    call twice r0 into r1;
+    output r1 as field.private;",
+        )?;
+
+        // Initialize a new process, and retrieve the stack and the 'call' instruction.
+        let mut process = Process::<CurrentNetwork>::load_without_keys()?;
+        process.add_program(&program)?;
+        let stack = process.get_stack(program.id())?;
+        let function = program.get_function(&Identifier::from_str("main")?)?;
+        let instruction = &function.instructions()[0];
+
+        // Initializes the registers for 'main', with the given deadline.
+        let registers = |deadline| -> Result<Registers<CurrentNetwork, CurrentAleo>> {
+            let call_stack = CallStack::evaluate(Authorization::new(&[]))?;
+            let mut registers = Registers::new(call_stack, stack.get_register_types(function.name())?.clone());
+            registers.set_caller(Address::from_str("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah")?);
+            registers.set_tvk(Field::from_u64(1));
+            registers.set_deadline(deadline);
+            registers.store(stack, &Register::Locator(0), Value::from_str("3field")?)?;
+            Ok(registers)
+        };
+
+        // Ensure the call is evaluated without a deadline.
+        let mut registers_without_deadline = registers(None)?;
+        instruction.evaluate(stack, &mut registers_without_deadline)?;
+        let output = registers_without_deadline.load(stack, &Operand::Register(Register::Locator(1)))?;
+        assert_eq!(output, Value::from_str("6field")?);
+
+        // Ensure the deadline of the caller applies to the called closure.
+        let error = instruction.evaluate(stack, &mut registers(Some(Instant::now()))?).unwrap_err();
+        let error = EvaluationError::<CurrentNetwork>::from_error(error);
+        assert!(matches!(error, EvaluationError::Timeout(..)), "{error}");
+        Ok(())
     }
 }
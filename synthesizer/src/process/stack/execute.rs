@@ -195,7 +195,8 @@ impl<N: Network> Stack<N> {
         // Initialize a tracker to determine if there are any function calls.
         let mut contains_function_call = false;
 
-        // Execute the instructions.
+        // Execute the instructions, stopping at the first 'return' instruction.
+        let mut returned_registers = None;
         for instruction in function.instructions() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
@@ -215,15 +216,25 @@ impl<N: Network> Stack<N> {
                     contains_function_call = true;
                 }
             }
+
+            // If the instruction is a 'return', then stop executing the function.
+            if let Instruction::Return(return_) = instruction {
+                returned_registers = Some(return_.registers());
+                break;
+            }
         }
         lap!(timer, "Execute the instructions");
 
-        // Load the outputs.
-        let output_registers = &function.outputs().iter().map(|output| output.register().clone()).collect::<Vec<_>>();
-        let outputs = output_registers
-            .iter()
-            .map(|register| registers.load_circuit(self, &Operand::Register(register.clone())))
-            .collect::<Result<Vec<_>>>()?;
+        // Load the outputs, which are the returned registers if the function returned early.
+        let (output_operands, output_registers) = match returned_registers {
+            Some(registers) => {
+                (registers.iter().cloned().map(Operand::Register).collect(), registers.into_iter().map(Some).collect())
+            }
+            None => (function.output_operands(), function.output_registers()),
+        };
+        let output_registers = &output_registers;
+        let outputs =
+            output_operands.iter().map(|operand| registers.load_circuit(self, operand)).collect::<Result<Vec<_>>>()?;
         lap!(timer, "Load the outputs");
 
         #[cfg(debug_assertions)]
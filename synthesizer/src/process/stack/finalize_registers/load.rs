@@ -65,6 +65,8 @@ impl<N: Network> FinalizeRegisters<N> {
             }
             // If the operand is the caller, load the value of the caller.
             Operand::Caller => bail!("Forbidden operation: Cannot use 'self.caller' in 'finalize'"),
+            // If the operand is a global constant, load the value of the global constant.
+            Operand::Global(name) => return Ok(Value::Plaintext(stack.program().get_global(name)?.1)),
         };
 
         // Retrieve the stack value.
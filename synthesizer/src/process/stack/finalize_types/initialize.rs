@@ -78,6 +78,8 @@ impl<N: Network> FinalizeTypes<N> {
         // Check the destination register.
         match register {
             Register::Locator(locator) => {
+                // Ensure the destination register is not an input register.
+                ensure!(!self.inputs.contains_key(&locator), "Cannot write to input register {register}");
                 // Ensure the registers are monotonically increasing.
                 let expected_locator = (self.inputs.len() as u64) + self.destinations.len() as u64;
                 ensure!(expected_locator == locator, "Register '{register}' is out of order");
@@ -548,6 +550,9 @@ impl<N: Network> FinalizeTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Return => {
+                bail!("Instruction 'return' is not allowed in 'finalize'");
+            }
         }
         Ok(())
     }
@@ -69,6 +69,7 @@ impl<N: Network> FinalizeTypes<N> {
             Operand::Register(register) => self.get_type(stack, register)?,
             Operand::ProgramID(_) => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
             Operand::Caller => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
+            Operand::Global(name) => RegisterType::Plaintext(stack.program().get_global(name)?.0),
         })
     }
 
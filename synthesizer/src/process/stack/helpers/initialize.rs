@@ -29,6 +29,7 @@ impl<N: Network> Stack<N> {
             universal_srs: process.universal_srs().clone(),
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
+            rng: Default::default(),
         };
 
         // Add all of the imports into the stack.
@@ -42,6 +43,13 @@ impl<N: Network> Stack<N> {
             // Add the external stack to the stack.
             stack.insert_external_stack(external_stack.clone())?;
         }
+        // Ensure the program global constants are well-formed.
+        for (name, (plaintext_type, value)) in program.globals() {
+            // Ensure the global value matches the global type.
+            if let Err(error) = stack.matches_plaintext(value, plaintext_type) {
+                bail!("Global '{name}' in program '{}' is invalid: {error}", program.id())
+            }
+        }
         // Add the program closures to the stack.
         for closure in program.closures().values() {
             // Add the closure to the stack.
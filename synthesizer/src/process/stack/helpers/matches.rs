@@ -17,6 +17,22 @@
 use super::*;
 
 impl<N: Network> Stack<N> {
+    /// Returns `true` if the given value matches the declared type of the input at the given index of the function.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist, or does not have an input at the given index.
+    pub fn accepts_input(&self, function_name: &Identifier<N>, index: usize, value: &Value<N>) -> Result<bool> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+        // Retrieve the declared value type of the input.
+        let value_type = match function.inputs().get_index(index) {
+            Some(input) => input.value_type(),
+            None => bail!("Function '{function_name}' does not have an input at index {index}"),
+        };
+        // Determine if the value matches the declared value type.
+        Ok(self.matches_value_type(value, value_type).is_ok())
+    }
+
     /// Checks that the given value matches the layout of the value type.
     pub fn matches_value_type(&self, value: &Value<N>, value_type: &ValueType<N>) -> Result<()> {
         // Ensure the value matches the declared value type in the register.
@@ -48,6 +64,31 @@ impl<N: Network> Stack<N> {
         }
     }
 
+    /// Checks that the given value matches the layout of the value type, as declared in the given program.
+    ///
+    /// Unlike `matches_value_type`, this method does not require a constructed stack,
+    /// and may be used to check a value before the program is added to a process.
+    ///
+    /// # Errors
+    /// This method will halt if the value does not match the value type.
+    /// This method will halt if the value type is an external record, as it cannot be resolved without a stack.
+    pub fn matches_register_value(value: &Value<N>, value_type: &ValueType<N>, program: &Program<N>) -> Result<()> {
+        match (value, value_type) {
+            (Value::Plaintext(plaintext), ValueType::Constant(plaintext_type))
+            | (Value::Plaintext(plaintext), ValueType::Public(plaintext_type))
+            | (Value::Plaintext(plaintext), ValueType::Private(plaintext_type)) => {
+                Self::matches_plaintext_internal(program, plaintext, plaintext_type, 0)
+            }
+            (Value::Record(record), ValueType::Record(record_name)) => {
+                Self::matches_record_in_program(program, record, record_name)
+            }
+            (Value::Record(..), ValueType::ExternalRecord(locator)) => {
+                bail!("External record '{locator}' cannot be resolved without a stack")
+            }
+            _ => bail!("A value does not match its declared value type '{value_type}'"),
+        }
+    }
+
     /// Checks that the given record matches the layout of the external record type.
     pub fn matches_external_record(&self, record: &Record<N, Plaintext<N>>, locator: &Locator<N>) -> Result<()> {
         // Retrieve the record name.
@@ -67,16 +108,32 @@ impl<N: Network> Stack<N> {
             bail!("Expected external record '{record_name}', found external record '{}'", record_type.name())
         }
 
-        self.matches_record_internal(record, &record_type, 0)
+        Self::matches_record_internal(self.program(), record, &record_type, 0)
     }
 
     /// Checks that the given record matches the layout of the record type.
     pub fn matches_record(&self, record: &Record<N, Plaintext<N>>, record_name: &Identifier<N>) -> Result<()> {
+        Self::matches_record_in_program(self.program(), record, record_name)
+    }
+
+    /// Checks that the given plaintext matches the layout of the plaintext type.
+    pub fn matches_plaintext(&self, plaintext: &Plaintext<N>, plaintext_type: &PlaintextType<N>) -> Result<()> {
+        Self::matches_plaintext_internal(self.program(), plaintext, plaintext_type, 0)
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Checks that the given record matches the layout of the record type in the given program.
+    fn matches_record_in_program(
+        program: &Program<N>,
+        record: &Record<N, Plaintext<N>>,
+        record_name: &Identifier<N>,
+    ) -> Result<()> {
         // Ensure the record name is valid.
         ensure!(!Program::is_reserved_keyword(record_name), "Record name '{record_name}' is reserved");
 
         // Retrieve the record type from the program.
-        let record_type = match self.program().get_record(record_name) {
+        let record_type = match program.get_record(record_name) {
             Ok(record_type) => record_type,
             Err(..) => bail!("Record '{record_name}' is not defined in the program"),
         };
@@ -86,21 +143,14 @@ impl<N: Network> Stack<N> {
             bail!("Expected record '{record_name}', found record '{}'", record_type.name())
         }
 
-        self.matches_record_internal(record, &record_type, 0)
+        Self::matches_record_internal(program, record, &record_type, 0)
     }
 
-    /// Checks that the given plaintext matches the layout of the plaintext type.
-    pub fn matches_plaintext(&self, plaintext: &Plaintext<N>, plaintext_type: &PlaintextType<N>) -> Result<()> {
-        self.matches_plaintext_internal(plaintext, plaintext_type, 0)
-    }
-}
-
-impl<N: Network> Stack<N> {
     /// Checks that the given record matches the layout of the record type.
     ///
     /// This method enforces `N::MAX_DATA_DEPTH` and `N::MAX_DATA_ENTRIES` limits.
     fn matches_record_internal(
-        &self,
+        program: &Program<N>,
         record: &Record<N, Plaintext<N>>,
         record_type: &RecordType<N>,
         depth: usize,
@@ -154,7 +204,7 @@ impl<N: Network> Stack<N> {
             // Ensure the entry name is valid.
             ensure!(!Program::is_reserved_keyword(entry_name), "Entry name '{entry_name}' is reserved");
             // Ensure the entry matches (recursive call).
-            self.matches_entry_internal(record_name, entry_name, entry, expected_type, depth + 1)?;
+            Self::matches_entry_internal(program, record_name, entry_name, entry, expected_type, depth + 1)?;
         }
 
         Ok(())
@@ -164,7 +214,7 @@ impl<N: Network> Stack<N> {
     ///
     /// This method enforces `N::MAX_DATA_DEPTH` and `N::MAX_DATA_ENTRIES` limits.
     fn matches_entry_internal(
-        &self,
+        program: &Program<N>,
         record_name: &Identifier<N>,
         entry_name: &Identifier<N>,
         entry: &Entry<N, Plaintext<N>>,
@@ -175,7 +225,7 @@ impl<N: Network> Stack<N> {
             (Entry::Constant(plaintext), EntryType::Constant(plaintext_type))
             | (Entry::Public(plaintext), EntryType::Public(plaintext_type))
             | (Entry::Private(plaintext), EntryType::Private(plaintext_type)) => {
-                match self.matches_plaintext_internal(plaintext, plaintext_type, depth) {
+                match Self::matches_plaintext_internal(program, plaintext, plaintext_type, depth) {
                     Ok(()) => Ok(()),
                     Err(error) => bail!("Invalid record entry '{record_name}.{entry_name}': {error}"),
                 }
@@ -190,7 +240,7 @@ impl<N: Network> Stack<N> {
     ///
     /// This method enforces `N::MAX_DATA_DEPTH` and `N::MAX_DATA_ENTRIES` limits.
     fn matches_plaintext_internal(
-        &self,
+        program: &Program<N>,
         plaintext: &Plaintext<N>,
         plaintext_type: &PlaintextType<N>,
         depth: usize,
@@ -217,7 +267,7 @@ impl<N: Network> Stack<N> {
                 ensure!(!Program::is_reserved_keyword(struct_name), "Struct '{struct_name}' is reserved");
 
                 // Retrieve the struct from the program.
-                let struct_ = match self.program().get_struct(struct_name) {
+                let struct_ = match program.get_struct(struct_name) {
                     Ok(struct_) => struct_,
                     Err(..) => bail!("Struct '{struct_name}' is not defined in the program"),
                 };
@@ -260,7 +310,7 @@ impl<N: Network> Stack<N> {
                     // Ensure the member name is valid.
                     ensure!(!Program::is_reserved_keyword(member_name), "Member name '{member_name}' is reserved");
                     // Ensure the member plaintext matches (recursive call).
-                    self.matches_plaintext_internal(member, expected_type, depth + 1)?;
+                    Self::matches_plaintext_internal(program, member, expected_type, depth + 1)?;
                 }
 
                 Ok(())
@@ -268,3 +318,113 @@ impl<N: Network> Stack<N> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_accepts_input() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program accepts.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function compute:
+    input r0 as message.private;
+    input r1 as u8.public;
+    add r0.first r0.second into r2;
+    output r2 as field.private;",
+        )?;
+
+        // Construct the process.
+        let process = crate::process::test_helpers::sample_process(&program);
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id())?;
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute")?;
+
+        // Ensure matching inputs are accepted.
+        let input = Value::from_str("{ first: 1field, second: 2field }")?;
+        assert!(stack.accepts_input(&function_name, 0, &input)?);
+        let input = Value::from_str("5u8")?;
+        assert!(stack.accepts_input(&function_name, 1, &input)?);
+
+        // Ensure mismatching inputs are rejected.
+        let input = Value::from_str("{ first: 1field }")?;
+        assert!(!stack.accepts_input(&function_name, 0, &input)?);
+        let input = Value::from_str("5u16")?;
+        assert!(!stack.accepts_input(&function_name, 1, &input)?);
+        let input = Value::from_str("5u8")?;
+        assert!(!stack.accepts_input(&function_name, 0, &input)?);
+
+        // Ensure an out-of-bounds index fails.
+        assert!(stack.accepts_input(&function_name, 2, &input).is_err());
+        // Ensure an unknown function fails.
+        assert!(stack.accepts_input(&Identifier::from_str("missing")?, 0, &input).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches_register_value() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program matches.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    contents as message.private;
+
+function compute:
+    input r0 as message.private;
+    add r0.first r0.second into r1;
+    output r1 as field.private;",
+        )?;
+
+        // Declare the value types.
+        let message = ValueType::<CurrentNetwork>::from_str("message.private")?;
+        let token = ValueType::<CurrentNetwork>::from_str("token.record")?;
+        let field = ValueType::<CurrentNetwork>::from_str("field.public")?;
+        let external = ValueType::<CurrentNetwork>::from_str("other.aleo/token.record")?;
+
+        // Ensure conforming values are accepted, without constructing a stack.
+        let value = Value::from_str("{ first: 1field, second: 2field }")?;
+        Stack::matches_register_value(&value, &message, &program)?;
+        let field_value = Value::from_str("5field")?;
+        Stack::matches_register_value(&field_value, &field, &program)?;
+        let record = Value::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 5u64.private, contents: { first: 1field.private, second: 2field.private }, _nonce: 0group.public }",
+        )?;
+        Stack::matches_register_value(&record, &token, &program)?;
+
+        // Ensure non-conforming values are rejected.
+        let value = Value::from_str("{ first: 1field }")?;
+        assert!(Stack::matches_register_value(&value, &message, &program).is_err());
+        let value = Value::from_str("{ first: 1field, third: 2field }")?;
+        assert!(Stack::matches_register_value(&value, &message, &program).is_err());
+        let value = Value::from_str("5u8")?;
+        assert!(Stack::matches_register_value(&value, &field, &program).is_err());
+        assert!(Stack::matches_register_value(&field_value, &message, &program).is_err());
+        assert!(Stack::matches_register_value(&field_value, &token, &program).is_err());
+        assert!(Stack::matches_register_value(&record, &message, &program).is_err());
+
+        // Ensure external records are rejected, as they cannot be resolved without a stack.
+        assert!(Stack::matches_register_value(&record, &external, &program).is_err());
+        Ok(())
+    }
+}
@@ -29,7 +29,7 @@ impl<N: Network> Stack<N> {
             | ValueType::Public(plaintext_type)
             | ValueType::Private(plaintext_type) => Ok(Value::Plaintext(self.sample_plaintext(plaintext_type, rng)?)),
             ValueType::Record(record_name) => {
-                Ok(Value::Record(self.sample_record(burner_address, record_name, rng)?))
+                Ok(Value::from(self.sample_record(burner_address, record_name, rng)?))
             }
             ValueType::ExternalRecord(locator) => {
                 bail!("Illegal operation: Cannot sample external records (for '{locator}.record').")
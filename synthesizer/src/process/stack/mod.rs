@@ -51,6 +51,7 @@ use crate::{
     CallOperator,
     Certificate,
     Closure,
+    EvaluationError,
     Function,
     Instruction,
     Operand,
@@ -77,6 +78,7 @@ use console::{
         ProgramID,
         Record,
         RecordType,
+        Register,
         RegisterType,
         Request,
         Response,
@@ -88,7 +90,8 @@ use console::{
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
+use rand::{CryptoRng, RngCore};
 use std::sync::Arc;
 
 pub type Assignments<N> = Arc<RwLock<Vec<circuit::Assignment<<N as Environment>::Field>>>>;
@@ -197,6 +200,10 @@ pub struct Stack<N: Network> {
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The RNG for randomized opcodes, if one is set.
+    /// Note: The RNG is shared by all clones of the stack (including the copies of the stack that are held
+    /// as external stacks by other programs), so that nested calls draw from a single stream.
+    rng: Arc<Mutex<Option<Box<dyn RngCore + Send>>>>,
 }
 
 impl<N: Network> Stack<N> {
@@ -226,6 +233,41 @@ impl<N: Network> Stack<N> {
         Stack::initialize(process, program)
     }
 
+    /// Sets the RNG for randomized opcodes, and returns the stack.
+    /// Supplying a seeded RNG makes the evaluation of randomized opcodes reproducible.
+    ///
+    /// Note: The returned stack no longer shares an RNG with any prior clones of this stack,
+    /// but any clones of the returned stack share its RNG.
+    #[inline]
+    pub fn with_rng(mut self, rng: impl RngCore + CryptoRng + Send + 'static) -> Self {
+        self.rng = Arc::new(Mutex::new(Some(Box::new(rng))));
+        self
+    }
+
+    /// Sets the RNG for randomized opcodes, replacing the RNG of this stack and of all of its clones.
+    #[inline]
+    pub fn set_rng(&self, rng: impl RngCore + CryptoRng + Send + 'static) {
+        *self.rng.lock() = Some(Box::new(rng));
+    }
+
+    /// Returns `true` if the stack has an RNG for randomized opcodes.
+    #[inline]
+    pub fn has_rng(&self) -> bool {
+        self.rng.lock().is_some()
+    }
+
+    /// Samples from the RNG of the stack, for the evaluation of a randomized opcode.
+    ///
+    /// # Errors
+    /// This method will halt if no RNG is set (see `Stack::with_rng` and `Stack::set_rng`).
+    #[inline]
+    pub fn sample_with_rng<T>(&self, sample: impl FnOnce(&mut dyn RngCore) -> T) -> Result<T> {
+        match self.rng.lock().as_mut() {
+            Some(rng) => Ok(sample(rng.as_mut())),
+            None => bail!("Cannot evaluate a randomized opcode in '{}' without an RNG", self.program.id()),
+        }
+    }
+
     /// Returns the program.
     #[inline]
     pub const fn program(&self) -> &Program<N> {
@@ -308,6 +350,33 @@ impl<N: Network> Stack<N> {
         Ok(num_calls)
     }
 
+    /// Returns the maximum call-nesting depth of the given function, i.e. the length of the longest path
+    /// of calls (to closures or functions, including in imported programs) from the function.
+    /// A function without calls has a depth of `0`.
+    ///
+    /// Note: The recursion terminates, as calls are acyclic. A program may not call itself,
+    /// a closure may not call, and an imported program is added before the programs that import it.
+    #[inline]
+    pub fn call_depth(&self, function_name: &Identifier<N>) -> Result<usize> {
+        let mut depth = 0;
+        for instruction in self.get_function(function_name)?.instructions() {
+            if let Instruction::Call(call) = instruction {
+                // Determine the depth of the callee, where a closure does not call.
+                let callee_depth = match call.is_function_call(self)? {
+                    true => match call.operator() {
+                        CallOperator::Locator(locator) => {
+                            self.get_external_stack(locator.program_id())?.call_depth(locator.resource())?
+                        }
+                        CallOperator::Resource(resource) => self.call_depth(resource)?,
+                    },
+                    false => 0,
+                };
+                depth = depth.max(1 + callee_depth);
+            }
+        }
+        Ok(depth)
+    }
+
     /// Returns the register types for the given closure or function name.
     #[inline]
     pub fn get_register_types(&self, name: &Identifier<N>) -> Result<&RegisterTypes<N>> {
@@ -315,6 +384,16 @@ impl<N: Network> Stack<N> {
         self.register_types.get(name).ok_or_else(|| anyhow!("Register types for '{name}' do not exist"))
     }
 
+    /// Returns the register type of the given operand in the closure or function with the given name.
+    ///
+    /// # Errors
+    /// This method will halt if the register types for the given name do not exist.
+    /// This method will halt if the operand is a register that is not defined in the given closure or function.
+    #[inline]
+    pub fn resolve_operand_type(&self, function_name: &Identifier<N>, operand: &Operand<N>) -> Result<RegisterType<N>> {
+        self.get_register_types(function_name)?.get_type_from_operand(self, operand)
+    }
+
     /// Returns the register types for the given finalize name.
     #[inline]
     pub fn get_finalize_types(&self, name: &Identifier<N>) -> Result<&FinalizeTypes<N>> {
@@ -34,6 +34,8 @@ impl<N: Network> RegisterTypes<N> {
         for instruction in closure.instructions() {
             // Ensure the closure contains no call instructions.
             ensure!(instruction.opcode() != Opcode::Call, "A 'call' instruction is not allowed in closures");
+            // Ensure the closure contains no return instructions.
+            ensure!(instruction.opcode() != Opcode::Return, "A 'return' instruction is not allowed in closures");
             // Check the instruction opcode, operands, and destinations.
             register_types.check_instruction(stack, closure.name(), instruction)?;
         }
@@ -69,12 +71,23 @@ impl<N: Network> RegisterTypes<N> {
         for instruction in function.instructions() {
             // Check the instruction opcode, operands, and destinations.
             register_types.check_instruction(stack, function.name(), instruction)?;
+            // If the instruction is a return, check the returned registers against the declared outputs.
+            if let Instruction::Return(return_) = instruction {
+                register_types.check_return(stack, function, return_)?;
+            }
         }
 
         // Step 3. Check the outputs are well-formed.
         for output in function.outputs() {
-            // Retrieve the register type and check the output register type.
-            register_types.check_output(stack, output.register(), &RegisterType::from(*output.value_type()))?;
+            match output.operand() {
+                // Retrieve the register type and check the output register type.
+                Operand::Register(register) => {
+                    register_types.check_output(stack, register, &RegisterType::from(*output.value_type()))?
+                }
+                // Note: The literal is checked against the output value type in `Program::add_function`.
+                Operand::Literal(..) => (),
+                _ => bail!("Output '{output}' in '{}' must be a register or a literal", function.name()),
+            }
         }
 
         // Step 4. If the function has a finalize command, check that its operands are all defined.
@@ -156,6 +169,8 @@ impl<N: Network> RegisterTypes<N> {
         // Check the destination register.
         match register {
             Register::Locator(locator) => {
+                // Ensure the destination register is not an input register.
+                ensure!(!self.inputs.contains_key(&locator), "Cannot write to input register {register}");
                 // Ensure the registers are monotonically increasing.
                 let expected_locator = (self.inputs.len() as u64) + self.destinations.len() as u64;
                 ensure!(expected_locator == locator, "Register '{register}' is out of order");
@@ -258,6 +273,31 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Ensures the registers of the given return instruction match the declared outputs of the given function,
+    /// in number and in type.
+    #[inline]
+    fn check_return(&self, stack: &Stack<N>, function: &Function<N>, return_: &Return<N>) -> Result<()> {
+        // Ensure the number of returned registers matches the number of outputs.
+        ensure!(
+            return_.operands().len() == function.outputs().len(),
+            "'{return_}' in function '{}' returns {} registers, but {} outputs are declared",
+            function.name(),
+            return_.operands().len(),
+            function.outputs().len()
+        );
+        // Ensure the type of each returned register matches the type of its output.
+        for (register, output) in return_.registers().iter().zip_eq(function.outputs()) {
+            let register_type = self.get_type(stack, register)?;
+            let output_type = RegisterType::from(*output.value_type());
+            ensure!(
+                register_type == output_type,
+                "'{return_}' in function '{}' returns '{register}' of type '{register_type}', but the output is declared as '{output_type}'",
+                function.name()
+            );
+        }
+        Ok(())
+    }
+
     /// Ensures the given instruction is well-formed.
     #[inline]
     fn check_instruction(
@@ -556,6 +596,13 @@ impl<N: Network> RegisterTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Return => {
+                // Ensure the instruction is the return operation.
+                ensure!(
+                    matches!(instruction, Instruction::Return(..)),
+                    "Instruction '{instruction}' is not for opcode 'return'."
+                );
+            }
         }
         Ok(())
     }
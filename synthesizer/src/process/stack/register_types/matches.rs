@@ -84,6 +84,16 @@ impl<N: Network> RegisterTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{caller_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the global type matches the member type.
+                Operand::Global(name) => {
+                    // Retrieve the global type.
+                    let (global_type, _) = stack.program().get_global(name)?;
+                    // Ensure the global type matches the member type.
+                    ensure!(
+                        global_type == *member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{global_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
@@ -127,6 +137,15 @@ impl<N: Network> RegisterTypes<N> {
                 bail!("Forbidden operation: Cannot cast a program ID ('{program_id}') as a record owner")
             }
             Operand::Caller => {}
+            Operand::Global(name) => {
+                // Retrieve the global type.
+                let (global_type, _) = stack.program().get_global(name)?;
+                // Ensure the global type is an address.
+                ensure!(
+                    global_type == PlaintextType::Literal(LiteralType::Address),
+                    "Casting to a record requires the first operand to be an address"
+                );
+            }
         }
 
         // Ensure the second input type is a u64.
@@ -146,6 +165,15 @@ impl<N: Network> RegisterTypes<N> {
                     "Casting to a record requires the second operand to be a u64"
                 )
             }
+            Operand::Global(name) => {
+                // Retrieve the global type.
+                let (global_type, _) = stack.program().get_global(name)?;
+                // Ensure the global type is a u64.
+                ensure!(
+                    global_type == PlaintextType::Literal(LiteralType::U64),
+                    "Casting to a record requires the second operand to be a u64"
+                )
+            }
             // These operand types are never a `u64` type.
             Operand::ProgramID(..) | Operand::Caller => {
                 bail!("Casting to a record requires the second operand to be a u64")
@@ -212,6 +240,16 @@ impl<N: Network> RegisterTypes<N> {
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{caller_type}' in the operand '{operand}'.",
                             )
                         }
+                        // Ensure the global type matches the entry type.
+                        Operand::Global(name) => {
+                            // Retrieve the global type.
+                            let (global_type, _) = stack.program().get_global(name)?;
+                            // Ensure the global type matches the entry type.
+                            ensure!(
+                                global_type == *plaintext_type,
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{global_type}' in the operand '{operand}'.",
+                            )
+                        }
                     }
                 }
             }
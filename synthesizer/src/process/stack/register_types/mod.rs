@@ -17,7 +17,7 @@
 mod initialize;
 mod matches;
 
-use crate::{CallOperator, Closure, Function, Instruction, Opcode, Operand, Program, Stack};
+use crate::{CallOperator, Closure, Function, Instruction, Opcode, Operand, Program, Return, Stack};
 use console::{
     network::prelude::*,
     program::{
@@ -79,6 +79,7 @@ impl<N: Network> RegisterTypes<N> {
             Operand::Register(register) => self.get_type(stack, register)?,
             Operand::ProgramID(_) => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
             Operand::Caller => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
+            Operand::Global(name) => RegisterType::Plaintext(stack.program().get_global(name)?.0),
         })
     }
 
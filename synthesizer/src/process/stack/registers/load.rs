@@ -51,6 +51,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             }
             // If the operand is the caller, load the value of the caller.
             Operand::Caller => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.caller()?)))),
+            // If the operand is a global constant, load the value of the global constant.
+            Operand::Global(name) => return Ok(Value::Plaintext(stack.program().get_global(name)?.1)),
         };
 
         // Retrieve the stack value.
@@ -135,6 +137,12 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
                     self.caller_circuit()?,
                 ))));
             }
+            // If the operand is a global constant, load the value of the global constant.
+            Operand::Global(name) => {
+                return Ok(circuit::Value::Plaintext(circuit::Plaintext::constant(
+                    stack.program().get_global(name)?.1,
+                )));
+            }
         };
 
         // Retrieve the circuit value.
@@ -176,3 +184,112 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         Ok(circuit_value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, Program};
+    use console::{network::Testnet3, program::Identifier};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_load_repeated() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program registers.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function compute:
+    input r0 as message.private;
+    add r0.first r0.second into r1;
+    add r0.first r1 into r2;
+    output r2 as field.private;",
+        )?;
+
+        // Construct the process.
+        let process = crate::process::test_helpers::sample_process(&program);
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("compute")?)?.clone(),
+        );
+
+        // Store the input.
+        let input = Value::Plaintext(Plaintext::from_str("{ first: 2field, second: 3field }")?);
+        registers.store(stack, &Register::Locator(0), input.clone())?;
+
+        // Ensure repeated loads of a register member return the same value.
+        let operand = Operand::Register(Register::from_str("r0.first")?);
+        let expected = Value::Plaintext(Plaintext::from_str("2field")?);
+        assert_eq!(registers.load(stack, &operand)?, expected);
+        assert_eq!(registers.load(stack, &operand)?, expected);
+
+        // Ensure repeated loads of a register return the same value.
+        let operand = Operand::Register(Register::Locator(0));
+        assert_eq!(registers.load(stack, &operand)?, input);
+        assert_eq!(registers.load(stack, &operand)?, input);
+
+        // Ensure a load before the store fails, and a load after the store succeeds.
+        let operand = Operand::Register(Register::Locator(1));
+        assert!(registers.load(stack, &operand).is_err());
+        let output = Value::Plaintext(Plaintext::from_str("5field")?);
+        registers.store(stack, &Register::Locator(1), output.clone())?;
+        assert_eq!(registers.load(stack, &operand)?, output);
+        assert_eq!(registers.load(stack, &operand)?, output);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_literal_operand() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program literals.aleo;
+
+function compute:
+    input r0 as field.private;
+    add r0 1field into r1;
+    output r1 as field.private;",
+        )?;
+
+        // Construct the process.
+        let process = crate::process::test_helpers::sample_process(&program);
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("compute")?)?.clone(),
+        );
+
+        // Sample an address.
+        let private_key = console::account::PrivateKey::<CurrentNetwork>::new(&mut TestRng::default())?;
+        let address = console::account::Address::try_from(&private_key)?.to_string();
+        // Construct a string of the maximum length.
+        let string = format!("\"{}\"", "a".repeat(CurrentNetwork::MAX_STRING_BYTES as usize));
+
+        // Ensure literal operands of each kind load as plaintext values, on both load paths.
+        for literal in ["1field", "-5i8", "255u8", "true", "0group", "3scalar", &address, &string] {
+            let operand = Operand::Literal(Literal::from_str(literal)?);
+            let expected = Value::Plaintext(Plaintext::from_str(literal)?);
+            assert_eq!(registers.load(stack, &operand)?, expected);
+            assert_eq!(circuit::Eject::eject_value(&registers.load_circuit(stack, &operand)?), expected);
+        }
+
+        // Ensure a malformed literal is rejected when parsed, so it never reaches the load path.
+        assert!(Operand::<CurrentNetwork>::from_str("256u8").is_err());
+        assert!(Operand::<CurrentNetwork>::from_str("-1u8").is_err());
+        Ok(())
+    }
+}
@@ -17,7 +17,7 @@
 mod load;
 mod store;
 
-use crate::{CallStack, Operand, RegisterTypes, Stack};
+use crate::{CallStack, EvaluationError, Instruction, Operand, RegisterTypes, Stack};
 use console::{
     network::prelude::*,
     program::{Entry, Literal, Plaintext, Register, Value},
@@ -25,6 +25,7 @@ use console::{
 };
 
 use indexmap::IndexMap;
+use std::time::Instant;
 
 #[derive(Clone)]
 pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
@@ -44,6 +45,10 @@ pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
     tvk: Option<Field<N>>,
     /// The transition view key, as a circuit.
     tvk_circuit: Option<circuit::Field<A>>,
+    /// The running count of constraints generated by the evaluated instructions.
+    constraints_generated: usize,
+    /// The deadline by which the evaluation must complete, if any.
+    deadline: Option<Instant>,
 }
 
 impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
@@ -59,6 +64,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             caller_circuit: None,
             tvk: None,
             tvk_circuit: None,
+            constraints_generated: 0,
+            deadline: None,
         }
     }
 
@@ -68,6 +75,12 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         self.call_stack.clone()
     }
 
+    /// Returns the assigned console registers, as a map from each register to its value.
+    #[inline]
+    pub fn console_registers(&self) -> IndexMap<Register<N>, Value<N>> {
+        self.console_registers.iter().map(|(index, value)| (Register::Locator(*index), value.clone())).collect()
+    }
+
     /// Returns the transition caller.
     #[inline]
     pub fn caller(&self) -> Result<Address<N>> {
@@ -116,6 +129,32 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         self.tvk_circuit = Some(tvk_circuit);
     }
 
+    /// Returns the deadline by which the evaluation must complete, if any.
+    #[inline]
+    pub const fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Sets the deadline by which the evaluation must complete.
+    #[inline]
+    pub fn set_deadline(&mut self, deadline: Option<Instant>) {
+        self.deadline = deadline;
+    }
+
+    /// Returns the running count of constraints generated by the instructions evaluated with these registers,
+    /// weighted by `N::opcode_cost` (as in `Program::complexity`).
+    #[inline]
+    pub const fn constraints_generated(&self) -> usize {
+        self.constraints_generated
+    }
+
+    /// Adds the constraints generated by the given (evaluated) instruction to the running count.
+    #[inline]
+    pub fn tally_constraints(&mut self, instruction: &Instruction<N>) {
+        let cost = usize::try_from(N::opcode_cost(*instruction.opcode())).unwrap_or(usize::MAX);
+        self.constraints_generated = self.constraints_generated.saturating_add(cost);
+    }
+
     /// Ensure the console and circuit registers match.
     #[inline]
     pub fn ensure_console_and_circuit_registers_match(&self) -> Result<()> {
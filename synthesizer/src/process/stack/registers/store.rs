@@ -38,6 +38,11 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
     pub fn store(&mut self, stack: &Stack<N>, register: &Register<N>, stack_value: Value<N>) -> Result<()> {
         match register {
             Register::Locator(locator) => {
+                // Ensure the register is not an (assigned) input register.
+                ensure!(
+                    !(self.register_types.is_input(register) && self.console_registers.contains_key(locator)),
+                    "Cannot write to input register {register}"
+                );
                 // Ensure the register assignments are monotonically increasing.
                 let expected_locator = self.console_registers.len() as u64;
                 ensure!(expected_locator == *locator, "Out-of-order write operation at '{register}'");
@@ -50,7 +55,9 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
                 // Ensure the register type is valid.
                 match self.register_types.get_type(stack, register) {
                     // Ensure the stack value matches the register type.
-                    Ok(register_type) => stack.matches_register_type(&stack_value, &register_type)?,
+                    Ok(register_type) => stack
+                        .matches_register_type(&stack_value, &register_type)
+                        .map_err(|error| EvaluationError::<N>::TypeMismatch(error.to_string()))?,
                     // Ensure the register is defined.
                     Err(error) => bail!("Register '{register}' is missing a type definition: {error}"),
                 };
@@ -101,6 +108,11 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
     ) -> Result<()> {
         match register {
             Register::Locator(locator) => {
+                // Ensure the register is not an (assigned) input register.
+                ensure!(
+                    !(self.register_types.is_input(register) && self.circuit_registers.contains_key(locator)),
+                    "Cannot write to input register {register}"
+                );
                 // Ensure the register assignments are monotonically increasing.
                 let expected_locator = self.circuit_registers.len() as u64;
                 ensure!(expected_locator == *locator, "Out-of-order write operation at '{register}'");
@@ -133,3 +145,68 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, Program};
+    use console::{network::Testnet3, program::Identifier};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_store_input_register_fails() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program registers.aleo;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+
+        // Construct the process.
+        let mut process = crate::process::test_helpers::sample_process(&program);
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id())?.clone();
+
+        // Ensure a function that writes to an input register is rejected when added.
+        let invalid = Program::<CurrentNetwork>::from_str(
+            r"
+program invalid_registers.aleo;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r0;
+    output r0 as field.private;",
+        )?;
+        let error = process.add_program(&invalid).unwrap_err();
+        assert!(error.to_string().contains("Cannot write to input register r0"), "{error}");
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("compute")?)?.clone(),
+        );
+
+        // Store the inputs.
+        let input = Value::Plaintext(Plaintext::from_str("2field")?);
+        registers.store(&stack, &Register::Locator(0), input.clone())?;
+        registers.store(&stack, &Register::Locator(1), input.clone())?;
+
+        // Ensure the inputs cannot be overwritten at runtime.
+        for index in 0..2 {
+            let error = registers.store(&stack, &Register::Locator(index), input.clone()).unwrap_err();
+            assert_eq!(error.to_string(), format!("Cannot write to input register r{index}"));
+        }
+        // Ensure the input is unchanged, and the destination can be written.
+        assert_eq!(registers.load(&stack, &Operand::Register(Register::Locator(0)))?, input);
+        registers.store(&stack, &Register::Locator(2), Value::Plaintext(Plaintext::from_str("4field")?))?;
+
+        Ok(())
+    }
+}
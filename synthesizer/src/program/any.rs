@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+/// An object-safe interface to a program, for storing programs of different networks together,
+/// e.g. as a `Box<dyn AnyProgram>`.
+pub trait AnyProgram {
+    /// Returns the program ID, as a string.
+    fn id_string(&self) -> String;
+
+    /// Returns the names of the functions in the program, in declaration order.
+    fn function_names(&self) -> Vec<String>;
+
+    /// Evaluates the function with the given name on the given inputs, as a JSON array of values,
+    /// by a caller sampled from the given RNG, and returns the outputs, as a JSON array of values.
+    fn evaluate_json(
+        &self,
+        function_name: &str,
+        inputs: &serde_json::Value,
+        rng: &mut dyn RngCore,
+    ) -> Result<serde_json::Value>;
+}
+
+impl<N: Network> AnyProgram for Program<N> {
+    /// Returns the program ID, as a string.
+    fn id_string(&self) -> String {
+        self.id().to_string()
+    }
+
+    /// Returns the names of the functions in the program, in declaration order.
+    fn function_names(&self) -> Vec<String> {
+        self.functions().keys().map(ToString::to_string).collect()
+    }
+
+    /// Evaluates the function with the given name on the given inputs, as a JSON array of values,
+    /// by a caller sampled from the given RNG, and returns the outputs, as a JSON array of values.
+    ///
+    /// Note: The program is evaluated in a new process (see `Program::to_process`).
+    ///
+    /// # Errors
+    /// This method will halt if the network does not have a circuit environment to evaluate in.
+    fn evaluate_json(
+        &self,
+        function_name: &str,
+        inputs: &serde_json::Value,
+        rng: &mut dyn RngCore,
+    ) -> Result<serde_json::Value> {
+        // Seed a cryptographically secure RNG from the given RNG, to sample the caller.
+        let rng = &mut StdRng::from_rng(rng)?;
+        // Evaluate the function in the circuit environment of the network.
+        match N::ID {
+            console::network::Testnet3::ID => {
+                let program = crate::cast_ref!((*self) as Program<console::network::Testnet3>);
+                program.evaluate_json_as::<circuit::AleoV0>(function_name, inputs, rng)
+            }
+            _ => bail!("Unsupported circuit environment for network: {}", N::ID),
+        }
+    }
+}
+
+impl<N: Network> Program<N> {
+    /// Evaluates the function with the given name on the given inputs, as a JSON array of values,
+    /// using the circuit environment `A`, and returns the outputs, as a JSON array of values.
+    fn evaluate_json_as<A: circuit::Aleo<Network = N>>(
+        &self,
+        function_name: &str,
+        inputs: &serde_json::Value,
+        rng: &mut StdRng,
+    ) -> Result<serde_json::Value> {
+        // Parse the function name and inputs.
+        let function_name = Identifier::<N>::from_str(function_name)?;
+        let inputs: Vec<Value<N>> = serde_json::from_value(inputs.clone())?;
+        // Evaluate the function.
+        let outputs = self.evaluate::<A, _>(&self.to_process()?, &function_name, &inputs, rng)?;
+        Ok(serde_json::to_value(outputs)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::MirrorNetwork;
+    use console::network::Testnet3;
+
+    #[test]
+    fn test_any_program() -> Result<()> {
+        // Initialize a program.
+        let program = r"
+program first.aleo;
+
+function sum:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;";
+
+        // Store the program on two different networks together.
+        let programs: Vec<Box<dyn AnyProgram>> = vec![
+            Box::new(Program::<Testnet3>::from_str(program)?),
+            Box::new(Program::<MirrorNetwork>::from_str(program)?),
+            Box::new(Program::<Testnet3>::credits()?),
+        ];
+
+        // Ensure the program IDs and function names are exposed.
+        assert_eq!(programs[0].id_string(), "first.aleo");
+        assert_eq!(programs[0].function_names(), vec!["sum".to_string()]);
+        assert_eq!(programs[1].id_string(), "first.aleo");
+        assert_eq!(programs[1].function_names(), vec!["sum".to_string()]);
+        assert_eq!(programs[2].id_string(), "credits.aleo");
+        assert!(programs[2].function_names().contains(&"transfer".to_string()));
+
+        let rng = &mut TestRng::default();
+
+        // Ensure a function evaluates from JSON.
+        let outputs = programs[0].evaluate_json("sum", &serde_json::json!(["2field", "3field"]), rng)?;
+        assert_eq!(outputs, serde_json::json!(["5field"]));
+
+        // Ensure invalid inputs fail.
+        assert!(programs[0].evaluate_json("sum", &serde_json::json!(["2field"]), rng).is_err());
+        assert!(programs[0].evaluate_json("sum", &serde_json::json!("2field"), rng).is_err());
+        assert!(programs[0].evaluate_json("missing", &serde_json::json!(["2field", "3field"]), rng).is_err());
+
+        // Ensure a network without a circuit environment fails to evaluate.
+        let error = programs[1].evaluate_json("sum", &serde_json::json!(["2field", "3field"]), rng).unwrap_err();
+        assert_eq!(error.to_string(), format!("Unsupported circuit environment for network: {}", u16::MAX));
+
+        Ok(())
+    }
+}
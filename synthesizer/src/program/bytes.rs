@@ -16,12 +16,45 @@
 
 use super::*;
 
+impl<N: Network> Program<N> {
+    /// The current version of the program byte format.
+    pub const BYTES_VERSION: u16 = 0;
+
+    /// Returns the program in the current version of the byte format, which is prefixed by its `u16` version.
+    pub fn to_version_bytes(&self) -> Result<Vec<u8>> {
+        // Note: The byte format of a program is prefixed by its version.
+        self.to_bytes_le()
+    }
+
+    /// Reads a program from the given bytes, dispatching on the `u16` version prefix.
+    ///
+    /// # Errors
+    /// This method will halt if the bytes do not start with a version prefix.
+    /// This method will halt if the version is unknown, such as one written by a newer release.
+    /// This method will halt if the bytes are malformed for their version.
+    pub fn from_version_bytes(bytes: &[u8]) -> Result<Self> {
+        // Read the version.
+        let version = match bytes {
+            [first, second, ..] => u16::from_le_bytes([*first, *second]),
+            _ => bail!("Failed to read program bytes: missing the format version"),
+        };
+        // Read the program in the format of its version.
+        match version {
+            0 => Self::from_bytes_le(bytes),
+            _ => bail!(
+                "Failed to read program bytes: unknown format version {version} (the latest supported version is {})",
+                Self::BYTES_VERSION
+            ),
+        }
+    }
+}
+
 impl<N: Network> FromBytes for Program<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version != Self::BYTES_VERSION {
             return Err(error("Invalid program version"));
         }
 
@@ -55,6 +88,13 @@ impl<N: Network> FromBytes for Program<N> {
                 3 => program.add_closure(Closure::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
                 // Read the function.
                 4 => program.add_function(Function::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                // Read the global constant.
+                5 => {
+                    let name = Identifier::read_le(&mut reader)?;
+                    let plaintext_type = PlaintextType::read_le(&mut reader)?;
+                    let value = Plaintext::read_le(&mut reader)?;
+                    program.add_global(name, plaintext_type, value).map_err(|e| error(e.to_string()))?
+                }
                 // Invalid variant.
                 _ => return Err(error(format!("Failed to parse program. Invalid component variant '{variant}'"))),
             }
@@ -67,7 +107,7 @@ impl<N: Network> FromBytes for Program<N> {
 impl<N: Network> ToBytes for Program<N> {
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        0u16.write_le(&mut writer)?;
+        Self::BYTES_VERSION.write_le(&mut writer)?;
 
         // Write the program ID.
         self.id.write_le(&mut writer)?;
@@ -129,6 +169,19 @@ impl<N: Network> ToBytes for Program<N> {
                     }
                     None => return Err(error(format!("Function '{identifier}' is not defined."))),
                 },
+                ProgramDefinition::Global => match self.globals.get(identifier) {
+                    Some((plaintext_type, value)) => {
+                        // Write the variant.
+                        5u8.write_le(&mut writer)?;
+                        // Write the global name.
+                        identifier.write_le(&mut writer)?;
+                        // Write the global type.
+                        plaintext_type.write_le(&mut writer)?;
+                        // Write the global value.
+                        value.write_le(&mut writer)?;
+                    }
+                    None => return Err(error(format!("Global '{identifier}' is not defined."))),
+                },
             }
         }
 
@@ -170,4 +223,93 @@ function compute:
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_backward_compatible() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    token_amount as u64.private;
+
+function compute:
+    input r0 as token.record;
+    add r0.token_amount r0.token_amount into r1;
+    output r1 as u64.private;
+    output r0.owner as address.public;",
+        )?;
+
+        // Ensure bytes written before literal outputs were supported still decode to the same program,
+        // and that the program is still written as the same bytes.
+        // Note: If this test fails, previously-stored programs can no longer be read.
+        let expected = concat!(
+            "000005746f6b656e04616c656f0002000205746f6b656e010101000c746f6b656e5f616d6f756e7402000c000407636f6d7075",
+            "7465010000000305746f6b656e01000000020001010001000c746f6b656e5f616d6f756e7401010001000c746f6b656e5f616d",
+            "6f756e7400010200000102000c0001000100056f776e65720100000000",
+        );
+        let bytes = (0..expected.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&expected[i..i + 2], 16))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(program, Program::<CurrentNetwork>::from_bytes_le(&bytes)?);
+        assert_eq!(bytes, program.to_bytes_le()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_with_attributes() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program attributes.aleo;
+
+@view
+@inline
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+        let compute = Identifier::from_str("compute")?;
+        assert_eq!(program.function_attributes(&compute)?.len(), 2);
+
+        // Ensure the program round-trips, as the attributes are excluded from equality.
+        let bytes = program.to_bytes_le()?;
+        let candidate = Program::<CurrentNetwork>::from_bytes_le(&bytes)?;
+        assert_eq!(program, candidate);
+        assert_eq!(bytes, candidate.to_bytes_le()?);
+
+        // Ensure the attributes are not serialized, as they are source-only.
+        assert!(candidate.function_attributes(&compute)?.is_empty());
+        let plain = Program::<CurrentNetwork>::from_str(&program.to_string().replace("@view\n@inline\n", ""))?;
+        assert!(plain.function_attributes(&compute)?.is_empty());
+        assert_eq!(bytes, plain.to_bytes_le()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_version_bytes() -> Result<()> {
+        let program = Program::<CurrentNetwork>::credits()?;
+
+        // Ensure the current version round-trips, and is prefixed by the version.
+        let bytes = program.to_version_bytes()?;
+        assert_eq!(bytes[..2], Program::<CurrentNetwork>::BYTES_VERSION.to_le_bytes());
+        assert_eq!(program, Program::from_version_bytes(&bytes)?);
+
+        // Ensure an unknown version is rejected with a clear error.
+        let mut bumped = bytes.clone();
+        bumped[..2].copy_from_slice(&(Program::<CurrentNetwork>::BYTES_VERSION + 1).to_le_bytes());
+        let error = Program::<CurrentNetwork>::from_version_bytes(&bumped).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to read program bytes: unknown format version 1 (the latest supported version is 0)"
+        );
+
+        // Ensure bytes without a version are rejected.
+        assert!(Program::<CurrentNetwork>::from_version_bytes(&bytes[..1]).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Program<N> {
+    /// Returns a copy of the program in canonical form.
+    ///
+    /// The imports are sorted by program ID, and the definitions are sorted in topological order,
+    /// with ties broken in lexicographic order of their names. As the `Display` output of a program
+    /// is generated from its definitions, the whitespace and comments of the source are normalized.
+    /// Two programs that differ only in the order of their imports and definitions canonicalize
+    /// to the same `Display` output.
+    pub fn canonicalize(&self) -> Program<N> {
+        // Determine the local dependencies of each definition.
+        let mut dependencies = self
+            .identifiers
+            .iter()
+            .map(|(name, definition)| {
+                let mut names = self.referenced_names(name, definition);
+                names.retain(|dependency| dependency != name && self.identifiers.contains_key(dependency));
+                (*name, names)
+            })
+            .collect::<IndexMap<_, _>>();
+
+        // Order the definitions, by repeatedly selecting the lexicographically-smallest definition
+        // whose dependencies have all been selected.
+        let mut order = Vec::with_capacity(self.identifiers.len());
+        while !dependencies.is_empty() {
+            let next = dependencies
+                .iter()
+                .filter(|(_, names)| names.is_empty())
+                .map(|(name, _)| *name)
+                .min_by_key(|name| name.to_string())
+                // Note: A cycle is unreachable for a well-formed program; fall back to declaration order.
+                .unwrap_or_else(|| *dependencies.keys().next().unwrap());
+            dependencies.shift_remove(&next);
+            dependencies.values_mut().for_each(|names| {
+                names.shift_remove(&next);
+            });
+            order.push(next);
+        }
+
+        // Construct the canonical program.
+        let mut program = self.clone();
+        program.imports.sort_keys();
+        program.identifiers = order.iter().filter_map(|name| Some((*name, *self.identifiers.get(name)?))).collect();
+        // Align the order of each declaration map with the order of the identifiers.
+        let position = |name: &Identifier<N>| program.identifiers.get_index_of(name);
+        program.mappings.sort_by(|a, _, b, _| position(a).cmp(&position(b)));
+        program.structs.sort_by(|a, _, b, _| position(a).cmp(&position(b)));
+        program.records.sort_by(|a, _, b, _| position(a).cmp(&position(b)));
+        program.closures.sort_by(|a, _, b, _| position(a).cmp(&position(b)));
+        program.functions.sort_by(|a, _, b, _| position(a).cmp(&position(b)));
+        program.globals.sort_by(|a, _, b, _| position(a).cmp(&position(b)));
+        program
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_canonicalize() -> Result<()> {
+        let first = Program::<CurrentNetwork>::from_str(
+            r"
+import foo.aleo;
+import bar.aleo;
+
+program canonical.aleo;
+
+// The message struct.
+struct message:
+    first as field;
+    second as field;
+
+struct envelope:
+    inner as message;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    contents as envelope.private;
+
+closure unwrap:
+    input r0 as envelope;
+    cast r0.inner.first r0.inner.second into r1 as message;
+    output r1 as message;
+
+function compute:
+    input r0 as token.record;
+    call unwrap r0.contents into r1;
+    output r1 as message.private;
+
+function twice:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        )?;
+
+        let second = Program::<CurrentNetwork>::from_str(
+            r"
+import bar.aleo;
+import foo.aleo;
+program canonical.aleo;
+function twice:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+struct message:
+    first as field;
+    second as field;
+struct envelope:
+    inner as message;
+closure unwrap:
+    input r0 as envelope;
+    cast r0.inner.first r0.inner.second into r1 as message;
+    output r1 as message;
+record token:
+    owner as address.private;
+    gates as u64.private;
+    contents as envelope.private;
+function compute:
+    input r0 as token.record; // The input record.
+    call unwrap r0.contents into r1;
+    output r1 as message.private;",
+        )?;
+
+        // Ensure the programs differ before canonicalization.
+        assert_ne!(first.to_string(), second.to_string());
+
+        // Ensure the programs canonicalize to the same output.
+        let first = first.canonicalize();
+        let second = second.canonicalize();
+        assert_eq!(first.to_string(), second.to_string());
+        assert_eq!(first.to_bytes_le()?, second.to_bytes_le()?);
+
+        // Ensure the canonical order is topological, then lexicographic.
+        let expected = r"import bar.aleo;
+import foo.aleo;
+
+program canonical.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+struct envelope:
+    inner as message;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    contents as envelope.private;
+
+function twice:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+
+closure unwrap:
+    input r0 as envelope;
+    cast r0.inner.first r0.inner.second into r1 as message;
+    output r1 as message;
+
+function compute:
+    input r0 as token.record;
+    call unwrap r0.contents into r1;
+    output r1 as message.private;
+";
+        assert_eq!(first.to_string(), expected);
+
+        // Ensure canonicalization is idempotent.
+        assert_eq!(first.canonicalize().to_string(), first.to_string());
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A snapshot of the definitions in a program, used to roll back definitions added after it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProgramCheckpoint<N: Network> {
+    /// The ID of the program.
+    id: ProgramID<N>,
+    /// The IDs of the imports.
+    imports: IndexSet<ProgramID<N>>,
+    /// The names of the definitions, and their kinds.
+    identifiers: IndexMap<Identifier<N>, ProgramDefinition>,
+    /// The names of the functions with attributes.
+    function_attributes: IndexSet<Identifier<N>>,
+}
+
+impl<N: Network> Program<N> {
+    /// Returns a checkpoint of the current definitions in the program.
+    ///
+    /// A checkpoint records the names of the definitions, rather than the definitions themselves,
+    /// and `Program::restore` removes every definition that is not named in it.
+    #[inline]
+    pub fn checkpoint(&self) -> ProgramCheckpoint<N> {
+        ProgramCheckpoint {
+            id: self.id,
+            imports: self.imports.keys().copied().collect(),
+            identifiers: self.identifiers.clone(),
+            function_attributes: self.function_attributes.keys().copied().collect(),
+        }
+    }
+
+    /// Restores the program to the given checkpoint, removing every definition added after it.
+    ///
+    /// Note: A checkpoint only tracks the names of definitions; edits to existing definitions
+    /// (such as renames or replacements) are not rolled back.
+    ///
+    /// # Errors
+    /// This method will halt if the checkpoint was taken from a different program.
+    /// This method will halt if a definition in the checkpoint has been removed (or redefined as another kind) since.
+    pub fn restore(&mut self, checkpoint: ProgramCheckpoint<N>) -> Result<()> {
+        // Ensure the checkpoint is for this program.
+        ensure!(checkpoint.id == self.id, "Checkpoint is for '{}', not '{}'", checkpoint.id, self.id);
+        // Ensure the program has not lost definitions since the checkpoint, as they cannot be restored.
+        for import_id in &checkpoint.imports {
+            ensure!(self.imports.contains_key(import_id), "Import '{import_id}' was removed after the checkpoint");
+        }
+        for (name, definition) in &checkpoint.identifiers {
+            ensure!(
+                self.identifiers.get(name) == Some(definition),
+                "Definition '{name}' in '{}' was removed after the checkpoint",
+                self.id
+            );
+        }
+
+        // Remove the definitions added after the checkpoint.
+        let identifiers = &checkpoint.identifiers;
+        self.imports.retain(|import_id, _| checkpoint.imports.contains(import_id));
+        self.identifiers.retain(|name, _| identifiers.contains_key(name));
+        self.mappings.retain(|name, _| identifiers.contains_key(name));
+        self.structs.retain(|name, _| identifiers.contains_key(name));
+        self.records.retain(|name, _| identifiers.contains_key(name));
+        self.closures.retain(|name, _| identifiers.contains_key(name));
+        self.functions.retain(|name, _| identifiers.contains_key(name));
+        self.globals.retain(|name, _| identifiers.contains_key(name));
+        self.function_attributes.retain(|name, _| checkpoint.function_attributes.contains(name));
+        // Remove the source spans of the removed functions.
+        let functions = &self.functions;
+        self.instruction_spans.retain(|name, _| functions.contains_key(name));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_checkpoint_restore() -> Result<()> {
+        // Initialize a new program.
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r"
+program batch.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+function compute:
+    input r0 as point.private;
+    add r0.x r0.y into r1;
+    output r1 as field.private;",
+        )?;
+        let expected = program.clone();
+
+        // Take a checkpoint before the batch.
+        let checkpoint = program.checkpoint();
+
+        // Add a batch of definitions, where the last definition fails.
+        let mut batch = || -> Result<()> {
+            program.add_struct(Struct::from_str("struct line:\n    start as point;\n    end as point;")?)?;
+            program.add_function(Function::from_str(
+                "function twice:\n    input r0 as field.private;\n    add r0 r0 into r1;\n    output r1 as field.private;",
+            )?)?;
+            program.add_function_attributes(&Identifier::from_str("twice")?, vec![Identifier::from_str("inline")?])?;
+            // Note: This function conflicts with the existing function `compute`.
+            program.add_function(Function::from_str(
+                "function compute:\n    input r0 as field.private;\n    add r0 r0 into r1;\n    output r1 as field.private;",
+            )?)
+        };
+        assert!(batch().is_err());
+        assert!(program.contains_struct(&Identifier::from_str("line")?));
+        assert!(program.contains_function(&Identifier::from_str("twice")?));
+
+        // Ensure restoring the checkpoint rolls back the batch.
+        program.restore(checkpoint.clone())?;
+        assert_eq!(program, expected);
+        assert_eq!(program.to_string(), expected.to_string());
+        assert!(!program.contains_struct(&Identifier::from_str("line")?));
+        assert!(!program.contains_function(&Identifier::from_str("twice")?));
+
+        // Ensure the rolled back names can be added again.
+        program.add_function(Function::from_str(
+            "function twice:\n    input r0 as field.private;\n    add r0 r0 into r1;\n    output r1 as field.private;",
+        )?)?;
+
+        // Ensure a checkpoint from another program is rejected.
+        let mut other = Program::<CurrentNetwork>::new(ProgramID::from_str("other.aleo")?)?;
+        assert!(other.restore(checkpoint.clone()).is_err());
+
+        // Ensure a checkpoint with more definitions than the program is rejected.
+        let mut empty = Program::<CurrentNetwork>::new(ProgramID::from_str("batch.aleo")?)?;
+        assert!(empty.restore(checkpoint).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_restore_after_retain() -> Result<()> {
+        // Initialize a new program.
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r"
+program batch.aleo;
+
+function first:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+
+function second:
+    input r0 as field.private;
+    mul r0 r0 into r1;
+    output r1 as field.private;",
+        )?;
+        let first = Identifier::from_str("first")?;
+        let second = Identifier::from_str("second")?;
+
+        // Take a checkpoint, remove a function, and add another in its place.
+        let checkpoint = program.checkpoint();
+        program.retain_functions(&IndexSet::from([first]))?;
+        program.add_function(Function::from_str(
+            "function third:\n    input r0 as field.private;\n    sub r0 r0 into r1;\n    output r1 as field.private;",
+        )?)?;
+        let expected = program.clone();
+
+        // Ensure the checkpoint cannot be restored, as it cannot restore the removed function,
+        // and the program is unchanged.
+        let error = program.restore(checkpoint).unwrap_err();
+        assert!(error.to_string().contains("'second'"), "{error}");
+        assert_eq!(program, expected);
+
+        // Ensure a checkpoint taken after the removal rolls back only the later additions.
+        let checkpoint = program.checkpoint();
+        program.add_function(Function::from_str(
+            "function fourth:\n    input r0 as field.private;\n    sub r0 r0 into r1;\n    output r1 as field.private;",
+        )?)?;
+        program.restore(checkpoint)?;
+        assert_eq!(program, expected);
+        assert!(program.contains_function(&first));
+        assert!(!program.contains_function(&second));
+        assert!(program.contains_function(&Identifier::from_str("third")?));
+        assert!(!program.contains_function(&Identifier::from_str("fourth")?));
+        Ok(())
+    }
+}
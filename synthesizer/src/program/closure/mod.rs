@@ -23,7 +23,7 @@ use output::*;
 mod bytes;
 mod parse;
 
-use crate::Instruction;
+use crate::{Instruction, Operand};
 use console::{
     network::prelude::*,
     program::{Identifier, Register},
@@ -69,6 +69,28 @@ impl<N: Network> Closure<N> {
     pub const fn outputs(&self) -> &IndexSet<Output<N>> {
         &self.outputs
     }
+
+    /// Returns the closure, with each register in its inputs, instructions, and outputs replaced by the given function.
+    ///
+    /// # Errors
+    /// This method will halt if a renamed input, destination, or output is not a register.
+    /// This method will halt if the renamed closure is malformed, as when parsed.
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        let mut renamed = Self::new(self.name);
+        for input in &self.inputs {
+            renamed.add_input(input.rename_registers(rename)?)?;
+        }
+        for instruction in &self.instructions {
+            renamed.add_instruction(instruction.rename_registers(rename)?)?;
+        }
+        for output in &self.outputs {
+            renamed.add_output(output.rename_registers(rename)?)?;
+        }
+        Ok(renamed)
+    }
 }
 
 impl<N: Network> Closure<N> {
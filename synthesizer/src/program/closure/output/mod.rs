@@ -17,6 +17,7 @@
 mod bytes;
 mod parse;
 
+use crate::Operand;
 use console::{
     network::prelude::*,
     program::{Register, RegisterType},
@@ -45,6 +46,15 @@ impl<N: Network> Output<N> {
     pub const fn register_type(&self) -> &RegisterType<N> {
         &self.register_type
     }
+
+    /// Returns the output, with its register replaced by the register returned by the given function.
+    #[inline]
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        Ok(Self { register: Operand::rename_register(&self.register, rename)?, register_type: self.register_type })
+    }
 }
 
 impl<N: Network> TypeName for Output<N> {
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Program<N> {
+    /// Returns the Rust definition of the struct with the given name, for generating typed bindings.
+    ///
+    /// The struct is named in `PascalCase`, and each member is typed by its literal type
+    /// (e.g. `field` as `Field<N>`, and `u64` as `u64`), or by the generated name of its nested struct.
+    /// The definition is generic over `N: Network` only if a member (transitively) requires it.
+    ///
+    /// Note: Only the definition of the given struct is generated, not those of its nested structs.
+    ///
+    /// # Errors
+    /// This method will halt if the struct, or a nested struct, is not defined in the program.
+    pub fn to_rust_struct(&self, struct_name: &Identifier<N>) -> Result<String> {
+        // Retrieve the struct.
+        let struct_ = self.get_struct(struct_name)?;
+
+        // Construct the members.
+        let mut members = String::new();
+        for (member_name, member_type) in struct_.members() {
+            members.push_str(&format!("    pub {member_name}: {},\n", self.rust_type(member_type)?));
+        }
+
+        // Construct the definition.
+        let generics = if self.rust_struct_is_generic(struct_name)? { "<N: Network>" } else { "" };
+        Ok(format!("pub struct {}{generics} {{\n{members}}}\n", rust_struct_name(struct_name)))
+    }
+
+    /// Returns the Rust type of the given plaintext type.
+    fn rust_type(&self, plaintext_type: &PlaintextType<N>) -> Result<String> {
+        Ok(match plaintext_type {
+            PlaintextType::Literal(literal_type) => match literal_type {
+                LiteralType::Address => "Address<N>",
+                LiteralType::Boolean => "bool",
+                LiteralType::Field => "Field<N>",
+                LiteralType::Group => "Group<N>",
+                LiteralType::I8 => "i8",
+                LiteralType::I16 => "i16",
+                LiteralType::I32 => "i32",
+                LiteralType::I64 => "i64",
+                LiteralType::I128 => "i128",
+                LiteralType::U8 => "u8",
+                LiteralType::U16 => "u16",
+                LiteralType::U32 => "u32",
+                LiteralType::U64 => "u64",
+                LiteralType::U128 => "u128",
+                LiteralType::Scalar => "Scalar<N>",
+                LiteralType::String => "String",
+            }
+            .to_string(),
+            PlaintextType::Struct(struct_name) => match self.rust_struct_is_generic(struct_name)? {
+                true => format!("{}<N>", rust_struct_name(struct_name)),
+                false => rust_struct_name(struct_name),
+            },
+        })
+    }
+
+    /// Returns `true` if the Rust definition of the struct with the given name is generic over `N: Network`.
+    fn rust_struct_is_generic(&self, struct_name: &Identifier<N>) -> Result<bool> {
+        for member_type in self.get_struct(struct_name)?.members().values() {
+            let is_generic = match member_type {
+                PlaintextType::Literal(literal_type) => matches!(
+                    literal_type,
+                    LiteralType::Address | LiteralType::Field | LiteralType::Group | LiteralType::Scalar
+                ),
+                // Note: Structs are acyclic, as a struct may only reference previously-defined structs.
+                PlaintextType::Struct(struct_name) => self.rust_struct_is_generic(struct_name)?,
+            };
+            if is_generic {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// Returns the Rust name of the given struct name, in `PascalCase` (e.g. `token_info` as `TokenInfo`).
+fn rust_struct_name<N: Network>(struct_name: &Identifier<N>) -> String {
+    struct_name
+        .to_string()
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_rust_struct() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program example_bindings.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+struct token_info:
+    amount as u64;
+    is_active as boolean;
+
+struct envelope:
+    body as message;
+    info as token_info;",
+        )?;
+
+        // Ensure the `message` struct has two field-typed members.
+        let expected = "pub struct Message<N: Network> {\n    pub first: Field<N>,\n    pub second: Field<N>,\n}\n";
+        assert_eq!(expected, program.to_rust_struct(&Identifier::from_str("message")?)?);
+
+        // Ensure a struct without network-dependent members is not generic.
+        let expected = "pub struct TokenInfo {\n    pub amount: u64,\n    pub is_active: bool,\n}\n";
+        assert_eq!(expected, program.to_rust_struct(&Identifier::from_str("token_info")?)?);
+
+        // Ensure nested structs are typed by their generated names.
+        let expected = "pub struct Envelope<N: Network> {\n    pub body: Message<N>,\n    pub info: TokenInfo,\n}\n";
+        assert_eq!(expected, program.to_rust_struct(&Identifier::from_str("envelope")?)?);
+
+        // Ensure a missing struct is rejected.
+        assert!(program.to_rust_struct(&Identifier::from_str("missing")?).is_err());
+        Ok(())
+    }
+}
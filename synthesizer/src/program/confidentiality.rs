@@ -0,0 +1,223 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A summary of how much a function reveals publicly, versus keeps private.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfidentialitySummary {
+    /// The number of public inputs.
+    public_inputs: usize,
+    /// The number of private inputs, including records.
+    private_inputs: usize,
+    /// The number of constant inputs.
+    constant_inputs: usize,
+    /// The number of public outputs.
+    public_outputs: usize,
+    /// The number of private outputs, including records.
+    private_outputs: usize,
+    /// The number of constant outputs.
+    constant_outputs: usize,
+    /// Whether every input flows (transitively) into a public or constant output.
+    leaks_all_inputs: bool,
+}
+
+impl ConfidentialitySummary {
+    /// Returns the number of public inputs.
+    pub const fn public_inputs(&self) -> usize {
+        self.public_inputs
+    }
+
+    /// Returns the number of private inputs, including records.
+    pub const fn private_inputs(&self) -> usize {
+        self.private_inputs
+    }
+
+    /// Returns the number of constant inputs.
+    pub const fn constant_inputs(&self) -> usize {
+        self.constant_inputs
+    }
+
+    /// Returns the number of public outputs.
+    pub const fn public_outputs(&self) -> usize {
+        self.public_outputs
+    }
+
+    /// Returns the number of private outputs, including records.
+    pub const fn private_outputs(&self) -> usize {
+        self.private_outputs
+    }
+
+    /// Returns the number of constant outputs.
+    pub const fn constant_outputs(&self) -> usize {
+        self.constant_outputs
+    }
+
+    /// Returns `true` if the function has inputs, and every input flows (transitively) into a public or constant output.
+    pub const fn leaks_all_inputs(&self) -> bool {
+        self.leaks_all_inputs
+    }
+}
+
+impl<N: Network> Program<N> {
+    /// Returns a summary of the visibility of the inputs and outputs of the function with the given name,
+    /// and whether every input flows into a revealed (public or constant) output.
+    ///
+    /// Note: An input flows into an output if the output register is computed from the input register,
+    /// through any sequence of instructions. Records are counted as private, as their entries are encrypted.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    pub fn confidentiality_summary(&self, function_name: &Identifier<N>) -> Result<ConfidentialitySummary> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+
+        let mut summary = ConfidentialitySummary::default();
+
+        // Count the inputs by visibility, and initialize each input register as depending on itself.
+        let mut dependencies: IndexMap<u64, IndexSet<u64>> = IndexMap::new();
+        for input in function.inputs() {
+            match input.value_type() {
+                ValueType::Constant(..) => summary.constant_inputs += 1,
+                ValueType::Public(..) => summary.public_inputs += 1,
+                ValueType::Private(..) | ValueType::Record(..) | ValueType::ExternalRecord(..) => {
+                    summary.private_inputs += 1
+                }
+            }
+            let locator = input.register().locator();
+            dependencies.insert(locator, IndexSet::from([locator]));
+        }
+
+        // Propagate the input dependencies of each instruction operand to its destinations.
+        for instruction in function.instructions() {
+            let mut sources = IndexSet::new();
+            for operand in instruction.operands() {
+                if let Operand::Register(register) = operand {
+                    if let Some(inputs) = dependencies.get(&register.locator()) {
+                        sources.extend(inputs.iter().copied());
+                    }
+                }
+            }
+            for destination in instruction.destinations() {
+                dependencies.insert(destination.locator(), sources.clone());
+            }
+        }
+
+        // Count the outputs by visibility, and collect the inputs revealed by the public and constant outputs.
+        let mut revealed = IndexSet::new();
+        for output in function.outputs() {
+            match output.value_type() {
+                ValueType::Constant(..) => summary.constant_outputs += 1,
+                ValueType::Public(..) => summary.public_outputs += 1,
+                ValueType::Private(..) | ValueType::Record(..) | ValueType::ExternalRecord(..) => {
+                    summary.private_outputs += 1;
+                    continue;
+                }
+            }
+            // Note: A literal output does not depend on any input.
+            if let Some(inputs) = output.register().and_then(|register| dependencies.get(&register.locator())) {
+                revealed.extend(inputs.iter().copied());
+            }
+        }
+
+        // Determine whether every input is revealed.
+        summary.leaks_all_inputs = !function.inputs().is_empty()
+            && function.inputs().iter().all(|input| revealed.contains(&input.register().locator()));
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_confidentiality_summary() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program confidential.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    amount as u64.private;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    add r0 r1 into r2;
+    output r2 as field.private;
+
+function reveal:
+    input r0 as field.private;
+    input r1 as field.constant;
+    input r2 as field.public;
+    add r0 r1 into r3;
+    mul r3 r2 into r4;
+    output r4 as field.public;
+
+function partial:
+    input r0 as field.private;
+    input r1 as field.private;
+    add r1 1field into r2;
+    mul r0 r0 into r3;
+    output r2 as field.public;
+    output r3 as field.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 0u64 r1 into r2 as token.record;
+    output r2 as token.record;
+    output r1 as u64.constant;",
+        )?;
+
+        // Ensure a function with a private output reveals nothing publicly.
+        let summary = program.confidentiality_summary(&Identifier::from_str("compute")?)?;
+        assert_eq!(summary.public_outputs(), 0);
+        assert_eq!(summary.private_outputs(), 1);
+        assert_eq!(summary.constant_outputs(), 0);
+        assert_eq!(summary.public_inputs(), 1);
+        assert_eq!(summary.private_inputs(), 1);
+        assert!(!summary.leaks_all_inputs());
+
+        // Ensure a function whose public output depends on every input leaks all inputs.
+        let summary = program.confidentiality_summary(&Identifier::from_str("reveal")?)?;
+        assert_eq!((summary.constant_inputs(), summary.public_inputs(), summary.private_inputs()), (1, 1, 1));
+        assert_eq!(summary.public_outputs(), 1);
+        assert!(summary.leaks_all_inputs());
+
+        // Ensure a function whose public output depends on some inputs does not leak all inputs.
+        let summary = program.confidentiality_summary(&Identifier::from_str("partial")?)?;
+        assert_eq!((summary.public_outputs(), summary.private_outputs()), (1, 1));
+        assert!(!summary.leaks_all_inputs());
+
+        // Ensure records are counted as private, and constant outputs are revealed.
+        let summary = program.confidentiality_summary(&Identifier::from_str("mint")?)?;
+        assert_eq!((summary.private_outputs(), summary.constant_outputs()), (1, 1));
+        assert!(!summary.leaks_all_inputs());
+
+        // Ensure an undefined function fails.
+        assert!(program.confidentiality_summary(&Identifier::from_str("missing")?).is_err());
+
+        Ok(())
+    }
+}
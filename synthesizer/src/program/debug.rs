@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Process;
+
+impl<N: Network> Program<N> {
+    /// Returns a human-readable table of the static register types of the function with the given name,
+    /// with one line per register, i.e. `r0: field (input, public)` or `r2: field (destination)`.
+    ///
+    /// The register types are those of the program's stack in the given process.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if the process does not contain this program.
+    pub fn debug_registers(&self, process: &Process<N>, function_name: &Identifier<N>) -> Result<String> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+
+        // Retrieve the stack of the program, and the register types of the function.
+        let stack = self.get_stack_in(process)?;
+        let register_types = stack.get_register_types(function_name)?;
+
+        // Describe each input register, with its visibility.
+        let mut lines = Vec::with_capacity(function.inputs().len() + function.instructions().len());
+        for input in function.inputs() {
+            let register = input.register();
+            let kind = match input.value_type() {
+                ValueType::Constant(..) => "input, constant",
+                ValueType::Public(..) => "input, public",
+                ValueType::Private(..) => "input, private",
+                ValueType::Record(..) | ValueType::ExternalRecord(..) => "input, record",
+            };
+            lines.push(format!("{register}: {} ({kind})", register_types.get_type(stack, register)?));
+        }
+        // Describe each destination register.
+        for register in function.instructions().iter().flat_map(|instruction| instruction.destinations()) {
+            lines.push(format!("{register}: {} (destination)", register_types.get_type(stack, &register)?));
+        }
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_debug_registers() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program example.aleo;
+
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+
+        let process = program.to_process()?;
+
+        // Ensure the dump lists every register, with its type and kind.
+        let dump = program.debug_registers(&process, &Identifier::from_str("compute")?)?;
+        assert_eq!(dump, "r0: field (input, public)\nr1: field (input, private)\nr2: field (destination)");
+
+        // Ensure an undefined function fails.
+        assert!(program.debug_registers(&process, &Identifier::from_str("missing")?).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,798 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::program::Register;
+
+impl<N: Network> Program<N> {
+    /// Inserts the given instruction at the given index of the function with the given name.
+    /// The destinations of the instruction must be the next locators at the given index,
+    /// and the registers of the subsequent instructions and outputs are renumbered to follow them.
+    ///
+    /// The program is only updated if the modified function is well-formed.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if the index is greater than the number of instructions in the function.
+    /// This method will halt if the destinations of the instruction are not the next locators at the index.
+    /// This method will halt if the instruction reads a register that is not defined at the index.
+    /// This method will halt if the modified function exceeds the maximum number of instructions.
+    pub fn insert_instruction(
+        &mut self,
+        function_name: &Identifier<N>,
+        index: usize,
+        instruction: Instruction<N>,
+    ) -> Result<()> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+        // Ensure the index is within bounds.
+        ensure!(
+            index <= function.instructions().len(),
+            "Cannot insert an instruction at index {index} of function '{function_name}', which has {} instructions",
+            function.instructions().len()
+        );
+
+        // Determine the first locator written at the given index.
+        let start = Self::next_locator(&function, index);
+        // Ensure the destinations of the instruction are the next locators.
+        for (i, destination) in (start..).zip(instruction.destinations()) {
+            ensure!(
+                destination == Register::Locator(i),
+                "Destination '{destination}' of '{instruction}' must be '{}'",
+                Register::<N>::Locator(i)
+            );
+        }
+
+        // Renumber the subsequent registers to follow the destinations of the instruction.
+        let shift = instruction.destinations().len() as u64;
+        let mut rename = |register: &Register<N>| match register.locator() >= start {
+            true => Ok(Operand::Register(with_locator(register, register.locator() + shift))),
+            false => Ok(Operand::Register(register.clone())),
+        };
+
+        // Reconstruct the function with the inserted instruction.
+        let mut instructions = function.instructions()[..index].to_vec();
+        instructions.push(instruction);
+        let function = Self::reconstruct_function(&function, instructions, index, &mut rename)?;
+        // Ensure the function is well-formed, and update the program.
+        self.replace_function(function)
+    }
+
+    /// Removes the instruction at the given index of the function with the given name.
+    /// The registers of the subsequent instructions and outputs are renumbered to fill the
+    /// locators of the destinations of the removed instruction.
+    ///
+    /// The program is only updated if the modified function is well-formed.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if the index is not less than the number of instructions in the function.
+    /// This method will halt if a destination of the removed instruction is read by a subsequent instruction or output.
+    /// This method will halt if the function would be left without instructions.
+    pub fn remove_instruction(&mut self, function_name: &Identifier<N>, index: usize) -> Result<()> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+        // Ensure the index is within bounds.
+        let instruction = match function.instructions().get(index) {
+            Some(instruction) => instruction,
+            None => bail!(
+                "Cannot remove the instruction at index {index} of function '{function_name}', which has {} instructions",
+                function.instructions().len()
+            ),
+        };
+
+        // Determine the locators written by the instruction.
+        let start = Self::next_locator(&function, index);
+        let end = start + instruction.destinations().len() as u64;
+        // Ensure the destinations of the instruction are not read after the instruction.
+        if let Some(register) =
+            Self::subsequent_registers(&function, index + 1).find(|register| (start..end).contains(&register.locator()))
+        {
+            bail!("Cannot remove '{instruction}', as register '{register}' is used in function '{function_name}'")
+        }
+
+        // Renumber the subsequent registers to fill the locators of the destinations of the instruction.
+        let shift = end - start;
+        let mut rename = |register: &Register<N>| match register.locator() >= end {
+            true => Ok(Operand::Register(with_locator(register, register.locator() - shift))),
+            false => Ok(Operand::Register(register.clone())),
+        };
+
+        // Reconstruct the function without the removed instruction.
+        let instructions = function.instructions()[..index].to_vec();
+        let function = Self::reconstruct_function(&function, instructions, index + 1, &mut rename)?;
+        // Ensure the function is well-formed, and update the program.
+        self.replace_function(function)
+    }
+
+    /// Inlines the `call` instruction at the given index of the function with the given name,
+    /// by replacing it with the instructions of the called closure. The registers of the closure
+    /// are renumbered to follow the registers of the caller, its inputs are wired to the operands
+    /// of the call, and the subsequent uses of the call destinations are wired to its outputs.
+    ///
+    /// The program is only updated if the modified function is well-formed.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if the instruction at the given index is not a `call` to a closure in the program.
+    /// This method will halt if an output of the closure cannot be wired to the uses of the call destination.
+    /// This method will halt if the modified function exceeds the maximum number of instructions.
+    pub fn inline(&mut self, caller: &Identifier<N>, call_index: usize) -> Result<()> {
+        // Retrieve the function.
+        let function = self.get_function(caller)?;
+        // Retrieve the call instruction.
+        let call = match function.instructions().get(call_index) {
+            Some(Instruction::Call(call)) => call,
+            Some(instruction) => bail!("Cannot inline '{instruction}' in function '{caller}', as it is not a call"),
+            None => bail!("Function '{caller}' does not have an instruction at index {call_index}"),
+        };
+        // Retrieve the called closure.
+        let closure = match call.operator() {
+            CallOperator::Resource(resource) => match self.get_closure(resource) {
+                Ok(closure) => closure,
+                Err(..) => bail!("Cannot inline '{call}', as '{resource}' is not a closure in the program"),
+            },
+            CallOperator::Locator(locator) => bail!("Cannot inline '{call}', as '{locator}' is external"),
+        };
+        // Ensure the call matches the closure.
+        ensure!(closure.inputs().len() == call.operands().len(), "Cannot inline '{call}', as its inputs do not match");
+        ensure!(
+            closure.outputs().len() == call.destinations().len(),
+            "Cannot inline '{call}', as its outputs do not match"
+        );
+
+        // Determine the first locator written by the call.
+        let start = Self::next_locator(&function, call_index);
+        // Determine the number of locators written by the call, and by the closure.
+        let num_call_destinations = call.destinations().len() as u64;
+        let num_closure_destinations =
+            closure.instructions().iter().map(|instruction| instruction.destinations().len()).sum::<usize>() as u64;
+
+        // Resolves a register of the closure to an operand of the function. The inputs resolve to the operands
+        // of the call, and the remaining registers are renumbered to follow the instructions before the call.
+        let num_inputs = closure.inputs().len() as u64;
+        let mut resolve = |register: &Register<N>| match register.locator() < num_inputs {
+            true => with_member_path(&call.operands()[register.locator() as usize], register),
+            false => Ok(Operand::Register(with_locator(register, start + register.locator() - num_inputs))),
+        };
+
+        // Rename the registers of the closure instructions.
+        let inlined = closure
+            .instructions()
+            .iter()
+            .map(|instruction| instruction.rename_registers(&mut resolve))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Resolve the outputs of the closure, which replace the destinations of the call.
+        let outputs = closure.outputs().iter().map(|output| resolve(output.register())).collect::<Result<Vec<_>>>()?;
+        // Rename the subsequent registers, to use the outputs of the closure and follow the inlined instructions.
+        let mut rename = |register: &Register<N>| match register.locator().checked_sub(start) {
+            Some(index) if index < num_call_destinations => with_member_path(&outputs[index as usize], register),
+            Some(_) => Ok(Operand::Register(with_locator(
+                register,
+                register.locator() - num_call_destinations + num_closure_destinations,
+            ))),
+            None => Ok(Operand::Register(register.clone())),
+        };
+
+        // Reconstruct the function with the inlined instructions.
+        let mut instructions = function.instructions()[..call_index].to_vec();
+        instructions.extend(inlined);
+        let function = Self::reconstruct_function(&function, instructions, call_index + 1, &mut rename)?;
+        // Ensure the function is well-formed, and update the program.
+        self.replace_function(function)
+    }
+
+    /// Applies the given closure to each instruction of the function with the given name, in order.
+    /// This is the primitive underlying in-place rewriting passes, such as folding and renaming.
+    ///
+    /// The program is only updated if the rewritten function is well-formed.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if a rewritten destination is not a locator.
+    /// This method will halt if a rewritten instruction writes a register out of order,
+    /// or reads a register that is not defined.
+    pub fn map_instructions<F>(&mut self, function_name: &Identifier<N>, mut f: F) -> Result<()>
+    where
+        F: FnMut(&mut Instruction<N>),
+    {
+        // Retrieve a copy of the function, so the program is unchanged if the rewrite is invalid.
+        let mut function = self.get_function(function_name)?;
+        // Rewrite each instruction.
+        function.instructions_mut().iter_mut().for_each(&mut f);
+        // Ensure each destination register is a locator.
+        for instruction in function.instructions() {
+            for destination in instruction.destinations() {
+                ensure!(
+                    matches!(destination, Register::Locator(..)),
+                    "Destination '{destination}' of '{instruction}' must be a locator"
+                );
+            }
+        }
+        // Ensure the function is well-formed, and update the program.
+        self.replace_function(function)
+    }
+
+    /// Renumbers the registers of the function with the given name to a dense, monotonic sequence,
+    /// i.e. the inputs are assigned `r0`, `r1`, ..., followed by the destinations of each instruction in order.
+    /// The inputs, operands, destinations, outputs, and finalize command (including member accesses)
+    /// are rewritten consistently.
+    ///
+    /// The program is only updated if the renumbered function is well-formed.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if a register is defined more than once in the function.
+    /// This method will halt if a register is read but never defined in the function.
+    pub fn renumber_registers(&mut self, function_name: &Identifier<N>) -> Result<()> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+
+        // Assign each defined locator its position in the order of definition.
+        let mut locators = IndexMap::new();
+        let definitions = function
+            .inputs()
+            .iter()
+            .map(|input| input.register().clone())
+            .chain(function.instructions().iter().flat_map(|instruction| instruction.destinations()));
+        for register in definitions {
+            let next = locators.len() as u64;
+            ensure!(
+                locators.insert(register.locator(), next).is_none(),
+                "Register '{register}' is defined more than once in function '{function_name}'"
+            );
+        }
+
+        // Renumber every register, including member accesses, to its assigned locator.
+        let mut rename = |register: &Register<N>| match locators.get(&register.locator()) {
+            Some(locator) => Ok(Operand::Register(with_locator(register, *locator))),
+            None => bail!("Register '{register}' is not defined in function '{function_name}'"),
+        };
+
+        // Reconstruct the function with the renumbered registers.
+        let function = Self::reconstruct_function(&function, vec![], 0, &mut rename)?;
+        // Ensure the function is well-formed, and update the program.
+        self.replace_function(function)
+    }
+
+    /// Checks that the registers of the given function are written in order, and are defined before they are read.
+    ///
+    /// Note: The types of the registers are checked when the program is added to a process.
+    ///
+    /// # Errors
+    /// This method will halt if an input or destination register is out of order.
+    /// This method will halt if an instruction or output reads a register that is not yet defined.
+    fn check_register_order(function: &Function<N>) -> Result<()> {
+        // Ensure the input registers are in order.
+        for (locator, input) in (0u64..).zip(function.inputs()) {
+            ensure!(input.register().locator() == locator, "Register '{}' is out of order", input.register());
+        }
+        let mut next_locator = function.inputs().len() as u64;
+        for instruction in function.instructions() {
+            for operand in instruction.operands() {
+                if let Operand::Register(register) = operand {
+                    ensure!(
+                        register.locator() < next_locator,
+                        "Register '{register}' in '{instruction}' is not defined"
+                    );
+                }
+            }
+            for destination in instruction.destinations() {
+                ensure!(destination.locator() == next_locator, "Register '{destination}' is out of order");
+                next_locator += 1;
+            }
+        }
+        for output in function.outputs() {
+            if let Some(register) = output.register() {
+                ensure!(register.locator() < next_locator, "Register '{register}' in '{output}' is not defined");
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the next locator to be written by the instruction at the given index of the function.
+    fn next_locator(function: &Function<N>, index: usize) -> u64 {
+        let num_destinations: usize =
+            function.instructions()[..index].iter().map(|instruction| instruction.destinations().len()).sum();
+        (function.inputs().len() + num_destinations) as u64
+    }
+
+    /// Returns the registers in the instructions from the given index onwards, the outputs,
+    /// and the finalize command of the given function.
+    fn subsequent_registers(function: &Function<N>, index: usize) -> impl Iterator<Item = Register<N>> + '_ {
+        let instructions = &function.instructions()[index.min(function.instructions().len())..];
+        instructions
+            .iter()
+            .flat_map(|instruction| {
+                let operands = instruction.operands().iter().filter_map(|operand| match operand {
+                    Operand::Register(register) => Some(register.clone()),
+                    _ => None,
+                });
+                operands.chain(instruction.destinations()).collect::<Vec<_>>()
+            })
+            .chain(function.outputs().iter().filter_map(|output| output.register().cloned()))
+            .chain(
+                function
+                    .finalize_command()
+                    .map(|command| command.operands().to_vec())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|operand| match operand {
+                        Operand::Register(register) => Some(register),
+                        _ => None,
+                    }),
+            )
+    }
+
+    /// Returns the function reconstructed from its inputs and the given leading instructions,
+    /// followed by the instructions of the given function from the given index onwards, its outputs, and its
+    /// finalize command, where the registers of the inputs and the latter are renamed by the given function.
+    fn reconstruct_function<F>(
+        function: &Function<N>,
+        mut instructions: Vec<Instruction<N>>,
+        index: usize,
+        rename: &mut F,
+    ) -> Result<Function<N>>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        // Rename the registers of the inputs.
+        let inputs = function.inputs().iter().map(|input| input.rename_registers(rename)).collect::<Result<_>>()?;
+        // Rename the registers of the subsequent instructions, outputs, and finalize command.
+        for instruction in &function.instructions()[index..] {
+            instructions.push(instruction.rename_registers(rename)?);
+        }
+        let outputs = function.outputs().iter().map(|output| output.rename_registers(rename)).collect::<Result<_>>()?;
+        // Retain the finalize scope, which does not share registers with the function.
+        let finalize = match function.finalize() {
+            Some((command, finalize)) => Some((command.rename_registers(rename)?, finalize.clone())),
+            None => None,
+        };
+        function.reconstruct(inputs, instructions, outputs, finalize)
+    }
+
+    /// Replaces the function of the same name in the program with the given function,
+    /// if the given function is well-formed.
+    fn replace_function(&mut self, function: Function<N>) -> Result<()> {
+        // Ensure the function is well-formed.
+        Self::check_function(&function, &NetworkLimits::of::<N>())?;
+        // Ensure each register is written in order, and is defined before it is read.
+        Self::check_register_order(&function)?;
+
+        // Update the function in the program, discarding the source spans of its previous instructions.
+        self.instruction_spans.shift_remove(function.name());
+        self.functions.insert(*function.name(), function);
+        Ok(())
+    }
+}
+
+/// Returns the given register, with its locator replaced by the given locator.
+fn with_locator<N: Network>(register: &Register<N>, locator: u64) -> Register<N> {
+    match register {
+        Register::Locator(..) => Register::Locator(locator),
+        Register::Member(_, path) => Register::Member(locator, path.clone()),
+    }
+}
+
+/// Returns the given operand, with the member path of the given register appended.
+fn with_member_path<N: Network>(operand: &Operand<N>, register: &Register<N>) -> Result<Operand<N>> {
+    match (operand, register) {
+        (_, Register::Locator(..)) => Ok(operand.clone()),
+        (Operand::Register(Register::Locator(locator)), Register::Member(_, path)) => {
+            Ok(Operand::Register(Register::Member(*locator, path.clone())))
+        }
+        (Operand::Register(Register::Member(locator, prefix)), Register::Member(_, path)) => {
+            Ok(Operand::Register(Register::Member(*locator, prefix.iter().chain(path).copied().collect())))
+        }
+        _ => bail!("Cannot access '{register}', as it resolves to '{operand}'"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::network::AleoV0;
+    use console::{account::PrivateKey, network::Testnet3, program::Value};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_insert_instruction() -> Result<()> {
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r"
+program edit.aleo;
+
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    mul r2 r1 into r3;
+    output r3 as field.private;",
+        )?;
+        let compute = Identifier::from_str("compute")?;
+
+        // Insert a `sub` after the `add`, and ensure the subsequent registers are renumbered.
+        program.insert_instruction(&compute, 1, Instruction::from_str("sub r2 r0 into r3;")?)?;
+        let expected = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    sub r2 r0 into r3;
+    mul r2 r1 into r4;
+    output r4 as field.private;",
+        )?;
+        assert_eq!(expected, program.get_function(&compute)?);
+
+        // Ensure an insertion that reads an undefined register is rejected, and the program is unchanged.
+        let expected = program.clone();
+        let error = program.insert_instruction(&compute, 1, Instruction::from_str("sub r5 r0 into r3;")?).unwrap_err();
+        assert!(error.to_string().contains("'r5'"), "{error}");
+        assert_eq!(expected, program);
+
+        // Ensure an insertion with an out-of-order destination is rejected.
+        assert!(program.insert_instruction(&compute, 1, Instruction::from_str("sub r2 r0 into r7;")?).is_err());
+        // Ensure an out-of-bounds insertion is rejected.
+        assert!(program.insert_instruction(&compute, 9, Instruction::from_str("sub r2 r0 into r5;")?).is_err());
+        assert_eq!(expected, program);
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_instruction() -> Result<()> {
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r"
+program edit.aleo;
+
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    sub r0 r1 into r3;
+    mul r2 r1 into r4;
+    output r4 as field.private;",
+        )?;
+        let compute = Identifier::from_str("compute")?;
+
+        // Ensure removing an instruction whose destination is used is rejected.
+        let expected = program.clone();
+        assert!(program.remove_instruction(&compute, 0).is_err());
+        assert!(program.remove_instruction(&compute, 3).is_err());
+        assert_eq!(expected, program);
+
+        // Remove the `sub`, and ensure the subsequent registers are renumbered.
+        program.remove_instruction(&compute, 1)?;
+        let expected = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    mul r2 r1 into r3;
+    output r3 as field.private;",
+        )?;
+        assert_eq!(expected, program.get_function(&compute)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_instruction_checks_network() -> Result<()> {
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r"
+program edit.aleo;
+
+function compute:
+    input r0 as group.public;
+    input r1 as group.private;
+    add r0 r1 into r2;
+    output r2 as group.private;",
+        )?;
+        let compute = Identifier::from_str("compute")?;
+
+        // Replace an operand with a group element that is not on the curve.
+        // Note: Such a literal cannot be parsed or deserialized, only constructed directly.
+        let (x, y) = (console::types::Field::from_u64(1), console::types::Field::from_u64(1));
+        let literal = Literal::Group(console::types::Group::from_xy_coordinates_unchecked(x, y));
+        let mut rename = |register: &Register<CurrentNetwork>| match register {
+            Register::Locator(0) => Ok(Operand::Literal(literal.clone())),
+            register => Ok(Operand::Register(register.clone())),
+        };
+        let instruction = Instruction::from_str("add r2 r0 into r3;")?.rename_registers(&mut rename)?;
+
+        // Ensure an insertion that the network rejects is rejected, and the program is unchanged.
+        let expected = program.clone();
+        let error = program.insert_instruction(&compute, 1, instruction).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!("Literal operand '{literal}' of instruction 1 in 'compute' is not a well-formed 'group'")
+        );
+        assert_eq!(expected, program);
+
+        // Ensure a well-formed insertion is accepted.
+        program.insert_instruction(&compute, 1, Instruction::from_str("add r2 r0 into r3;")?)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_inline() -> Result<()> {
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r"
+program inline.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+closure combine:
+    input r0 as field;
+    input r1 as field;
+    mul r0 r1 into r2;
+    add r2 r0 into r3;
+    output r3 as field;
+    output r1 as field;
+
+function compute:
+    input r0 as message.public;
+    input r1 as field.private;
+    add r1 r1 into r2;
+    call combine r0.first r2 into r3 r4;
+    sub r3 r4 into r5;
+    add r5 r0.second into r6;
+    output r6 as field.private;",
+        )?;
+        let compute = Identifier::from_str("compute")?;
+
+        // Ensure a non-call instruction cannot be inlined, and the program is unchanged.
+        let expected = program.clone();
+        assert!(program.inline(&compute, 0).is_err());
+        assert!(program.inline(&compute, 9).is_err());
+        assert_eq!(expected, program);
+
+        // Inline the call, and ensure the registers are wired to the caller.
+        program.inline(&compute, 1)?;
+        let expected = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as message.public;
+    input r1 as field.private;
+    add r1 r1 into r2;
+    mul r0.first r2 into r3;
+    add r3 r0.first into r4;
+    sub r4 r2 into r5;
+    add r5 r0.second into r6;
+    output r6 as field.private;",
+        )?;
+        assert_eq!(expected, program.get_function(&compute)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_inline_evaluate() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program inline_helper.aleo;
+
+closure twice:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    call twice r1 into r2;
+    mul r2 r0 into r3;
+    output r3 as field.private;",
+        )?;
+        let compute = Identifier::from_str("compute")?;
+
+        // Inline the single-instruction helper.
+        let mut inlined = program.clone();
+        inlined.inline(&compute, 0)?;
+        assert_eq!(2, inlined.get_function(&compute)?.instructions().len());
+        assert!(!matches!(inlined.get_function(&compute)?.instructions()[0], Instruction::Call(..)));
+
+        // Evaluates the function in the given program on the given inputs.
+        let evaluate = |program: &Program<CurrentNetwork>| -> Result<Vec<Value<CurrentNetwork>>> {
+            let rng = &mut TestRng::default();
+            // Construct the process.
+            let process = crate::process::test_helpers::sample_process(program);
+            // Authorize the function call.
+            let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let inputs = [Value::from_str("3field")?, Value::from_str("5field")?];
+            let authorization =
+                process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), compute, inputs.iter(), rng)?;
+            // Evaluate the function.
+            Ok(process.evaluate::<CurrentAleo>(authorization)?.outputs().to_vec())
+        };
+
+        // Ensure the inlined function evaluates to the same output.
+        let expected = evaluate(&program)?;
+        assert_eq!(expected, [Value::from_str("30field")?]);
+        assert_eq!(expected, evaluate(&inlined)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_instructions() -> Result<()> {
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r"
+program rewrite.aleo;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    sub r2 r0 into r3;
+    output r3 as field.private;",
+        )?;
+        let compute = Identifier::from_str("compute")?;
+        let inputs = [Value::from_str("3field")?, Value::from_str("5field")?];
+        let rng = &mut TestRng::default();
+        let expected = program.evaluate::<CurrentAleo, _>(&program.to_process()?, &compute, &inputs, rng)?;
+
+        // Swap the operands of the commutative `add`.
+        program.map_instructions(&compute, |instruction| {
+            if let Instruction::Add(..) = instruction {
+                let (operands, destination) = (instruction.operands(), &instruction.destinations()[0]);
+                let swapped = format!("add {} {} into {destination};", operands[1], operands[0]);
+                *instruction = Instruction::from_str(&swapped).unwrap();
+            }
+        })?;
+        assert_eq!("add r1 r0 into r2;", program.get_function(&compute)?.instructions()[0].to_string());
+
+        // Ensure the output is unchanged.
+        assert_eq!(expected, program.evaluate::<CurrentAleo, _>(&program.to_process()?, &compute, &inputs, rng)?);
+
+        // Ensure an invalid rewrite is rolled back.
+        let original = program.clone();
+        let result = program.map_instructions(&compute, |instruction| {
+            if let Instruction::Sub(..) = instruction {
+                *instruction = Instruction::from_str("sub r2 r7 into r3;").unwrap();
+            }
+        });
+        assert!(result.is_err());
+        assert_eq!(original, program);
+        Ok(())
+    }
+
+    #[test]
+    fn test_edit_string_literal() -> Result<()> {
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r#"
+program strings.aleo;
+
+closure matches:
+    input r0 as string;
+    is.eq r0 " r0 r1 " into r1;
+    output r1 as boolean;
+
+function compute:
+    input r0 as string.private;
+    input r2 as string.private;
+    is.eq r0 " r0 r2 " into r5;
+    call matches r2 into r6;
+    output r5 as boolean.private;
+    output r6 as boolean.private;"#,
+        )?;
+        let compute = Identifier::from_str("compute")?;
+        // Ensure the registers, but not the string literals that spell them, are renamed by each edit.
+        let assert_function = |program: &Program<CurrentNetwork>, expected: &str| -> Result<()> {
+            assert_eq!(program.get_function(&compute)?, Function::from_str(expected)?);
+            Ok(())
+        };
+
+        // Renumber the registers.
+        program.renumber_registers(&compute)?;
+        assert_function(
+            &program,
+            r#"
+function compute:
+    input r0 as string.private;
+    input r1 as string.private;
+    is.eq r0 " r0 r2 " into r2;
+    call matches r1 into r3;
+    output r2 as boolean.private;
+    output r3 as boolean.private;"#,
+        )?;
+
+        // Inline the call.
+        program.inline(&compute, 1)?;
+        let inlined = r#"
+function compute:
+    input r0 as string.private;
+    input r1 as string.private;
+    is.eq r0 " r0 r2 " into r2;
+    is.eq r1 " r0 r1 " into r3;
+    output r2 as boolean.private;
+    output r3 as boolean.private;"#;
+        assert_function(&program, inlined)?;
+
+        // Insert an instruction, and remove it again.
+        program.insert_instruction(&compute, 0, Instruction::from_str(r#"is.neq r0 " r2 " into r2;"#)?)?;
+        assert_function(
+            &program,
+            r#"
+function compute:
+    input r0 as string.private;
+    input r1 as string.private;
+    is.neq r0 " r2 " into r2;
+    is.eq r0 " r0 r2 " into r3;
+    is.eq r1 " r0 r1 " into r4;
+    output r3 as boolean.private;
+    output r4 as boolean.private;"#,
+        )?;
+        program.remove_instruction(&compute, 0)?;
+        assert_function(&program, inlined)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_renumber_registers() -> Result<()> {
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r"
+program renumber.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+function compute:
+    input r0 as point.private;
+    /// The offset, in r2.
+    input r2 as field.private;
+    add r0.x r2 into r5;
+    mul r5 r0.y into r9;
+    cast r9 r5 into r10 as point;
+    output r10.x as field.private;",
+        )?;
+        let compute = Identifier::from_str("compute")?;
+        let inputs = [Value::from_str("{ x: 2field, y: 3field }")?, Value::from_str("5field")?];
+        let rng = &mut TestRng::default();
+
+        // Ensure the function with gaps cannot be evaluated.
+        let evaluate = |process| program.evaluate::<CurrentAleo, _>(&process, &compute, &inputs, rng);
+        assert!(program.to_process().and_then(evaluate).is_err());
+
+        // Renumber the registers, and ensure they are contiguous.
+        program.renumber_registers(&compute)?;
+        let expected = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as point.private;
+    input r1 as field.private;
+    add r0.x r1 into r2;
+    mul r2 r0.y into r3;
+    cast r3 r2 into r4 as point;
+    output r4.x as field.private;",
+        )?;
+        assert_eq!(expected, program.get_function(&compute)?);
+        // Ensure the doc comments are retained, and are not renumbered.
+        assert_eq!(program.get_function(&compute)?.input_doc(1), Some("The offset, in r2."));
+
+        // Ensure the renumbered function evaluates.
+        let outputs = program.evaluate::<CurrentAleo, _>(&program.to_process()?, &compute, &inputs, rng)?;
+        assert_eq!(outputs, [Value::from_str("21field")?]);
+
+        // Ensure renumbering is idempotent.
+        program.renumber_registers(&compute)?;
+        assert_eq!(expected, program.get_function(&compute)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,398 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{Process, Stack};
+use console::{
+    account::{Address, PrivateKey},
+    program::{Balance, Entry, Owner, Record},
+    types::{Group, U64},
+};
+
+impl<N: Network> Program<N> {
+    /// Returns a new process (without keys) that contains the program.
+    ///
+    /// Note: A new process contains 'credits.aleo', so the program may import it, but no other programs.
+    ///
+    /// # Errors
+    /// This method will halt if the program cannot be added to a new process.
+    pub fn to_process(&self) -> Result<Process<N>> {
+        // Initialize a new process.
+        let mut process = Process::load_without_keys()?;
+        // Add the program, unless it is 'credits.aleo', which the process already contains.
+        match process.contains_program(self.id()) {
+            true => ensure!(process.get_stack(self.id())?.program() == self, "Program '{}' already exists", self.id()),
+            false => process.add_program(self)?,
+        }
+        Ok(process)
+    }
+
+    /// Returns the stack of the program in the given process.
+    ///
+    /// # Errors
+    /// This method will halt if the process does not contain this program.
+    pub(super) fn get_stack_in<'a>(&self, process: &'a Process<N>) -> Result<&'a Stack<N>> {
+        // Retrieve the stack.
+        let stack = process.get_stack(self.id())?;
+        // Ensure the stack is for this program, rather than another program with the same ID.
+        ensure!(stack.program() == self, "The process contains a different program with the ID '{}'", self.id());
+        Ok(stack)
+    }
+
+    /// Evaluates the function with the given name on the given inputs, and returns its outputs.
+    ///
+    /// The function is evaluated in the given process (see `Program::to_process`),
+    /// by a caller sampled from the given RNG.
+    ///
+    /// # Errors
+    /// This method will halt if the process does not contain this program.
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if the inputs do not match the function, or if the evaluation fails.
+    pub fn evaluate<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        process: &Process<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+        rng: &mut R,
+    ) -> Result<Vec<Value<N>>> {
+        // Sample a random caller.
+        let private_key = PrivateKey::new(rng)?;
+        // Evaluate the function.
+        self.evaluate_as::<A, R>(process, &private_key, function_name, inputs, rng)
+    }
+
+    /// Evaluates the given functions in order, as a pipeline, and returns the outputs of the last function.
+    /// The first function is evaluated on the given inputs, and each subsequent function is evaluated
+    /// on the outputs of the previous function.
+    ///
+    /// The functions are evaluated in the given process, by the same caller, sampled from the given RNG.
+    ///
+    /// # Errors
+    /// This method will halt if there are no functions, or if a function does not exist in the program.
+    /// This method will halt if the outputs of a function do not match the inputs of the next function,
+    /// in number or in type (irrespective of visibility).
+    /// This method will halt if the inputs do not match the first function, or if an evaluation fails.
+    pub fn evaluate_pipeline<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        process: &Process<N>,
+        stages: &[Identifier<N>],
+        initial_inputs: &[Value<N>],
+        rng: &mut R,
+    ) -> Result<Vec<Value<N>>> {
+        // Ensure there is at least one stage.
+        ensure!(!stages.is_empty(), "A pipeline must have at least one function");
+
+        // Ensure the outputs of each stage match the inputs of the next stage.
+        for (stage, next_stage) in stages.iter().tuple_windows() {
+            // Retrieve the output types of the stage, and the input types of the next stage.
+            let output_types = self.get_function(stage)?.output_types();
+            let input_types = self.get_function(next_stage)?.input_types();
+            // Ensure the number of outputs matches the number of inputs.
+            ensure!(
+                output_types.len() == input_types.len(),
+                "Function '{stage}' has {} output(s), but the next function '{next_stage}' has {} input(s)",
+                output_types.len(),
+                input_types.len()
+            );
+            // Ensure each output type matches its input type.
+            for (index, (output_type, input_type)) in output_types.iter().zip_eq(&input_types).enumerate() {
+                let matches = match (output_type, input_type) {
+                    (
+                        ValueType::Constant(output) | ValueType::Public(output) | ValueType::Private(output),
+                        ValueType::Constant(input) | ValueType::Public(input) | ValueType::Private(input),
+                    ) => output == input,
+                    (output, input) => output == input,
+                };
+                ensure!(
+                    matches,
+                    "Output {index} of '{stage}' ({output_type}) does not match input {index} of '{next_stage}' ({input_type})"
+                );
+            }
+        }
+
+        // Sample a random caller, who evaluates every stage.
+        let private_key = PrivateKey::new(rng)?;
+        // Evaluate each stage on the outputs of the previous stage.
+        let mut values = initial_inputs.to_vec();
+        for stage in stages {
+            values = self.evaluate_as::<A, R>(process, &private_key, stage, &values, rng)?;
+        }
+        Ok(values)
+    }
+
+    /// Evaluates the function with the given name on the given inputs, by the given caller, and returns its outputs.
+    pub(super) fn evaluate_as<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        process: &Process<N>,
+        private_key: &PrivateKey<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+        rng: &mut R,
+    ) -> Result<Vec<Value<N>>> {
+        // Retrieve the stack of the program.
+        let stack = self.get_stack_in(process)?;
+
+        // Authorize the function call.
+        let authorization = stack.authorize::<A, R>(private_key, *function_name, inputs.iter(), rng)?;
+
+        // Evaluate the function.
+        let response = process.evaluate::<A>(authorization)?;
+        Ok(response.outputs().to_vec())
+    }
+
+    /// Evaluates the function with the given name on zero-valued inputs of its declared input types,
+    /// and returns its outputs. This is a convenience for smoke tests and dry runs.
+    ///
+    /// Literals are zero (see `Literal::zero`), and structs are zero in each member.
+    /// Records are owned by the caller (sampled from the given RNG),
+    /// with a zero balance, zero entries, and a zero nonce.
+    ///
+    /// # Errors
+    /// This method will halt if the process does not contain this program.
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if the function takes an external record as input.
+    /// This method will halt if the evaluation fails.
+    pub fn evaluate_with_defaults<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        process: &Process<N>,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<Vec<Value<N>>> {
+        // Sample a random caller, who owns any record inputs.
+        let private_key = PrivateKey::new(rng)?;
+        let caller = Address::try_from(&private_key)?;
+
+        // Construct the default inputs.
+        let inputs = self
+            .get_function(function_name)?
+            .input_types()
+            .iter()
+            .map(|input_type| self.default_value(input_type, caller))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Evaluate the function.
+        self.evaluate_as::<A, R>(process, &private_key, function_name, &inputs, rng)
+    }
+
+    /// Returns the default value of the given value type, where any record is owned by the given address.
+    fn default_value(&self, value_type: &ValueType<N>, owner: Address<N>) -> Result<Value<N>> {
+        match value_type {
+            ValueType::Constant(plaintext_type)
+            | ValueType::Public(plaintext_type)
+            | ValueType::Private(plaintext_type) => Ok(Value::Plaintext(self.default_plaintext(plaintext_type)?)),
+            ValueType::Record(record_name) => {
+                // Retrieve the record type.
+                let record_type = self.get_record(record_name)?;
+                // Construct the owner and balance, with the declared visibility.
+                let owner = match record_type.owner().is_public() {
+                    true => Owner::Public(owner),
+                    false => Owner::Private(Plaintext::from(Literal::Address(owner))),
+                };
+                let gates = match record_type.gates().is_public() {
+                    true => Balance::Public(U64::zero()),
+                    false => Balance::Private(Plaintext::from(Literal::U64(U64::zero()))),
+                };
+                // Construct the entries, with the declared visibility.
+                let data = record_type
+                    .entries()
+                    .iter()
+                    .map(|(name, entry_type)| {
+                        let entry = match entry_type {
+                            EntryType::Constant(plaintext_type) => {
+                                Entry::Constant(self.default_plaintext(plaintext_type)?)
+                            }
+                            EntryType::Public(plaintext_type) => Entry::Public(self.default_plaintext(plaintext_type)?),
+                            EntryType::Private(plaintext_type) => {
+                                Entry::Private(self.default_plaintext(plaintext_type)?)
+                            }
+                        };
+                        Ok((*name, entry))
+                    })
+                    .collect::<Result<IndexMap<_, _>>>()?;
+                Ok(Value::from(Record::<N, Plaintext<N>>::from_plaintext(owner, gates, data, Group::zero())?))
+            }
+            ValueType::ExternalRecord(locator) => {
+                bail!("Cannot construct a default input for external record '{locator}'")
+            }
+        }
+    }
+
+    /// Returns the default plaintext of the given plaintext type.
+    fn default_plaintext(&self, plaintext_type: &PlaintextType<N>) -> Result<Plaintext<N>> {
+        match plaintext_type {
+            PlaintextType::Literal(literal_type) => Ok(Plaintext::from(Literal::zero(*literal_type))),
+            PlaintextType::Struct(struct_name) => {
+                // Retrieve the struct.
+                let struct_ = self.get_struct(struct_name)?;
+                // Construct the default members.
+                let members = struct_
+                    .members()
+                    .iter()
+                    .map(|(name, member_type)| Ok((*name, self.default_plaintext(member_type)?)))
+                    .collect::<Result<IndexMap<_, _>>>()?;
+                Ok(Plaintext::Struct(members, Default::default()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::network::AleoV0;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_program_evaluate() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program evaluate.aleo;
+
+function sum:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+        let function_name = Identifier::from_str("sum")?;
+        let process = program.to_process()?;
+        let rng = &mut TestRng::default();
+
+        // Ensure the function evaluates to the expected output.
+        let inputs = [Value::from_str("2field")?, Value::from_str("3field")?];
+        let outputs = program.evaluate::<CurrentAleo, _>(&process, &function_name, &inputs, rng)?;
+        assert_eq!(outputs, vec![Value::from_str("5field")?]);
+
+        // Ensure the evaluation fails on the wrong number of inputs.
+        assert!(program.evaluate::<CurrentAleo, _>(&process, &function_name, &inputs[..1], rng).is_err());
+        // Ensure the evaluation fails on an undefined function.
+        assert!(program.evaluate::<CurrentAleo, _>(&process, &Identifier::from_str("missing")?, &inputs, rng).is_err());
+        // Ensure the evaluation fails in a process that does not contain the program.
+        let other = Program::<CurrentNetwork>::from_str(&program.to_string().replace("add", "mul"))?;
+        assert!(program.evaluate::<CurrentAleo, _>(&other.to_process()?, &function_name, &inputs, rng).is_err());
+        assert!(
+            program.evaluate::<CurrentAleo, _>(&Process::load_without_keys()?, &function_name, &inputs, rng).is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_evaluate_pipeline() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program pipeline.aleo;
+
+function twice:
+    input r0 as field.public;
+    add r0 r0 into r1;
+    output r1 as field.private;
+
+function squared:
+    input r0 as field.private;
+    mul r0 r0 into r1;
+    output r1 as field.private;
+
+function is_zero:
+    input r0 as u8.private;
+    is.eq r0 0u8 into r1;
+    output r1 as boolean.private;
+
+function sum:
+    input r0 as field.private;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+        let twice = Identifier::from_str("twice")?;
+        let squared = Identifier::from_str("squared")?;
+        let process = program.to_process()?;
+        let rng = &mut TestRng::default();
+
+        // Ensure the stages are chained, in order.
+        let inputs = [Value::from_str("3field")?];
+        let outputs = program.evaluate_pipeline::<CurrentAleo, _>(&process, &[twice, squared], &inputs, rng)?;
+        assert_eq!(outputs, vec![Value::from_str("36field")?]);
+        let outputs = program.evaluate_pipeline::<CurrentAleo, _>(&process, &[squared, twice], &inputs, rng)?;
+        assert_eq!(outputs, vec![Value::from_str("18field")?]);
+
+        // Ensure an empty pipeline is rejected.
+        assert!(program.evaluate_pipeline::<CurrentAleo, _>(&process, &[], &inputs, rng).is_err());
+        // Ensure a type mismatch between stages is rejected.
+        let error = program.evaluate_pipeline::<CurrentAleo, _>(
+            &process,
+            &[twice, Identifier::from_str("is_zero")?],
+            &inputs,
+            rng,
+        );
+        assert!(error.unwrap_err().to_string().contains("does not match input 0 of 'is_zero'"));
+        // Ensure an arity mismatch between stages is rejected.
+        let error =
+            program.evaluate_pipeline::<CurrentAleo, _>(&process, &[twice, Identifier::from_str("sum")?], &inputs, rng);
+        assert!(error.unwrap_err().to_string().contains("has 1 output(s), but the next function 'sum' has 2 input(s)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_evaluate_with_defaults() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program defaults.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    amount as u64.private;
+
+function sum:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;
+
+function spend:
+    input r0 as token.record;
+    input r1 as point.private;
+    add r0.amount 1u64 into r2;
+    add r1.x r1.y into r3;
+    output r2 as u64.private;
+    output r3 as field.private;",
+        )?;
+
+        let process = program.to_process()?;
+        let rng = &mut TestRng::default();
+
+        // Ensure the function evaluates on zero inputs.
+        let outputs = program.evaluate_with_defaults::<CurrentAleo, _>(&process, &Identifier::from_str("sum")?, rng)?;
+        assert_eq!(outputs, vec![Value::from_str("0field")?]);
+
+        // Ensure records and structs are constructed with zero entries and members.
+        let outputs = program.evaluate_with_defaults::<CurrentAleo, _>(&process, &Identifier::from_str("spend")?, rng)?;
+        assert_eq!(outputs, vec![Value::from_str("1u64")?, Value::from_str("0field")?]);
+
+        Ok(())
+    }
+}
@@ -17,7 +17,7 @@
 use crate::{FinalizeRegisters, Opcode, Operand, ProgramStorage, ProgramStore, Stack};
 use console::{
     network::prelude::*,
-    program::{Identifier, Literal, Plaintext, Value},
+    program::{Identifier, Literal, Plaintext, Register, Value},
 };
 
 /// Decrements the value stored at the `first` operand in `mapping` by the amount in the `second` operand.
@@ -61,6 +61,17 @@ impl<N: Network> Decrement<N> {
     pub const fn value(&self) -> &Operand<N> {
         &self.second
     }
+
+    /// Returns the command, with each register in its operands replaced by the given function.
+    #[inline]
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        let first = self.first.rename_registers(rename)?;
+        let second = self.second.rename_registers(rename)?;
+        Ok(Self { mapping: self.mapping, first, second })
+    }
 }
 
 impl<N: Network> Decrement<N> {
@@ -55,6 +55,17 @@ impl<N: Network, const VARIANT: u8> FinalizeOperation<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![]
     }
+
+    /// Returns the operation, with each register in its operands replaced by the given function.
+    #[inline]
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        Ok(Self {
+            operands: self.operands.iter().map(|operand| operand.rename_registers(rename)).collect::<Result<_>>()?,
+        })
+    }
 }
 
 impl<N: Network, const VARIANT: u8> FinalizeOperation<N, VARIANT> {
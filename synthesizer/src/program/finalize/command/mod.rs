@@ -23,8 +23,8 @@ pub use finalize::*;
 mod increment;
 pub use increment::*;
 
-use crate::{program::Instruction, FinalizeRegisters, ProgramStorage, ProgramStore, Stack};
-use console::network::prelude::*;
+use crate::{program::Instruction, FinalizeRegisters, Operand, ProgramStorage, ProgramStore, Stack};
+use console::{network::prelude::*, program::Register};
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum Command<N: Network> {
@@ -53,6 +53,19 @@ impl<N: Network> Command<N> {
             Command::Increment(increment) => increment.evaluate_finalize(stack, store, registers),
         }
     }
+
+    /// Returns the command, with each register in its operands and destinations replaced by the given function.
+    #[inline]
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        match self {
+            Command::Decrement(decrement) => decrement.rename_registers(rename).map(Command::Decrement),
+            Command::Instruction(instruction) => instruction.rename_registers(rename).map(Command::Instruction),
+            Command::Increment(increment) => increment.rename_registers(rename).map(Command::Increment),
+        }
+    }
 }
 
 impl<N: Network> FromBytes for Command<N> {
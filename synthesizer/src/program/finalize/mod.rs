@@ -26,7 +26,7 @@ use output::*;
 mod bytes;
 mod parse;
 
-use crate::Instruction;
+use crate::{Instruction, Operand};
 use console::{
     network::prelude::*,
     program::{FinalizeType, Identifier, Register},
@@ -82,6 +82,29 @@ impl<N: Network> Finalize<N> {
     pub fn output_types(&self) -> Vec<FinalizeType<N>> {
         self.outputs.iter().map(|output| *output.finalize_type()).collect()
     }
+
+    /// Returns the finalize scope, with each register in its inputs, commands, and outputs
+    /// replaced by the given function.
+    ///
+    /// # Errors
+    /// This method will halt if a renamed input, destination, or output is not a register.
+    /// This method will halt if the renamed finalize scope is malformed, as when parsed.
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        let mut renamed = Self::new(self.name);
+        for input in &self.inputs {
+            renamed.add_input(input.rename_registers(rename)?)?;
+        }
+        for command in &self.commands {
+            renamed.add_command(command.rename_registers(rename)?)?;
+        }
+        for output in &self.outputs {
+            renamed.add_output(output.rename_registers(rename)?)?;
+        }
+        Ok(renamed)
+    }
 }
 
 impl<N: Network> Finalize<N> {
@@ -17,6 +17,7 @@
 mod bytes;
 mod parse;
 
+use crate::Operand;
 use console::{
     network::prelude::*,
     program::{Register, ValueType},
@@ -44,6 +45,15 @@ impl<N: Network> Input<N> {
     pub const fn value_type(&self) -> &ValueType<N> {
         &self.value_type
     }
+
+    /// Returns the input, with its register replaced by the register returned by the given function.
+    #[inline]
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        Ok(Self { register: Operand::rename_register(&self.register, rename)?, value_type: self.value_type })
+    }
 }
 
 impl<N: Network> TypeName for Input<N> {
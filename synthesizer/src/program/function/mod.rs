@@ -24,17 +24,18 @@ mod bytes;
 mod parse;
 
 use crate::{
-    program::finalize::{Finalize, FinalizeCommand},
+    program::finalize::{Command, Finalize, FinalizeCommand},
     Instruction,
+    Operand,
 };
 use console::{
     network::prelude::*,
     program::{Identifier, Register, ValueType},
 };
 
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct Function<N: Network> {
     /// The name of the function.
     name: Identifier<N>,
@@ -47,12 +48,24 @@ pub struct Function<N: Network> {
     outputs: IndexSet<Output<N>>,
     /// The optional finalize command and logic.
     finalize: Option<(FinalizeCommand<N>, Finalize<N>)>,
+    /// A map of input indices to the doc comments of the inputs, if parsed from a string.
+    input_docs: IndexMap<usize, String>,
+    /// A map of output indices to the doc comments of the outputs, if parsed from a string.
+    output_docs: IndexMap<usize, String>,
 }
 
 impl<N: Network> Function<N> {
     /// Initializes a new function with the given name.
     pub fn new(name: Identifier<N>) -> Self {
-        Self { name, inputs: IndexSet::new(), instructions: Vec::new(), outputs: IndexSet::new(), finalize: None }
+        Self {
+            name,
+            inputs: IndexSet::new(),
+            instructions: Vec::new(),
+            outputs: IndexSet::new(),
+            finalize: None,
+            input_docs: IndexMap::new(),
+            output_docs: IndexMap::new(),
+        }
     }
 
     /// Returns the name of the function.
@@ -75,6 +88,11 @@ impl<N: Network> Function<N> {
         &self.instructions
     }
 
+    /// Returns the function instructions, for rewriting in place.
+    pub(crate) fn instructions_mut(&mut self) -> &mut [Instruction<N>] {
+        &mut self.instructions
+    }
+
     /// Returns the function outputs.
     pub const fn outputs(&self) -> &IndexSet<Output<N>> {
         &self.outputs
@@ -85,6 +103,78 @@ impl<N: Network> Function<N> {
         self.outputs.iter().map(|output| *output.value_type()).collect()
     }
 
+    /// Returns the function output operands, which are either registers or literals.
+    pub fn output_operands(&self) -> Vec<Operand<N>> {
+        self.outputs.iter().map(|output| output.operand().clone()).collect()
+    }
+
+    /// Returns the function output registers, as used to construct the response.
+    /// A literal output has no register, and is `None`.
+    pub fn output_registers(&self) -> Vec<Option<Register<N>>> {
+        self.outputs.iter().map(|output| output.register().cloned()).collect()
+    }
+
+    /// Returns the function signature, as the input types and output types of the function.
+    pub fn signature(&self) -> (Vec<ValueType<N>>, Vec<ValueType<N>>) {
+        (self.input_types(), self.output_types())
+    }
+
+    /// Returns the registers of the function, in the order they are introduced,
+    /// as the input registers, followed by the destination registers of each instruction.
+    pub fn register_order(&self) -> Vec<Register<N>> {
+        self.inputs
+            .iter()
+            .map(|input| input.register().clone())
+            .chain(self.instructions.iter().flat_map(|instruction| instruction.destinations()))
+            .collect()
+    }
+
+    /// Returns the maximum number of operands of any instruction in the function.
+    pub fn max_operand_count(&self) -> usize {
+        self.instructions.iter().map(|instruction| instruction.operands().len()).max().unwrap_or(0)
+    }
+
+    /// Returns the doc comment of the input at the given index, if any.
+    pub fn input_doc(&self, index: usize) -> Option<&str> {
+        self.input_docs.get(&index).map(String::as_str)
+    }
+
+    /// Returns the doc comment of the output at the given index, if any.
+    pub fn output_doc(&self, index: usize) -> Option<&str> {
+        self.output_docs.get(&index).map(String::as_str)
+    }
+
+    /// Copies the doc comments of the inputs and outputs of the given function into this function.
+    pub(crate) fn copy_docs_from(&mut self, other: &Function<N>) {
+        self.input_docs = other.input_docs.clone();
+        self.output_docs = other.output_docs.clone();
+    }
+
+    /// Returns a function of the same name, and with the same doc comments, from the given
+    /// inputs, instructions, outputs, and finalize scope (e.g. after renaming their registers).
+    ///
+    /// # Errors
+    /// This method will halt if the given parts do not form a function, as when parsed.
+    pub(crate) fn reconstruct(
+        &self,
+        inputs: Vec<Input<N>>,
+        instructions: Vec<Instruction<N>>,
+        outputs: Vec<Output<N>>,
+        finalize: Option<(FinalizeCommand<N>, Finalize<N>)>,
+    ) -> Result<Self> {
+        // Initialize a new function.
+        let mut function = Self::new(self.name);
+        inputs.into_iter().try_for_each(|input| function.add_input(input))?;
+        instructions.into_iter().try_for_each(|instruction| function.add_instruction(instruction))?;
+        outputs.into_iter().try_for_each(|output| function.add_output(output))?;
+        if let Some((command, finalize)) = finalize {
+            function.add_finalize(command, finalize)?;
+        }
+        // Retain the doc comments of the inputs and outputs.
+        function.copy_docs_from(self);
+        Ok(function)
+    }
+
     /// Returns the function finalize logic.
     pub const fn finalize(&self) -> Option<&(FinalizeCommand<N>, Finalize<N>)> {
         self.finalize.as_ref()
@@ -99,6 +189,74 @@ impl<N: Network> Function<N> {
     pub fn finalize_logic(&self) -> Option<&Finalize<N>> {
         self.finalize.as_ref().map(|(_, finalize)| finalize)
     }
+
+    /// Returns `true` if the function is pure, meaning its outputs depend only on its inputs,
+    /// and evaluating it has no side effects. A function is not pure if it outputs a record,
+    /// contains an `assert` instruction, contains a `call` instruction, or has a finalize scope.
+    ///
+    /// Note: A `call` is conservatively considered impure, as the callee is not inspected.
+    pub fn is_pure(&self) -> bool {
+        // Ensure the function does not output a record.
+        let outputs_record = self
+            .outputs
+            .iter()
+            .any(|output| matches!(output.value_type(), ValueType::Record(..) | ValueType::ExternalRecord(..)));
+        // Ensure the function does not assert or call.
+        let has_side_effects = self.instructions.iter().any(|instruction| {
+            matches!(instruction, Instruction::AssertEq(..) | Instruction::AssertNeq(..) | Instruction::Call(..))
+        });
+        !outputs_record && !has_side_effects && self.finalize.is_none()
+    }
+
+    /// Returns `true` if the function is deterministic, meaning it does not contain
+    /// a nondeterministic instruction, in either the function or its finalize scope.
+    ///
+    /// Note: A `call` is not inspected; see `Program::function_is_deterministic` for local calls.
+    pub fn is_deterministic(&self) -> bool {
+        // Ensure the function instructions are deterministic.
+        let is_function_deterministic = self.instructions.iter().all(Instruction::is_deterministic);
+        // Ensure the finalize instructions are deterministic.
+        let is_finalize_deterministic = match self.finalize_logic() {
+            Some(finalize) => finalize.commands().iter().all(|command| match command {
+                Command::Instruction(instruction) => instruction.is_deterministic(),
+                Command::Decrement(..) | Command::Increment(..) => true,
+            }),
+            None => true,
+        };
+        is_function_deterministic && is_finalize_deterministic
+    }
+
+    /// Returns the names of the members of the given record input that are read by the function,
+    /// as the first identifier of each member register rooted at the input, in order of first appearance.
+    /// A record that is only passed through (e.g. as `r0`) does not have any of its members read.
+    ///
+    /// Note: The instructions, the outputs, and the finalize command are inspected.
+    pub fn reads_record_fields(&self, record_input: &Register<N>) -> Vec<Identifier<N>> {
+        // Retrieve the locator of the record input.
+        let locator = record_input.locator();
+
+        // Collect the registers read by the instructions, the outputs, and the finalize command.
+        let operands = self
+            .instructions
+            .iter()
+            .flat_map(|instruction| instruction.operands())
+            .chain(self.finalize_command().into_iter().flat_map(|command| command.operands()))
+            .filter_map(|operand| match operand {
+                Operand::Register(register) => Some(register),
+                _ => None,
+            });
+        let registers = operands.chain(self.outputs.iter().filter_map(|output| output.register()));
+
+        // Collect the member names of the registers rooted at the record input.
+        registers
+            .filter_map(|register| match register {
+                Register::Member(member_locator, path) if *member_locator == locator => path.first().copied(),
+                _ => None,
+            })
+            .collect::<IndexSet<_>>()
+            .into_iter()
+            .collect()
+    }
 }
 
 impl<N: Network> Function<N> {
@@ -211,6 +369,20 @@ impl<N: Network> Function<N> {
     }
 }
 
+impl<N: Network> PartialEq for Function<N> {
+    /// Returns `true` if the functions are equal.
+    /// Note: The doc comments are excluded, as they describe the function, but are not part of it.
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.inputs == other.inputs
+            && self.instructions == other.instructions
+            && self.outputs == other.outputs
+            && self.finalize == other.finalize
+    }
+}
+
+impl<N: Network> Eq for Function<N> {}
+
 impl<N: Network> TypeName for Function<N> {
     /// Returns the type name as a string.
     #[inline]
@@ -218,3 +390,197 @@ impl<N: Network> TypeName for Function<N> {
         "function"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_function_is_pure() -> Result<()> {
+        // Ensure a function that only computes on its inputs is pure.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+        assert!(function.is_pure());
+
+        // Ensure a function that outputs a record is not pure.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;",
+        )?;
+        assert!(!function.is_pure());
+
+        // Ensure a function that asserts is not pure.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function check:
+    input r0 as field.public;
+    input r1 as field.private;
+    assert.eq r0 r1;
+    output r0 as field.private;",
+        )?;
+        assert!(!function.is_pure());
+
+        // Ensure a function that calls is not pure.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function delegate:
+    input r0 as field.private;
+    call helper r0 into r1;
+    output r1 as field.private;",
+        )?;
+        assert!(!function.is_pure());
+
+        // Ensure a function with a finalize scope is not pure.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment account[r0] by r1;",
+        )?;
+        assert!(!function.is_pure());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_function_max_operand_count() -> Result<()> {
+        // Ensure a function without instructions has no operands.
+        let function = Function::<CurrentNetwork>::from_str("function empty:\n    input r0 as field.private;")?;
+        assert_eq!(function.max_operand_count(), 0);
+
+        // Ensure the largest instruction is counted.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as boolean.private;
+    input r1 as field.private;
+    add r1 r1 into r2;
+    ternary r0 r1 r2 into r3;
+    neg r3 into r4;
+    output r4 as field.private;",
+        )?;
+        assert_eq!(function.max_operand_count(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_function_register_order() -> Result<()> {
+        // Ensure the registers are ordered as the inputs, followed by the destinations.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+        let expected =
+            ["r0", "r1", "r2"].iter().map(|register| Register::from_str(register)).collect::<Result<Vec<_>>>()?;
+        assert_eq!(expected, function.register_order());
+
+        // Ensure an instruction with multiple destinations introduces them in order.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function delegate:
+    input r0 as field.private;
+    call helper r0 into r1 r2;
+    add r1 r2 into r3;
+    output r3 as field.private;",
+        )?;
+        let expected =
+            ["r0", "r1", "r2", "r3"].iter().map(|register| Register::from_str(register)).collect::<Result<Vec<_>>>()?;
+        assert_eq!(expected, function.register_order());
+        Ok(())
+    }
+
+    #[test]
+    fn test_function_reads_record_fields() -> Result<()> {
+        // Ensure the member read from the record input is reported, once.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as token.record;
+    add r0.token_amount r0.token_amount into r1;
+    output r1 as u64.private;",
+        )?;
+        let token_amount = Identifier::from_str("token_amount")?;
+        assert_eq!(vec![token_amount], function.reads_record_fields(&Register::from_str("r0")?));
+        // Ensure a register that is not a record input has no members read.
+        assert!(function.reads_record_fields(&Register::from_str("r1")?).is_empty());
+
+        // Ensure members are reported in order of first appearance, including through the outputs.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function transfer:
+    input r0 as token.record;
+    input r1 as address.private;
+    cast r1 r0.gates r0.token_amount into r2 as token.record;
+    output r2 as token.record;
+    output r0.owner as address.private;",
+        )?;
+        let expected = ["gates", "token_amount", "owner"]
+            .iter()
+            .map(|name| Identifier::from_str(name))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(expected, function.reads_record_fields(&Register::from_str("r0")?));
+
+        // Ensure a record that is only passed through has no members read.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function forward:
+    input r0 as token.record;
+    call helper r0 into r1;
+    output r1 as field.private;",
+        )?;
+        assert!(function.reads_record_fields(&Register::from_str("r0")?).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_function_is_deterministic() -> Result<()> {
+        // Ensure the `add` example is deterministic.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+        assert!(function.is_deterministic());
+
+        // Ensure a function with a finalize scope is deterministic.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as u64.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    increment account[r0] by r1;",
+        )?;
+        assert!(function.is_deterministic());
+        Ok(())
+    }
+}
@@ -19,16 +19,65 @@ use super::*;
 impl<N: Network> FromBytes for Output<N> {
     /// Reads the output from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let register = FromBytes::read_le(&mut reader)?;
+        // Read the variant. A register output begins with its own register variant (0 or 1),
+        // which keeps the encoding of register outputs unchanged.
+        let variant = u8::read_le(&mut reader)?;
+        // Read the operand.
+        let operand = match variant {
+            0 | 1 => Operand::Register(Register::read_le((&[variant][..]).chain(&mut reader))?),
+            2 => Operand::Literal(Literal::read_le(&mut reader)?),
+            3.. => return Err(error(format!("Failed to deserialize output variant {variant}"))),
+        };
+        // Read the value type.
         let value_type = FromBytes::read_le(&mut reader)?;
-        Ok(Self { register, value_type })
+        Ok(Self { operand, value_type })
     }
 }
 
 impl<N: Network> ToBytes for Output<N> {
     /// Writes the output to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        self.register.write_le(&mut writer)?;
+        // Write the operand. A register output is written as the register, which begins with its own variant (0 or 1),
+        // and a literal output is written as the variant 2 followed by the literal.
+        match &self.operand {
+            Operand::Register(register) => register.write_le(&mut writer)?,
+            Operand::Literal(literal) => {
+                2u8.write_le(&mut writer)?;
+                literal.write_le(&mut writer)?
+            }
+            _ => return Err(error("Failed to serialize output: invalid operand")),
+        }
+        // Write the value type.
         self.value_type.write_le(&mut writer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_output_bytes() -> Result<()> {
+        for (output, variant) in [
+            ("output r0 as field.private;", 0u8),
+            ("output r1.owner as address.public;", 1u8),
+            ("output 5u8 as u8.public;", 2u8),
+            ("output 1field as field.private;", 2u8),
+        ] {
+            let expected = Output::<CurrentNetwork>::from_str(output)?;
+            let bytes = expected.to_bytes_le()?;
+            // Ensure the output is prefixed by its variant.
+            assert_eq!(bytes[0], variant, "Output '{output}' has the wrong variant");
+            // Ensure the output round-trips.
+            assert_eq!(expected, Output::read_le(&bytes[..])?);
+        }
+        // Ensure an unknown variant is rejected.
+        let mut bytes = Output::<CurrentNetwork>::from_str("output r0 as field.private;")?.to_bytes_le()?;
+        bytes[0] = 3;
+        assert!(Output::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
+}
@@ -17,27 +17,46 @@
 mod bytes;
 mod parse;
 
+use crate::Operand;
 use console::{
     network::prelude::*,
-    program::{Register, ValueType},
+    program::{Literal, Register, ValueType},
 };
 
 /// An output statement defines an output of a function, and may refer to the value
-/// in either a register or a register member. An output statement is of the form
-/// `output {register} as {value_type};`.
+/// in either a register, a register member, or a literal. An output statement is of the form
+/// `output {register} as {value_type};` or `output {literal} as {value_type};`.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Output<N: Network> {
-    /// The output register.
-    register: Register<N>,
+    /// The output operand, which is either a register or a literal.
+    operand: Operand<N>,
     /// The output value type.
     value_type: ValueType<N>,
 }
 
 impl<N: Network> Output<N> {
-    /// Returns the output register.
+    /// Returns the output operand.
     #[inline]
-    pub const fn register(&self) -> &Register<N> {
-        &self.register
+    pub const fn operand(&self) -> &Operand<N> {
+        &self.operand
+    }
+
+    /// Returns the output register, if the output is not a literal.
+    #[inline]
+    pub const fn register(&self) -> Option<&Register<N>> {
+        match &self.operand {
+            Operand::Register(register) => Some(register),
+            _ => None,
+        }
+    }
+
+    /// Returns the output literal, if the output is a literal.
+    #[inline]
+    pub const fn literal(&self) -> Option<&Literal<N>> {
+        match &self.operand {
+            Operand::Literal(literal) => Some(literal),
+            _ => None,
+        }
     }
 
     /// Returns the output value type.
@@ -45,6 +64,15 @@ impl<N: Network> Output<N> {
     pub const fn value_type(&self) -> &ValueType<N> {
         &self.value_type
     }
+
+    /// Returns the output, with its register (if any) replaced by the operand returned by the given function.
+    #[inline]
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        Ok(Self { operand: self.operand.rename_registers(rename)?, value_type: self.value_type })
+    }
 }
 
 impl<N: Network> TypeName for Output<N> {
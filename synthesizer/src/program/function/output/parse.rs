@@ -18,7 +18,8 @@ use super::*;
 
 impl<N: Network> Parser for Output<N> {
     /// Parses a string into an output statement.
-    /// The output statement is of the form `output {register} as {value_type};`.
+    /// The output statement is of the form `output {register} as {value_type};`
+    /// or `output {literal} as {value_type};`.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the whitespace and comments from the string.
@@ -27,8 +28,9 @@ impl<N: Network> Parser for Output<N> {
         let (string, _) = tag(Self::type_name())(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
-        // Parse the register from the string.
-        let (string, register) = Register::parse(string)?;
+        // Parse the register or literal from the string.
+        let (string, operand) =
+            alt((map(Register::parse, Operand::Register), map(Literal::parse, Operand::Literal)))(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the "as" from the string.
@@ -42,7 +44,7 @@ impl<N: Network> Parser for Output<N> {
         // Parse the semicolon from the string.
         let (string, _) = tag(";")(string)?;
         // Return the output statement.
-        Ok((string, Self { register, value_type }))
+        Ok((string, Self { operand, value_type }))
     }
 }
 
@@ -76,9 +78,9 @@ impl<N: Network> Display for Output<N> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
             f,
-            "{type_} {register} as {value_type};",
+            "{type_} {operand} as {value_type};",
             type_ = Self::type_name(),
-            register = self.register,
+            operand = self.operand,
             value_type = self.value_type
         )
     }
@@ -95,19 +97,25 @@ mod tests {
     fn test_output_parse() -> Result<()> {
         // Literal
         let output = Output::<CurrentNetwork>::parse("output r0 as field.private;").unwrap().1;
-        assert_eq!(output.register(), &Register::<CurrentNetwork>::Locator(0));
+        assert_eq!(output.register(), Some(&Register::<CurrentNetwork>::Locator(0)));
         assert_eq!(output.value_type(), &ValueType::<CurrentNetwork>::from_str("field.private")?);
 
         // Struct
         let output = Output::<CurrentNetwork>::parse("output r1 as signature.private;").unwrap().1;
-        assert_eq!(output.register(), &Register::<CurrentNetwork>::Locator(1));
+        assert_eq!(output.register(), Some(&Register::<CurrentNetwork>::Locator(1)));
         assert_eq!(output.value_type(), &ValueType::<CurrentNetwork>::from_str("signature.private")?);
 
         // Record
         let output = Output::<CurrentNetwork>::parse("output r2 as token.record;").unwrap().1;
-        assert_eq!(output.register(), &Register::<CurrentNetwork>::Locator(2));
+        assert_eq!(output.register(), Some(&Register::<CurrentNetwork>::Locator(2)));
         assert_eq!(output.value_type(), &ValueType::<CurrentNetwork>::from_str("token.record")?);
 
+        // Constant
+        let output = Output::<CurrentNetwork>::parse("output 5field as field.constant;").unwrap().1;
+        assert_eq!(output.register(), None);
+        assert_eq!(output.literal(), Some(&Literal::<CurrentNetwork>::from_str("5field")?));
+        assert_eq!(output.value_type(), &ValueType::<CurrentNetwork>::from_str("field.constant")?);
+
         Ok(())
     }
 
@@ -124,5 +132,9 @@ mod tests {
         // Record
         let output = Output::<CurrentNetwork>::parse("output r2 as token.record;").unwrap().1;
         assert_eq!(format!("{}", output), "output r2 as token.record;");
+
+        // Constant
+        let output = Output::<CurrentNetwork>::parse("output 5field as field.constant;").unwrap().1;
+        assert_eq!(format!("{}", output), "output 5field as field.constant;");
     }
 }
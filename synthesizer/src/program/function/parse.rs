@@ -16,10 +16,12 @@
 
 use super::*;
 
-impl<N: Network> Parser for Function<N> {
-    /// Parses a string into a function.
-    #[inline]
-    fn parse(string: &str) -> ParserResult<Self> {
+impl<N: Network> Function<N> {
+    /// Parses a string into a function, along with the span of each instruction,
+    /// as the start and end byte offsets into the given string.
+    pub(crate) fn parse_with_spans(string: &str) -> ParserResult<'_, (Self, Vec<(usize, usize)>)> {
+        // Retain the input, to compute the offsets of the instructions.
+        let input = string;
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
         // Parse the 'function' keyword from the string.
@@ -33,12 +35,23 @@ impl<N: Network> Parser for Function<N> {
         // Parse the colon ':' keyword from the string.
         let (string, _) = tag(":")(string)?;
 
-        // Parse the inputs from the string.
-        let (string, inputs) = many0(Input::parse)(string)?;
-        // Parse the instructions from the string.
-        let (string, instructions) = many0(Instruction::parse)(string)?;
-        // Parse the outputs from the string.
-        let (string, outputs) = many0(Output::parse)(string)?;
+        // Parse the inputs from the string, along with their doc comments.
+        let (string, inputs) = many0(pair(Self::parse_doc_comment, Input::parse))(string)?;
+        let (input_docs, inputs): (Vec<_>, Vec<_>) = inputs.into_iter().unzip();
+        // Parse the instructions from the string, along with their spans.
+        let (string, instructions) = many0(|string| {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the instruction from the string.
+            let (remainder, instruction) = Instruction::parse(string)?;
+            // Compute the span of the instruction.
+            let span = (input.len() - string.len(), input.len() - remainder.len());
+            Ok((remainder, (instruction, span)))
+        })(string)?;
+        let (instructions, spans): (Vec<_>, Vec<_>) = instructions.into_iter().unzip();
+        // Parse the outputs from the string, along with their doc comments.
+        let (string, outputs) = many0(pair(Self::parse_doc_comment, Output::parse))(string)?;
+        let (output_docs, outputs): (Vec<_>, Vec<_>) = outputs.into_iter().unzip();
 
         // Parse an optional finalize command from the string.
         let (string, command) = opt(FinalizeCommand::parse)(string)?;
@@ -76,20 +89,70 @@ impl<N: Network> Parser for Function<N> {
                     return Err(error);
                 }
             }
-            Ok::<_, Error>(function)
+            // Attach the doc comments of the inputs and outputs.
+            let docs = |docs: &[Option<String>]| {
+                docs.iter().enumerate().filter_map(|(index, doc)| Some((index, doc.clone()?))).collect()
+            };
+            function.input_docs = docs(&input_docs);
+            function.output_docs = docs(&output_docs);
+            Ok::<_, Error>((function, spans.clone()))
         })(string)
     }
+
+    /// Parses the doc comment preceding an input or output statement, if any,
+    /// as the text of the consecutive `///` lines, joined by newlines.
+    fn parse_doc_comment(string: &str) -> ParserResult<'_, Option<String>> {
+        // Parse each line of the doc comment.
+        let (string, lines) = many0(|string| {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Ensure the comment is a doc comment.
+            let (_, _) = tag("///")(string)?;
+            // Parse the comment from the string, which ensures its characters are safe.
+            let (string, body) = Sanitizer::parse_comment(string)?;
+            // Remove the third '/', and the conventional space, from the body of the comment.
+            let line = body[1..].strip_prefix(' ').unwrap_or(&body[1..]).trim_end();
+            Ok((string, line))
+        })(string)?;
+        match lines.is_empty() {
+            true => Ok((string, None)),
+            false => Ok((string, Some(lines.join("\n")))),
+        }
+    }
+}
+
+impl<N: Network> Parser for Function<N> {
+    /// Parses a string into a function.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        map(Self::parse_with_spans, |(function, _)| function)(string)
+    }
 }
 
 impl<N: Network> FromStr for Function<N> {
     type Err = Error;
 
     /// Returns a function from a string literal.
+    /// On failure, the error reports the input, instruction, or output that failed to parse, if any.
     fn from_str(string: &str) -> Result<Self> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                if !remainder.is_empty() {
+                    // Skip the whitespace and comments preceding the statement that failed to parse.
+                    let remainder = Sanitizer::parse(remainder).map_or(remainder, |(remainder, _)| remainder);
+                    // Determine the statement that failed to parse, from its leading keyword.
+                    let statement = match remainder {
+                        line if line.starts_with("input ") => format!("input {}", object.inputs.len()),
+                        line if line.starts_with("output ") => format!("output {}", object.outputs.len()),
+                        line if line.starts_with("finalize") => "finalize".to_string(),
+                        _ => format!("instruction {}", object.instructions.len()),
+                    };
+                    bail!(
+                        "Failed to parse string. In function '{}', failed parsing {statement}: \"{remainder}\"",
+                        object.name
+                    )
+                }
                 // Return the object.
                 Ok(object)
             }
@@ -110,9 +173,22 @@ impl<N: Network> Display for Function<N> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         // Write the function to a string.
         write!(f, "{} {}:", Self::type_name(), self.name)?;
-        self.inputs.iter().try_for_each(|input| write!(f, "\n    {input}"))?;
+        // A helper to write the doc comment of an input or output, if any.
+        let write_doc = |f: &mut Formatter, doc: Option<&str>| {
+            doc.into_iter().flat_map(str::lines).try_for_each(|line| match line.is_empty() {
+                true => write!(f, "\n    ///"),
+                false => write!(f, "\n    /// {line}"),
+            })
+        };
+        for (index, input) in self.inputs.iter().enumerate() {
+            write_doc(f, self.input_doc(index))?;
+            write!(f, "\n    {input}")?;
+        }
         self.instructions.iter().try_for_each(|instruction| write!(f, "\n    {instruction}"))?;
-        self.outputs.iter().try_for_each(|output| write!(f, "\n    {output}"))?;
+        for (index, output) in self.outputs.iter().enumerate() {
+            write_doc(f, self.output_doc(index))?;
+            write!(f, "\n    {output}")?;
+        }
 
         // If finalize exists, write it out.
         if let Some((command, finalize)) = &self.finalize {
@@ -195,6 +271,49 @@ function foo:
         assert_eq!(0, function.outputs.len());
     }
 
+    #[test]
+    fn test_function_from_str_context() {
+        // Ensure a malformed instruction is reported with its index.
+        let error = Function::<CurrentNetwork>::from_str(
+            r"
+function foo:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    add r1 into r2;
+    output r1 as field.private;",
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Failed to parse string. In function 'foo', failed parsing instruction 1"));
+
+        // Ensure a malformed output is reported with its index.
+        let error = Function::<CurrentNetwork>::from_str(
+            r"
+function foo:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field;",
+        )
+        .unwrap_err();
+        assert!(error.to_string().starts_with("Failed to parse string. In function 'foo', failed parsing output 0"));
+
+        // Ensure a comment preceding the malformed statement does not change the statement that is reported.
+        let error = Function::<CurrentNetwork>::from_str(
+            r"
+function foo:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    // The output is missing its visibility.
+    output r1 as field;",
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse string. In function 'foo', failed parsing output 0: \"output r1 as field;\""
+        );
+    }
+
     #[test]
     fn test_function_parse_finalize() {
         let function = Function::<CurrentNetwork>::parse(
@@ -286,4 +405,43 @@ finalize compute:
         let function = Function::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(expected, format!("{function}"),);
     }
+
+    #[test]
+    fn test_function_doc_comments() -> Result<()> {
+        let expected = r"function sum:
+    /// The first addend.
+    input r0 as field.public;
+    /// The second addend,
+    /// which is private.
+    input r1 as field.private;
+    add r0 r1 into r2;
+    /// The sum.
+    output r2 as field.private;";
+
+        // Ensure the doc comments are captured.
+        let function = Function::<CurrentNetwork>::from_str(expected)?;
+        assert_eq!(function.input_doc(0), Some("The first addend."));
+        assert_eq!(function.input_doc(1), Some("The second addend,\nwhich is private."));
+        assert_eq!(function.input_doc(2), None);
+        assert_eq!(function.output_doc(0), Some("The sum."));
+
+        // Ensure the doc comments are re-emitted, and round-trip.
+        assert_eq!(expected, function.to_string());
+        assert_eq!(expected, Function::<CurrentNetwork>::from_str(&function.to_string())?.to_string());
+
+        // Ensure the doc comments do not affect equality, or the bytes of the function.
+        let undocumented = Function::<CurrentNetwork>::from_str(
+            r"
+function sum:
+    // A regular comment is not a doc comment.
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+        assert_eq!(undocumented.input_doc(0), None);
+        assert_eq!(function, undocumented);
+        assert_eq!(function.to_bytes_le()?, undocumented.to_bytes_le()?);
+        Ok(())
+    }
 }
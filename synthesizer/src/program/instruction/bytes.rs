@@ -95,4 +95,74 @@ mod tests {
         assert_eq!(expected_bytes, candidate.to_bytes_le()?);
         Ok(())
     }
+
+    #[test]
+    fn test_opcode_indices() {
+        // The opcode index of each instruction is encoded in its bytes, and must never change.
+        let opcodes = Instruction::<CurrentNetwork>::OPCODES.iter().map(|opcode| **opcode).collect::<Vec<_>>();
+        let expected = [
+            "abs",
+            "abs.w",
+            "add",
+            "add.w",
+            "and",
+            "assert.eq",
+            "assert.neq",
+            "call",
+            "cast",
+            "commit.bhp256",
+            "commit.bhp512",
+            "commit.bhp768",
+            "commit.bhp1024",
+            "commit.ped64",
+            "commit.ped128",
+            "div",
+            "div.w",
+            "double",
+            "gt",
+            "gte",
+            "hash.bhp256",
+            "hash.bhp512",
+            "hash.bhp768",
+            "hash.bhp1024",
+            "hash.ped64",
+            "hash.ped128",
+            "hash.psd2",
+            "hash.psd4",
+            "hash.psd8",
+            "inv",
+            "is.eq",
+            "is.neq",
+            "lt",
+            "lte",
+            "mod",
+            "mul",
+            "mul.w",
+            "nand",
+            "neg",
+            "nor",
+            "not",
+            "or",
+            "pow",
+            "pow.w",
+            "rem",
+            "rem.w",
+            "shl",
+            "shl.w",
+            "shr",
+            "shr.w",
+            "square",
+            "sqrt",
+            "sub",
+            "sub.w",
+            "ternary",
+            "xor",
+            "return",
+        ];
+        assert_eq!(
+            expected.to_vec(),
+            opcodes,
+            "The opcode indices must not change. Append new instructions at the end."
+        );
+    }
 }
@@ -171,6 +171,10 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Returns the given registers as the outputs of the function, and stops its execution.
+    ///
+    /// Note: This variant is last, as the opcode index of each instruction is its position in `OPCODES`.
+    Return(Return<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -258,6 +262,7 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            Return,
         }}
     };
     // A variant **without** curly braces:
@@ -331,6 +336,9 @@ macro_rules! opcodes {
 }
 
 impl<N: Network> Instruction<N> {
+    /// The list of instruction opcodes whose outcome is not fully determined by their operands.
+    /// Note: There are no nondeterministic opcodes yet; a randomized opcode must be listed here.
+    pub const NONDETERMINISTIC_OPCODES: &'static [Opcode] = &[];
     /// The list of all instruction opcodes.
     pub const OPCODES: &'static [Opcode] = &instruction!(opcodes, Instruction, |None| {});
 
@@ -352,6 +360,43 @@ impl<N: Network> Instruction<N> {
         instruction!(self, |instruction| instruction.destinations())
     }
 
+    /// Returns the instruction, with each register in its operands and destinations replaced by the given function.
+    #[inline]
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        instruction!(self, |instruction| instruction.rename_registers(rename).map(Into::into))
+    }
+
+    /// Returns `true` if the instruction is commutative, meaning its two operands may be swapped
+    /// without changing the outcome or the set of accepted operand types.
+    #[inline]
+    pub const fn is_commutative(&self) -> bool {
+        matches!(
+            self,
+            Self::Add(..)
+                | Self::AddWrapped(..)
+                | Self::And(..)
+                | Self::AssertEq(..)
+                | Self::AssertNeq(..)
+                | Self::IsEq(..)
+                | Self::IsNeq(..)
+                | Self::Mul(..)
+                | Self::MulWrapped(..)
+                | Self::Nand(..)
+                | Self::Nor(..)
+                | Self::Or(..)
+                | Self::Xor(..)
+        )
+    }
+
+    /// Returns `true` if the instruction is deterministic, meaning its outcome is fully determined by its operands.
+    #[inline]
+    pub fn is_deterministic(&self) -> bool {
+        !Self::NONDETERMINISTIC_OPCODES.contains(&self.opcode())
+    }
+
     /// Evaluates the instruction.
     #[inline]
     pub fn evaluate<A: circuit::Aleo<Network = N>>(
@@ -396,17 +441,164 @@ impl<N: Network> Display for Instruction<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::network::Testnet3;
+    use crate::{AddOperation, MulOperation, Operation};
+    use console::{
+        network::{
+            prelude::{One, ToField, Zero},
+            Testnet3,
+        },
+        program::{Literal, LiteralType},
+        types::{Field, Scalar},
+    };
 
     type CurrentNetwork = Testnet3;
 
+    #[test]
+    fn test_supports_opcode() {
+        // Retrieve every literal type.
+        let literal_types = (0..=u8::MAX).filter_map(LiteralType::from_tag).collect::<Vec<_>>();
+
+        // Returns the literal types that the given operation accepts as its first operand,
+        // with any other operand types.
+        fn first_operand_types<O, const NUM_OPERANDS: usize>(literal_types: &[LiteralType]) -> Vec<LiteralType>
+        where
+            O: Operation<CurrentNetwork, Literal<CurrentNetwork>, LiteralType, NUM_OPERANDS>,
+        {
+            let num_combinations = literal_types.len().pow(NUM_OPERANDS as u32 - 1);
+            literal_types
+                .iter()
+                .copied()
+                .filter(|first| {
+                    // Enumerate every combination of the remaining operand types.
+                    (0..num_combinations).any(|mut index| {
+                        let mut inputs = [*first; NUM_OPERANDS];
+                        for input in inputs.iter_mut().skip(1) {
+                            *input = literal_types[index % literal_types.len()];
+                            index /= literal_types.len();
+                        }
+                        O::output_type(&inputs).is_ok()
+                    })
+                })
+                .collect()
+        }
+
+        // Ensure `LiteralType::supports_opcode` matches the operand types of each literal operation.
+        macro_rules! check_operations {
+            ($( $operation:ident < $num_operands:literal > ),+ $(,)?) => {{
+                let mut opcodes = Vec::new();
+                $({
+                    type O = crate::$operation<CurrentNetwork>;
+                    let opcode =
+                        <O as Operation<CurrentNetwork, Literal<CurrentNetwork>, LiteralType, $num_operands>>::OPCODE;
+                    let expected = first_operand_types::<O, $num_operands>(&literal_types);
+                    for literal_type in &literal_types {
+                        assert_eq!(
+                            expected.contains(literal_type),
+                            literal_type.supports_opcode(&opcode),
+                            "'{opcode}' on '{literal_type}'"
+                        );
+                    }
+                    opcodes.push(opcode);
+                })+
+                opcodes
+            }};
+        }
+        let literal_opcodes = check_operations!(
+            AbsOperation<1>, AbsWrappedOperation<1>, AddOperation<2>, AddWrappedOperation<2>, AndOperation<2>,
+            DivOperation<2>, DivWrappedOperation<2>, DoubleOperation<1>, GreaterThanOperation<2>,
+            GreaterThanOrEqualOperation<2>, InvOperation<1>, LessThanOperation<2>, LessThanOrEqualOperation<2>,
+            ModuloOperation<2>, MulOperation<2>, MulWrappedOperation<2>, NandOperation<2>, NegOperation<1>,
+            NorOperation<2>, NotOperation<1>, OrOperation<2>, PowOperation<2>, PowWrappedOperation<2>,
+            RemOperation<2>, RemWrappedOperation<2>, ShlOperation<2>, ShlWrappedOperation<2>, ShrOperation<2>,
+            ShrWrappedOperation<2>, SquareOperation<1>, SquareRootOperation<1>, SubOperation<2>,
+            SubWrappedOperation<2>, TernaryOperation<3>, XorOperation<2>,
+        );
+
+        // Ensure every other opcode either accepts any literal type, or does not operate on literals.
+        for opcode in Instruction::<CurrentNetwork>::OPCODES.iter().filter(|opcode| !literal_opcodes.contains(opcode)) {
+            let expected = !matches!(**opcode, "call" | "return");
+            for literal_type in &literal_types {
+                assert_eq!(expected, literal_type.supports_opcode(opcode), "'{opcode}' on '{literal_type}'");
+            }
+        }
+    }
+
     #[test]
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            56,
+            57,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
     }
+
+    #[test]
+    fn test_is_commutative() -> Result<()> {
+        let instruction = |string: &str| Instruction::<CurrentNetwork>::from_str(string);
+
+        assert!(instruction("add r0 r1 into r2;")?.is_commutative());
+        assert!(instruction("mul r0 r1 into r2;")?.is_commutative());
+        assert!(instruction("and r0 r1 into r2;")?.is_commutative());
+        assert!(instruction("is.eq r0 r1 into r2;")?.is_commutative());
+
+        assert!(!instruction("sub r0 r1 into r2;")?.is_commutative());
+        assert!(!instruction("div r0 r1 into r2;")?.is_commutative());
+        assert!(!instruction("lt r0 r1 into r2;")?.is_commutative());
+        assert!(!instruction("ternary r0 r1 r2 into r3;")?.is_commutative());
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_deterministic() -> Result<()> {
+        // Ensure every nondeterministic opcode is an instruction opcode.
+        for opcode in Instruction::<CurrentNetwork>::NONDETERMINISTIC_OPCODES {
+            assert!(Instruction::<CurrentNetwork>::OPCODES.contains(opcode), "Unknown opcode '{opcode}'");
+        }
+
+        let instruction = |string: &str| Instruction::<CurrentNetwork>::from_str(string);
+        assert!(instruction("add r0 r1 into r2;")?.is_deterministic());
+        assert!(instruction("hash.psd2 r0 into r1;")?.is_deterministic());
+        assert!(instruction("ternary r0 r1 r2 into r3;")?.is_deterministic());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scalar_arithmetic() -> Result<()> {
+        type Add = AddOperation<CurrentNetwork>;
+        type Mul = MulOperation<CurrentNetwork>;
+
+        let one = Scalar::<CurrentNetwork>::one();
+        let minus_one = -one;
+
+        // Ensure scalar addition reduces by the scalar field modulus.
+        let sum = <Add as Operation<_, _, _, 2>>::evaluate(&[Literal::Scalar(minus_one), Literal::Scalar(one)])?;
+        assert_eq!(sum, Literal::Scalar(Scalar::zero()));
+        let sum = <Add as Operation<_, _, _, 2>>::evaluate(&[Literal::Scalar(minus_one), Literal::Scalar(minus_one)])?;
+        assert_eq!(sum, Literal::Scalar(minus_one + minus_one));
+        assert_eq!(sum, Literal::Scalar(-(one + one)));
+
+        // Ensure scalar addition is distinct from base field addition on the same integer.
+        let field = <Add as Operation<_, _, _, 2>>::evaluate(&[
+            Literal::Field(minus_one.to_field()?),
+            Literal::Field(Field::one()),
+        ])?;
+        assert_ne!(field, Literal::Field(Field::zero()));
+
+        // Ensure mixing scalar and field operands is rejected.
+        assert!(<Add as Operation<_, _, _, 2>>::output_type(&[LiteralType::Field, LiteralType::Scalar]).is_err());
+        assert!(<Add as Operation<_, _, _, 2>>::output_type(&[LiteralType::Scalar, LiteralType::Field]).is_err());
+        assert!(
+            <Add as Operation<_, _, _, 2>>::evaluate(&[Literal::Field(Field::one()), Literal::Scalar(one)]).is_err()
+        );
+        assert_eq!(
+            <Add as Operation<_, _, _, 2>>::output_type(&[LiteralType::Scalar, LiteralType::Scalar])?,
+            LiteralType::Scalar
+        );
+
+        // Ensure scalar multiplication is rejected, as it is not supported in circuits.
+        assert!(<Mul as Operation<_, _, _, 2>>::output_type(&[LiteralType::Scalar, LiteralType::Scalar]).is_err());
+        assert!(<Mul as Operation<_, _, _, 2>>::evaluate(&[Literal::Scalar(one), Literal::Scalar(one)]).is_err());
+        Ok(())
+    }
 }
@@ -17,9 +17,10 @@
 mod bytes;
 mod parse;
 
+use crate::Program;
 use console::{
     network::prelude::*,
-    program::{Literal, ProgramID, Register},
+    program::{Identifier, Literal, ProgramID, Register},
 };
 
 /// The `Operand` enum represents the options for an operand in an instruction.
@@ -34,6 +35,8 @@ pub enum Operand<N: Network> {
     ProgramID(ProgramID<N>),
     /// The operand is the caller address.
     Caller,
+    /// The operand is a global constant of the program.
+    Global(Identifier<N>),
 }
 
 impl<N: Network> From<Literal<N>> for Operand<N> {
@@ -68,6 +71,33 @@ impl<N: Network> From<&Register<N>> for Operand<N> {
     }
 }
 
+impl<N: Network> Operand<N> {
+    /// Returns the operand, with its register (if any) replaced by the operand returned by the given function.
+    #[inline]
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        match self {
+            Self::Register(register) => rename(register),
+            _ => Ok(self.clone()),
+        }
+    }
+
+    /// Returns the given register, replaced by the operand returned by the given function,
+    /// which must be a register (e.g. for a destination or an input).
+    #[inline]
+    pub(crate) fn rename_register<F>(register: &Register<N>, rename: &mut F) -> Result<Register<N>>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        match rename(register)? {
+            Self::Register(renamed) => Ok(renamed),
+            operand => bail!("Register '{register}' cannot be replaced by '{operand}', as it must be a register"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -26,6 +26,10 @@ impl<N: Network> Parser for Operand<N> {
             map(Register::parse, |register| Self::Register(register)),
             map(tag("self.caller"), |_| Self::Caller),
             map(ProgramID::parse, |program_id| Self::ProgramID(program_id)),
+            map_res(Identifier::parse, |identifier| match Program::<N>::is_reserved_keyword(&identifier) {
+                true => Err(error(format!("'{identifier}' is a reserved keyword"))),
+                false => Ok(Self::Global(identifier)),
+            }),
         ))(string)
     }
 }
@@ -67,6 +71,8 @@ impl<N: Network> Display for Operand<N> {
             Self::ProgramID(program_id) => Display::fmt(program_id, f),
             // Prints the caller, i.e. self.caller
             Self::Caller => write!(f, "self.caller"),
+            // Prints the global constant, i.e. PI
+            Self::Global(name) => Display::fmt(name, f),
         }
     }
 }
@@ -83,6 +89,9 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("1field").unwrap().1;
         assert_eq!(Operand::Literal(Literal::from_str("1field")?), operand);
 
+        let operand = Operand::<CurrentNetwork>::parse("-5i32").unwrap().1;
+        assert_eq!(Operand::Literal(Literal::from_str("-5i32")?), operand);
+
         let operand = Operand::<CurrentNetwork>::parse("r0").unwrap().1;
         assert_eq!(Operand::Register(Register::from_str("r0")?), operand);
 
@@ -95,6 +104,9 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("self.caller").unwrap().1;
         assert_eq!(Operand::Caller, operand);
 
+        let operand = Operand::<CurrentNetwork>::parse("PI").unwrap().1;
+        assert_eq!(Operand::Global(Identifier::from_str("PI")?), operand);
+
         // Sanity check a failure case.
         let (remainder, operand) = Operand::<CurrentNetwork>::parse("1field.private").unwrap();
         assert_eq!(Operand::Literal(Literal::from_str("1field")?), operand);
@@ -108,6 +120,9 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("1field").unwrap().1;
         assert_eq!(format!("{operand}"), "1field");
 
+        let operand = Operand::<CurrentNetwork>::parse("-5i32").unwrap().1;
+        assert_eq!(format!("{operand}"), "-5i32");
+
         let operand = Operand::<CurrentNetwork>::parse("r0").unwrap().1;
         assert_eq!(format!("{operand}"), "r0");
 
@@ -119,11 +134,18 @@ mod tests {
 
         let operand = Operand::<CurrentNetwork>::parse("self.caller").unwrap().1;
         assert_eq!(format!("{operand}"), "self.caller");
+
+        let operand = Operand::<CurrentNetwork>::parse("PI").unwrap().1;
+        assert_eq!(format!("{operand}"), "PI");
     }
 
     #[test]
     fn test_operand_from_str_fails() -> Result<()> {
         assert!(Operand::<CurrentNetwork>::from_str("1field.private").is_err());
+        assert!(Operand::<CurrentNetwork>::from_str("-1u8").is_err());
+        // Ensure a reserved keyword is not parsed as a global constant.
+        assert!(Operand::<CurrentNetwork>::parse("into").is_err());
+        assert!(Operand::<CurrentNetwork>::parse("as").is_err());
         Ok(())
     }
 }
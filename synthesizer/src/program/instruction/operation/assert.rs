@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Registers, Stack};
+use crate::{EvaluationError, Opcode, Operand, Registers, Stack};
 use console::{
     network::prelude::*,
     program::{Register, RegisterType},
@@ -62,6 +62,17 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![]
     }
+
+    /// Returns the operation, with each register in its operands replaced by the given function.
+    #[inline]
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        Ok(Self {
+            operands: self.operands.iter().map(|operand| operand.rename_registers(rename)).collect::<Result<_>>()?,
+        })
+    }
 }
 
 impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
@@ -85,12 +96,20 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
         match VARIANT {
             0 => {
                 if input_a != input_b {
-                    bail!("'{}' failed: '{input_a}' is not equal to '{input_b}' (should be equal)", Self::opcode())
+                    return Err(EvaluationError::<N>::AssertionFailed(format!(
+                        "'{}' failed: '{input_a}' is not equal to '{input_b}' (should be equal)",
+                        Self::opcode()
+                    ))
+                    .into());
                 }
             }
             1 => {
                 if input_a == input_b {
-                    bail!("'{}' failed: '{input_a}' is equal to '{input_b}' (should not be equal)", Self::opcode())
+                    return Err(EvaluationError::<N>::AssertionFailed(format!(
+                        "'{}' failed: '{input_a}' is equal to '{input_b}' (should not be equal)",
+                        Self::opcode()
+                    ))
+                    .into());
                 }
             }
             _ => bail!("Invalid 'assert' variant: {VARIANT}"),
@@ -142,6 +142,21 @@ impl<N: Network> Call<N> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         self.destinations.clone()
     }
+
+    /// Returns the operation, with each register in its operands and destinations replaced by the given function.
+    #[inline]
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        let operands = self.operands.iter().map(|operand| operand.rename_registers(rename)).collect::<Result<_>>()?;
+        let destinations = self
+            .destinations
+            .iter()
+            .map(|destination| Operand::rename_register(destination, rename))
+            .collect::<Result<_>>()?;
+        Ok(Self { operator: self.operator.clone(), operands, destinations })
+    }
 }
 
 impl<N: Network> Call<N> {
@@ -191,17 +206,15 @@ impl<N: Network> Call<N> {
 
         // If the operator is a closure, retrieve the closure and compute the output.
         let outputs = if let Ok(closure) = substack.program().get_closure(resource) {
-            // Ensure the number of inputs matches the number of input statements.
-            if closure.inputs().len() != inputs.len() {
-                bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
-            }
-            // Evaluate the closure, and load the outputs.
-            substack.evaluate_closure::<A>(
+            // Evaluate the closure (within the deadline of the caller), and load the outputs.
+            // Note: The number of inputs is checked against the input statements by the closure evaluation.
+            substack.evaluate_closure_with_deadline::<A>(
                 &closure,
-                &inputs,
+                inputs,
                 registers.call_stack(),
                 registers.caller()?,
                 registers.tvk()?,
+                registers.deadline(),
             )?
         }
         // If the operator is a function, retrieve the function and compute the output.
@@ -210,8 +223,9 @@ impl<N: Network> Call<N> {
             if function.inputs().len() != inputs.len() {
                 bail!("Expected {} inputs, found {}", function.inputs().len(), inputs.len())
             }
-            // Evaluate the function.
-            let response = substack.evaluate_function::<A>(registers.call_stack())?;
+            // Evaluate the function (within the deadline of the caller).
+            let (response, ..) =
+                substack.evaluate_function_with_deadline::<A>(registers.call_stack(), registers.deadline())?;
             // Load the outputs.
             response.outputs().to_vec()
         }
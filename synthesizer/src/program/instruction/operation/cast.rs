@@ -67,6 +67,17 @@ impl<N: Network> Cast<N> {
         vec![self.destination.clone()]
     }
 
+    /// Returns the operation, with each register in its operands and destinations replaced by the given function.
+    #[inline]
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        let operands = self.operands.iter().map(|operand| operand.rename_registers(rename)).collect::<Result<_>>()?;
+        let destination = Operand::rename_register(&self.destination, rename)?;
+        Ok(Self { operands, destination, register_type: self.register_type })
+    }
+
     /// Returns the casted register type.
     #[inline]
     pub const fn register_type(&self) -> &RegisterType<N> {
@@ -93,6 +104,13 @@ impl<N: Network> Cast<N> {
 
                 // Retrieve the struct and ensure it is defined in the program.
                 let struct_ = stack.program().get_struct(&struct_name)?;
+                // Ensure the number of operands matches the number of struct members.
+                ensure!(
+                    inputs.len() == struct_.members().len(),
+                    "Casting to the struct '{struct_name}' requires {} operands, found {} operands",
+                    struct_.members().len(),
+                    inputs.len()
+                );
 
                 // Initialize the struct members.
                 let mut members = IndexMap::new();
@@ -190,7 +208,7 @@ impl<N: Network> Cast<N> {
                 // Construct the record.
                 let record = Record::<N, Plaintext<N>>::from_plaintext(owner, gates, entries, nonce)?;
                 // Store the record.
-                registers.store(stack, &self.destination, Value::Record(record))
+                registers.store(stack, &self.destination, Value::from(record))
             }
             RegisterType::ExternalRecord(_locator) => {
                 bail!("Illegal operation: Cannot cast to an external record.")
@@ -219,6 +237,14 @@ impl<N: Network> Cast<N> {
 
                 // Retrieve the struct and ensure it is defined in the program.
                 let struct_ = stack.program().get_struct(&struct_)?;
+                // Ensure the number of operands matches the number of struct members.
+                ensure!(
+                    inputs.len() == struct_.members().len(),
+                    "Casting to the struct '{}' requires {} operands, found {} operands",
+                    struct_.name(),
+                    struct_.members().len(),
+                    inputs.len()
+                );
 
                 // Initialize the struct members.
                 let mut members = IndexMap::new();
@@ -359,6 +385,13 @@ impl<N: Network> Cast<N> {
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Retrieve the struct and ensure it is defined in the program.
                 let struct_ = stack.program().get_struct(&struct_name)?;
+                // Ensure the number of input types matches the number of struct members.
+                ensure!(
+                    input_types.len() == struct_.members().len(),
+                    "Casting to the struct '{struct_name}' requires {} operands, found {} operands",
+                    struct_.members().len(),
+                    input_types.len()
+                );
                 // Ensure the input types match the struct.
                 for ((_, member_type), input_type) in struct_.members().iter().zip_eq(input_types) {
                     match input_type {
@@ -81,6 +81,17 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the operation, with each register in its operands and destinations replaced by the given function.
+    #[inline]
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        let operands = self.operands.iter().map(|operand| operand.rename_registers(rename)).collect::<Result<_>>()?;
+        let destination = Operand::rename_register(&self.destination, rename)?;
+        Ok(Self { operands, destination })
+    }
 }
 
 impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
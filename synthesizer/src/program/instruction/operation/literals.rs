@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Operation, Registers, Stack};
+use crate::{EvaluationError, Opcode, Operand, Operation, Registers, Stack};
 use console::{
     network::prelude::*,
     program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
@@ -59,6 +59,17 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the operation, with each register in its operands and destinations replaced by the given function.
+    #[inline]
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        let operands = self.operands.iter().map(|operand| operand.rename_registers(rename)).collect::<Result<_>>()?;
+        let destination = Operand::rename_register(&self.destination, rename)?;
+        Ok(Self { operands, destination, _phantom: PhantomData })
+    }
 }
 
 impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const NUM_OPERANDS: usize>
@@ -84,7 +95,29 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
             inputs.iter().map(|input| RegisterType::Plaintext(PlaintextType::from(input.to_type()))).collect();
 
         // Compute the operation.
-        let output = O::evaluate(&inputs.try_into().map_err(|_| anyhow!("Failed to prepare operands in evaluate"))?)?;
+        let inputs: [Literal<N>; NUM_OPERANDS] =
+            inputs.try_into().map_err(|_| anyhow!("Failed to prepare operands in evaluate"))?;
+        // Note: A checked operation halts (i.e. panics) on an overflow or a division by zero,
+        // so the halt is caught here, and reported as an evaluation error instead.
+        // The inputs are only read by the operation, so they remain valid if it halts.
+        let output = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| O::evaluate(&inputs))) {
+            Ok(Ok(output)) => output,
+            Ok(Err(error)) => match Self::halt_error(&inputs, error.to_string()) {
+                Some(error) => return Err(error.into()),
+                None => return Err(error),
+            },
+            Err(payload) => {
+                let message = match (payload.downcast_ref::<String>(), payload.downcast_ref::<&str>()) {
+                    (Some(message), _) => message.clone(),
+                    (None, Some(message)) => message.to_string(),
+                    (None, None) => format!("'{}' halted", O::OPCODE),
+                };
+                match Self::halt_error(&inputs, message.clone()) {
+                    Some(error) => return Err(error.into()),
+                    None => bail!(message),
+                }
+            }
+        };
         // Compute the output type.
         let output_type = RegisterType::Plaintext(PlaintextType::from(output.to_type()));
 
@@ -99,6 +132,24 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
         registers.store_literal(stack, &self.destination, output)
     }
 
+    /// Returns the evaluation error for the operation halting (or failing) on the given inputs,
+    /// with the given message, if the operation is a checked arithmetic operation.
+    ///
+    /// Note: The halt is a division by zero if the divisor (or the inverted value) is zero, and an overflow otherwise.
+    fn halt_error(inputs: &[Literal<N>], message: String) -> Option<EvaluationError<N>> {
+        // Returns `true` if the input at the given index is zero.
+        let is_zero = |index: usize| inputs.get(index).map_or(false, |input| *input == Literal::zero(input.to_type()));
+
+        match *O::OPCODE {
+            "div" | "div.w" | "rem" | "rem.w" | "mod" if is_zero(1) => Some(EvaluationError::DivisionByZero(message)),
+            "inv" if is_zero(0) => Some(EvaluationError::DivisionByZero(message)),
+            "abs" | "neg" | "add" | "sub" | "mul" | "div" | "rem" | "pow" | "shl" | "shr" => {
+                Some(EvaluationError::Overflow(message))
+            }
+            _ => None,
+        }
+    }
+
     /// Executes the instruction.
     #[inline]
     pub fn execute<A: circuit::Aleo<Network = N>>(
@@ -181,7 +232,7 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
 
         // Ensure the number of operands is within the bounds.
         if NUM_OPERANDS > N::MAX_OPERANDS {
-            N::halt(format!("The number of operands must be <= {}", N::MAX_OPERANDS))
+            return fail(string);
         }
 
         // Initialize a vector to store the operands.
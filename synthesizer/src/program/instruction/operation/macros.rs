@@ -310,6 +310,10 @@ macro_rules! output_type {
         // Compute the output type.
         match first {
             $(console::program::LiteralType::$input => console::program::LiteralType::$output,)+
+            // Note: 'address' and 'string' operands are rejected with a specific error, as they do not support arithmetic.
+            console::program::LiteralType::Address | console::program::LiteralType::String => {
+                bail!("Type '{first}' does not support '{}'", Self::OPCODE)
+            }
             _ => bail!("Invalid operand types for the '{}' instruction", Self::OPCODE),
         }
     }};
@@ -320,6 +324,11 @@ macro_rules! output_type {
         // Compute the output type.
         match (first, second) {
             $((console::program::LiteralType::$input_a, console::program::LiteralType::$input_b) => console::program::LiteralType::$output,)+
+            // Note: 'address' and 'string' operands are rejected with a specific error, as they do not support arithmetic.
+            (literal_type @ (console::program::LiteralType::Address | console::program::LiteralType::String), _)
+            | (_, literal_type @ (console::program::LiteralType::Address | console::program::LiteralType::String)) => {
+                bail!("Type '{literal_type}' does not support '{}'", Self::OPCODE)
+            }
             _ => bail!("Invalid operand types for the '{}' instruction", Self::OPCODE),
         }
     }};
@@ -35,6 +35,9 @@ pub use is::*;
 mod literals;
 pub use literals::*;
 
+mod return_;
+pub use return_::*;
+
 mod macros;
 
 use crate::Opcode;
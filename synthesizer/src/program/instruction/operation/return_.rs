@@ -0,0 +1,349 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Opcode, Operand, Registers, Stack};
+use console::{
+    network::prelude::*,
+    program::{Register, RegisterType},
+};
+
+/// Returns the registers as the outputs of the function, and stops its execution.
+/// i.e. `return r0 r1;`
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Return<N: Network> {
+    /// The operands, each of which is a register.
+    operands: Vec<Operand<N>>,
+}
+
+impl<N: Network> Return<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Return
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the registers that are returned, in order.
+    #[inline]
+    pub fn registers(&self) -> Vec<Register<N>> {
+        self.operands
+            .iter()
+            .filter_map(|operand| match operand {
+                Operand::Register(register) => Some(register.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![]
+    }
+
+    /// Returns the operation, with each register in its operands replaced by the given function.
+    #[inline]
+    pub(crate) fn rename_registers<F>(&self, rename: &mut F) -> Result<Self>
+    where
+        F: FnMut(&Register<N>) -> Result<Operand<N>>,
+    {
+        // Ensure each returned operand remains a register.
+        let operands = self
+            .operands
+            .iter()
+            .map(|operand| match operand {
+                Operand::Register(register) => Ok(Operand::Register(Operand::rename_register(register, rename)?)),
+                _ => operand.rename_registers(rename),
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { operands })
+    }
+}
+
+impl<N: Network> Return<N> {
+    /// Evaluates the instruction.
+    /// Note: The outputs are loaded by the stack, which stops evaluating the function at this instruction.
+    #[inline]
+    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        // Ensure each returned register is assigned.
+        self.operands.iter().try_for_each(|operand| registers.load(stack, operand).map(|_| ()))
+    }
+
+    /// Executes the instruction.
+    /// Note: The outputs are loaded by the stack, which stops executing the function at this instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        // Ensure each returned register is assigned.
+        self.operands.iter().try_for_each(|operand| registers.load_circuit(stack, operand).map(|_| ()))
+    }
+
+    /// Returns the output type from the given program and input types.
+    /// Note: The returned types are checked against the declared outputs of the function by the stack.
+    #[inline]
+    pub fn output_types(&self, _stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != self.operands.len() {
+            bail!(
+                "Instruction '{}' expects {} inputs, found {} inputs",
+                Self::opcode(),
+                self.operands.len(),
+                input_types.len()
+            )
+        }
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() > N::MAX_OUTPUTS {
+            bail!("Instruction '{}' returns more than {} outputs", Self::opcode(), N::MAX_OUTPUTS)
+        }
+        Ok(vec![])
+    }
+}
+
+impl<N: Network> Parser for Return<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<'_, Self> {
+        /// Parses a register from the string.
+        fn parse_register<N: Network>(string: &str) -> ParserResult<'_, Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the register from the string.
+            Register::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the registers from the string.
+        let (string, registers) = map_res(many0(complete(parse_register)), |registers: Vec<Register<N>>| {
+            // Ensure the number of registers is within the bounds.
+            match registers.len() <= N::MAX_OUTPUTS {
+                true => Ok(registers),
+                false => Err(error("Failed to parse 'return' opcode: too many registers")),
+            }
+        })(string)?;
+
+        Ok((string, Self { operands: registers.into_iter().map(Operand::Register).collect() }))
+    }
+}
+
+impl<N: Network> FromStr for Return<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Return<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Return<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() > N::MAX_OUTPUTS {
+            eprintln!("The number of operands must be <= {}", N::MAX_OUTPUTS);
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{}", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))
+    }
+}
+
+impl<N: Network> FromBytes for Return<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of operands is within the bounds.
+        if num_operands > N::MAX_OUTPUTS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_OUTPUTS)));
+        }
+
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(num_operands);
+        // Read the operands, each of which must be a register.
+        for _ in 0..num_operands {
+            match Operand::read_le(&mut reader)? {
+                operand @ Operand::Register(..) => operands.push(operand),
+                operand => return Err(error(format!("Expected a register to return, found '{operand}'"))),
+            }
+        }
+
+        // Return the operation.
+        Ok(Self { operands })
+    }
+}
+
+impl<N: Network> ToBytes for Return<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() > N::MAX_OUTPUTS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_OUTPUTS)));
+        }
+        // Write the number of operands.
+        (self.operands.len() as u8).write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Process, Program};
+    use circuit::AleoV0;
+    use console::{
+        network::Testnet3,
+        program::{Identifier, Value},
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let (string, ret) = Return::<CurrentNetwork>::parse("return r0 r1.owner").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(ret.registers(), vec![Register::from_str("r0")?, Register::from_str("r1.owner")?]);
+        assert_eq!("return r0 r1.owner", ret.to_string());
+
+        // Ensure a return without registers is parsed.
+        assert!(Return::<CurrentNetwork>::from_str("return")?.operands().is_empty());
+        // Ensure a literal cannot be returned.
+        assert!(Return::<CurrentNetwork>::from_str("return 1u8").is_err());
+        // Ensure too many registers cannot be returned.
+        assert!(Return::<CurrentNetwork>::from_str("return r0 r1 r2 r3 r4 r5 r6 r7 r8").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let expected = Return::<CurrentNetwork>::from_str("return r0 r1.owner")?;
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Return::read_le(&expected_bytes[..])?);
+        assert!(Return::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluate_return() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program early_exit.aleo;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    return r2 r0;
+    mul r0 r1 into r3;
+    output r3 as field.private;
+    output r1 as field.private;",
+        )?;
+        let compute = Identifier::from_str("compute")?;
+
+        // Ensure the 'return' produces the outputs, and the subsequent instructions are not evaluated.
+        let inputs = [Value::from_str("3field")?, Value::from_str("5field")?];
+        let rng = &mut TestRng::default();
+        let outputs = program.evaluate::<CurrentAleo, _>(&program.to_process()?, &compute, &inputs, rng)?;
+        assert_eq!(outputs, [Value::from_str("8field")?, Value::from_str("3field")?]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_return_type_check() -> Result<()> {
+        // Adds the given program to a new process.
+        let add_program = |program: &str| -> Result<()> {
+            let mut process = Process::<CurrentNetwork>::load_without_keys()?;
+            process.add_program(&Program::from_str(program)?)
+        };
+
+        // Ensure the returned registers must match the declared outputs in number.
+        let result = add_program(
+            r"
+program early_exit.aleo;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    return r0 r1;
+    output r1 as field.private;",
+        );
+        assert!(result.unwrap_err().to_string().contains("returns 2 registers, but 1 outputs are declared"));
+
+        // Ensure the returned registers must match the declared outputs in type.
+        let result = add_program(
+            r"
+program early_exit.aleo;
+
+function compute:
+    input r0 as field.private;
+    input r1 as u8.private;
+    add r0 r0 into r2;
+    return r1;
+    output r2 as field.private;",
+        );
+        assert!(result.unwrap_err().to_string().contains("returns 'r1' of type 'u8'"));
+
+        // Ensure a closure may not return early.
+        let result = add_program(
+            r"
+program early_exit.aleo;
+
+closure helper:
+    input r0 as field;
+    add r0 r0 into r1;
+    return r1;
+    output r1 as field;
+
+function compute:
+    input r0 as field.private;
+    call helper r0 into r1;
+    output r1 as field.private;",
+        );
+        assert!(result.unwrap_err().to_string().contains("A 'return' instruction is not allowed in closures"));
+        Ok(())
+    }
+}
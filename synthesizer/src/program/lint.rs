@@ -0,0 +1,457 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::program::Register;
+
+/// A lint rule, which checks a program for a pattern that is legal but likely unintended.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LintRule {
+    /// A destination register that is never read by a later instruction or output.
+    DeadRegister,
+    /// A function output that is one of the function's input registers.
+    OutputIsInput,
+    /// A struct, record, mapping, closure, or global that no function depends on.
+    UnusedDefinition,
+    /// A literal operand, other than zero or one, that could be named as a global constant.
+    MagicNumber,
+    /// A function with no outputs and no finalize scope, which has no observable effect.
+    NoOutputs,
+}
+
+impl LintRule {
+    /// The list of all lint rules.
+    pub const ALL: [LintRule; 5] =
+        [Self::DeadRegister, Self::OutputIsInput, Self::UnusedDefinition, Self::MagicNumber, Self::NoOutputs];
+
+    /// Returns the ID of the lint rule.
+    pub const fn id(&self) -> &'static str {
+        match self {
+            Self::DeadRegister => "dead-register",
+            Self::OutputIsInput => "output-is-input",
+            Self::UnusedDefinition => "unused-definition",
+            Self::MagicNumber => "magic-number",
+            Self::NoOutputs => "no-outputs",
+        }
+    }
+
+    /// Returns the severity of the lint rule.
+    pub const fn severity(&self) -> LintSeverity {
+        match self {
+            Self::DeadRegister | Self::OutputIsInput | Self::NoOutputs => LintSeverity::Warning,
+            Self::UnusedDefinition | Self::MagicNumber => LintSeverity::Info,
+        }
+    }
+}
+
+/// The severity of a lint finding.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintSeverity {
+    /// A stylistic suggestion.
+    Info,
+    /// A likely mistake.
+    Warning,
+}
+
+/// The set of lint rules that are enabled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintRules {
+    /// The enabled rules.
+    rules: IndexSet<LintRule>,
+}
+
+impl LintRules {
+    /// Returns a rule set with every rule enabled.
+    pub fn all() -> Self {
+        Self { rules: LintRule::ALL.into_iter().collect() }
+    }
+
+    /// Returns a rule set with no rules enabled.
+    pub fn none() -> Self {
+        Self { rules: IndexSet::new() }
+    }
+
+    /// Returns the rule set, with the given rule enabled.
+    pub fn with(mut self, rule: LintRule) -> Self {
+        self.rules.insert(rule);
+        self
+    }
+
+    /// Returns the rule set, with the given rule disabled.
+    pub fn without(mut self, rule: LintRule) -> Self {
+        self.rules.shift_remove(&rule);
+        self
+    }
+
+    /// Returns `true` if the given rule is enabled.
+    pub fn is_enabled(&self, rule: LintRule) -> bool {
+        self.rules.contains(&rule)
+    }
+}
+
+impl Default for LintRules {
+    /// Returns a rule set with every rule enabled.
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// A finding of a lint rule in a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintFinding<N: Network> {
+    /// The rule that produced the finding.
+    rule: LintRule,
+    /// The name of the definition in which the finding occurs.
+    name: Identifier<N>,
+    /// The index of the instruction in which the finding occurs, if any.
+    index: Option<usize>,
+    /// The message describing the finding.
+    message: String,
+}
+
+impl<N: Network> LintFinding<N> {
+    /// Returns the rule that produced the finding.
+    pub const fn rule(&self) -> LintRule {
+        self.rule
+    }
+
+    /// Returns the severity of the finding.
+    pub const fn severity(&self) -> LintSeverity {
+        self.rule.severity()
+    }
+
+    /// Returns the location of the finding, as the name of the definition and the index of the instruction, if any.
+    /// Note: The source span of an instruction in a function is given by `Program::instruction_span`.
+    pub const fn location(&self) -> (&Identifier<N>, Option<usize>) {
+        (&self.name, self.index)
+    }
+
+    /// Returns the message describing the finding.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl<N: Network> Display for LintFinding<N> {
+    /// Prints the finding as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let severity = match self.severity() {
+            LintSeverity::Info => "info",
+            LintSeverity::Warning => "warning",
+        };
+        match self.index {
+            Some(index) => {
+                write!(f, "{severity}[{}] '{}' (instruction {index}): {}", self.rule.id(), self.name, self.message)
+            }
+            None => write!(f, "{severity}[{}] '{}': {}", self.rule.id(), self.name, self.message),
+        }
+    }
+}
+
+impl<N: Network> Program<N> {
+    /// Checks the program against the given lint rules, and returns the findings,
+    /// grouped by rule in the order of `LintRule::ALL`, and in order of declaration within each rule.
+    pub fn lint(&self, rules: &LintRules) -> Vec<LintFinding<N>> {
+        let mut findings = Vec::new();
+        for rule in LintRule::ALL.into_iter().filter(|rule| rules.is_enabled(*rule)) {
+            match rule {
+                LintRule::DeadRegister => self.lint_dead_registers(&mut findings),
+                LintRule::OutputIsInput => self.lint_output_is_input(&mut findings),
+                LintRule::UnusedDefinition => self.lint_unused_definitions(&mut findings),
+                LintRule::MagicNumber => self.lint_magic_numbers(&mut findings),
+                LintRule::NoOutputs => self.lint_no_outputs(&mut findings),
+            }
+        }
+        findings
+    }
+
+    /// Adds a finding for each destination register that is never read, in the closures and functions.
+    fn lint_dead_registers(&self, findings: &mut Vec<LintFinding<N>>) {
+        // A helper to collect the dead registers, given the instructions and the registers read after them.
+        let mut check = |name: &Identifier<N>, instructions: &[Instruction<N>], outputs: Vec<&Register<N>>| {
+            // Collect the locators that are read by an instruction or an output.
+            let read = instructions
+                .iter()
+                .flat_map(|instruction| instruction.operands())
+                .filter_map(|operand| match operand {
+                    Operand::Register(register) => Some(register.locator()),
+                    _ => None,
+                })
+                .chain(outputs.into_iter().map(|register| register.locator()))
+                .collect::<IndexSet<_>>();
+            // Add a finding for each destination that is never read.
+            for (index, instruction) in instructions.iter().enumerate() {
+                for destination in instruction.destinations() {
+                    if !read.contains(&destination.locator()) {
+                        findings.push(LintFinding {
+                            rule: LintRule::DeadRegister,
+                            name: *name,
+                            index: Some(index),
+                            message: format!("Register '{destination}' is assigned but never read"),
+                        });
+                    }
+                }
+            }
+        };
+
+        for (name, definition) in self.identifiers.iter() {
+            match definition {
+                ProgramDefinition::Closure => {
+                    if let Some(closure) = self.closures.get(name) {
+                        let outputs = closure.outputs().iter().map(|output| output.register()).collect();
+                        check(name, closure.instructions(), outputs);
+                    }
+                }
+                ProgramDefinition::Function => {
+                    if let Some(function) = self.functions.get(name) {
+                        // Note: The operands of the finalize command are read by the finalize scope.
+                        let mut outputs =
+                            function.outputs().iter().filter_map(|output| output.register()).collect::<Vec<_>>();
+                        if let Some(command) = function.finalize_command() {
+                            outputs.extend(command.operands().iter().filter_map(|operand| match operand {
+                                Operand::Register(register) => Some(register),
+                                _ => None,
+                            }));
+                        }
+                        check(name, function.instructions(), outputs);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Adds a finding for each function output that is one of the function's input registers.
+    fn lint_output_is_input(&self, findings: &mut Vec<LintFinding<N>>) {
+        for function in self.functions.values() {
+            for register in function.outputs().iter().filter_map(|output| output.register()) {
+                if function.inputs().iter().any(|input| input.register() == register) {
+                    findings.push(LintFinding {
+                        rule: LintRule::OutputIsInput,
+                        name: *function.name(),
+                        index: None,
+                        message: format!("Output register '{register}' is also an input"),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Adds a finding for each struct, record, mapping, closure, or global that no function depends on.
+    fn lint_unused_definitions(&self, findings: &mut Vec<LintFinding<N>>) {
+        // Compute the transitive dependencies of the functions.
+        let mut used = IndexSet::new();
+        let mut queue = self.functions.keys().copied().collect::<Vec<_>>();
+        while let Some(name) = queue.pop() {
+            if let Some(definition) = self.identifiers.get(&name) {
+                if used.insert(name) {
+                    queue.extend(self.referenced_names(&name, definition));
+                }
+            }
+        }
+
+        // Add a finding for each definition that is not used.
+        for (name, definition) in self.identifiers.iter().filter(|(name, _)| !used.contains(*name)) {
+            let kind = match definition {
+                ProgramDefinition::Mapping => "Mapping",
+                ProgramDefinition::Struct => "Struct",
+                ProgramDefinition::Record => "Record",
+                ProgramDefinition::Closure => "Closure",
+                ProgramDefinition::Global => "Global",
+                ProgramDefinition::Function => continue,
+            };
+            findings.push(LintFinding {
+                rule: LintRule::UnusedDefinition,
+                name: *name,
+                index: None,
+                message: format!("{kind} '{name}' is not used by any function"),
+            });
+        }
+    }
+
+    /// Adds a finding for each literal operand, other than zero or one, in the closures and functions.
+    fn lint_magic_numbers(&self, findings: &mut Vec<LintFinding<N>>) {
+        for (name, index, literal) in self.literal_operands() {
+            // Skip booleans, addresses, and strings, which are not numbers.
+            let literal_type = literal.to_type();
+            if matches!(literal_type, LiteralType::Boolean | LiteralType::Address | LiteralType::String) {
+                continue;
+            }
+            // Skip zero and one.
+            if literal == Literal::zero(literal_type) || literal.to_string() == format!("1{literal_type}") {
+                continue;
+            }
+            findings.push(LintFinding {
+                rule: LintRule::MagicNumber,
+                name,
+                index: Some(index),
+                message: format!("Literal '{literal}' could be declared as a global constant"),
+            });
+        }
+    }
+
+    /// Adds a finding for each function with no outputs and no finalize scope.
+    fn lint_no_outputs(&self, findings: &mut Vec<LintFinding<N>>) {
+        for function in self.functions.values() {
+            if function.outputs().is_empty() && function.finalize().is_none() {
+                findings.push(LintFinding {
+                    rule: LintRule::NoOutputs,
+                    name: *function.name(),
+                    index: None,
+                    message: "Function has no outputs and no finalize scope".to_string(),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the findings of the given rule on the given program, with only that rule enabled,
+    /// and ensures the rule is silent when disabled.
+    fn lint_only(program: &Program<CurrentNetwork>, rule: LintRule) -> Vec<LintFinding<CurrentNetwork>> {
+        // Ensure the rule is silent when disabled.
+        assert!(program.lint(&LintRules::all().without(rule)).iter().all(|finding| finding.rule() != rule));
+        assert!(program.lint(&LintRules::none()).is_empty());
+        // Lint the program with only the rule enabled.
+        let findings = program.lint(&LintRules::none().with(rule));
+        assert!(findings.iter().all(|finding| finding.rule() == rule && finding.severity() == rule.severity()));
+        findings
+    }
+
+    #[test]
+    fn test_lint_dead_register() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program lint.aleo;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    mul r0 r0 into r2;
+    output r2 as field.private;",
+        )?;
+
+        let findings = lint_only(&program, LintRule::DeadRegister);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location(), (&Identifier::from_str("compute")?, Some(0)));
+        assert_eq!(findings[0].message(), "Register 'r1' is assigned but never read");
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_output_is_input() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program lint.aleo;
+
+function echo:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r0 as field.private;
+    output r1 as field.private;",
+        )?;
+
+        let findings = lint_only(&program, LintRule::OutputIsInput);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location(), (&Identifier::from_str("echo")?, None));
+        assert_eq!(findings[0].message(), "Output register 'r0' is also an input");
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_unused_definition() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program lint.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+struct unused:
+    x as field;
+
+closure helper:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as point.private;
+    add r0.x r0.y into r1;
+    output r1 as field.private;",
+        )?;
+
+        let findings = lint_only(&program, LintRule::UnusedDefinition);
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].message(), "Struct 'unused' is not used by any function");
+        assert_eq!(findings[1].message(), "Closure 'helper' is not used by any function");
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_magic_number() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program lint.aleo;
+
+function compute:
+    input r0 as u64.private;
+    add r0 0u64 into r1;
+    mul r1 1u64 into r2;
+    mul r2 42u64 into r3;
+    output r3 as u64.private;",
+        )?;
+
+        let findings = lint_only(&program, LintRule::MagicNumber);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location(), (&Identifier::from_str("compute")?, Some(2)));
+        assert_eq!(findings[0].message(), "Literal '42u64' could be declared as a global constant");
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_no_outputs() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program lint.aleo;
+
+function check:
+    input r0 as field.private;
+    assert.eq r0 r0;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        )?;
+
+        let findings = lint_only(&program, LintRule::NoOutputs);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location(), (&Identifier::from_str("check")?, None));
+        assert_eq!(
+            findings[0].to_string(),
+            "warning[no-outputs] 'check': Function has no outputs and no finalize scope"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Process;
+
+impl<N: Network> Program<N> {
+    /// Returns an estimate of the peak register memory, in bytes, used to evaluate the function with the given name.
+    ///
+    /// As registers are not freed during evaluation, the peak is the sum of the sizes of the input and
+    /// destination registers, where each register is sized by the maximum number of bits of its type.
+    /// A record is sized by its owner, gates, entries, and nonce.
+    ///
+    /// The register types are retrieved from the given process, which must contain this program.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if the process does not contain this program.
+    pub fn estimate_memory(&self, process: &Process<N>, function_name: &Identifier<N>) -> Result<usize> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+
+        // Retrieve the stack of the program, and the register types of the function.
+        let stack = self.get_stack_in(process)?;
+        let register_types = stack.get_register_types(function_name)?;
+
+        // Determine the size of the nonce of a record.
+        let nonce_size_in_bits = LiteralType::Group.size_in_bits::<N>() as usize;
+
+        // Sum the sizes of the input and destination registers.
+        let size_in_bits = function.register_order().iter().try_fold(0usize, |size_in_bits, register| {
+            let register_size_in_bits = match register_types.get_type(stack, register)? {
+                RegisterType::Plaintext(plaintext_type) => self.plaintext_size_in_bits(&plaintext_type)?,
+                RegisterType::Record(record_name) => {
+                    self.record_size_in_bits(&self.get_record(&record_name)?)?.saturating_add(nonce_size_in_bits)
+                }
+                RegisterType::ExternalRecord(locator) => {
+                    let program = stack.get_external_program(locator.program_id())?;
+                    let record = stack.get_external_record(&locator)?;
+                    program.record_size_in_bits(&record)?.saturating_add(nonce_size_in_bits)
+                }
+            };
+            Ok::<_, Error>(size_in_bits.saturating_add(register_size_in_bits))
+        })?;
+        Ok(size_in_bits.saturating_add(7) / 8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_estimate_memory() -> Result<()> {
+        // Ensure the estimate is the size of each register.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program memory.aleo;
+
+function sum:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+        let field_size_in_bits = LiteralType::Field.size_in_bits::<CurrentNetwork>() as usize;
+        let process = program.to_process()?;
+        assert_eq!(program.estimate_memory(&process, &Identifier::from_str("sum")?)?, (3 * field_size_in_bits + 7) / 8);
+
+        // Initialize programs that differ only in the size of the record held by the function.
+        let program_with = |entries: &str| {
+            Program::<CurrentNetwork>::from_str(&format!(
+                r"
+program memory.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    amount as u64.private;{entries}
+
+function spend:
+    input r0 as token.record;
+    add r0.amount 1u64 into r1;
+    output r1 as u64.private;"
+            ))
+        };
+        let spend = Identifier::from_str("spend")?;
+        let estimate = |program: Program<CurrentNetwork>| program.estimate_memory(&program.to_process()?, &spend);
+        let small = estimate(program_with("")?)?;
+        let medium = estimate(program_with("\n    memo as field.private;")?)?;
+        let large = estimate(program_with("\n    memo as field.private;\n    extra as u128.public;")?)?;
+
+        // Ensure the estimate increases monotonically with the size of the record.
+        assert!(small < medium, "{small} < {medium}");
+        assert!(medium < large, "{medium} < {large}");
+
+        // Ensure an undefined function fails.
+        assert!(program.estimate_memory(&process, &Identifier::from_str("missing")?).is_err());
+        Ok(())
+    }
+}
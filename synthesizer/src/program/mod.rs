@@ -14,9 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod any;
+pub use any::*;
+
+mod checkpoint;
+pub use checkpoint::*;
+
 mod closure;
 pub use closure::*;
 
+mod confidentiality;
+pub use confidentiality::*;
+
 pub mod finalize;
 
 mod function;
@@ -28,19 +37,56 @@ pub use import::*;
 mod instruction;
 pub use instruction::*;
 
+mod lint;
+pub use lint::*;
+
 mod mapping;
 pub use mapping::*;
 
+mod partial;
+pub use partial::*;
+
+mod provider;
+pub use provider::*;
+
+mod validate;
+pub use validate::*;
+
 mod bytes;
+mod canonicalize;
+mod codegen;
+mod debug;
+mod edit;
+mod evaluate;
+mod memory;
 mod parse;
+mod rename;
 mod serialize;
 
+use crate::finalize::{Command, Decrement, FinalizeCommand, Increment};
 use console::{
     network::prelude::*,
-    program::{EntryType, Identifier, PlaintextType, ProgramID, RecordType, Struct},
+    program::{
+        EntryType,
+        FinalizeType,
+        Identifier,
+        Literal,
+        LiteralType,
+        Plaintext,
+        PlaintextType,
+        ProgramID,
+        Record,
+        RecordType,
+        Register,
+        RegisterType,
+        Struct,
+        Value,
+        ValueType,
+    },
+    types::{Field, U16},
 };
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 enum ProgramDefinition {
@@ -54,9 +100,20 @@ enum ProgramDefinition {
     Closure,
     /// A program function.
     Function,
+    /// A program global constant.
+    Global,
+}
+
+/// The resolved type of a struct member.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ResolvedType<N: Network> {
+    /// A literal type.
+    Literal(LiteralType),
+    /// A nested struct, resolved from its name in the program.
+    Struct(Struct<N>),
 }
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct Program<N: Network> {
     /// The ID of the program.
     id: ProgramID<N>,
@@ -74,12 +131,24 @@ pub struct Program<N: Network> {
     closures: IndexMap<Identifier<N>, Closure<N>>,
     /// A map of the declared functions for the program.
     functions: IndexMap<Identifier<N>, Function<N>>,
+    /// A map of the declared global constants for the program.
+    globals: IndexMap<Identifier<N>, (PlaintextType<N>, Plaintext<N>)>,
+    /// A map of function names to their declared attributes, which are source-only, and are not serialized to bytes.
+    function_attributes: IndexMap<Identifier<N>, Vec<Identifier<N>>>,
+    /// A map of function names to the source spans of their instructions, if parsed from a string.
+    instruction_spans: IndexMap<Identifier<N>, Vec<(usize, usize)>>,
 }
 
 impl<N: Network> Program<N> {
     /// Initializes an empty program.
     #[inline]
     pub fn new(id: ProgramID<N>) -> Result<Self> {
+        Self::with_capacity(id, 0, 0, 0)
+    }
+
+    /// Initializes an empty program, with space reserved for the given number of structs, records, and functions.
+    #[inline]
+    pub fn with_capacity(id: ProgramID<N>, structs: usize, records: usize, functions: usize) -> Result<Self> {
         // Ensure the program name is valid.
         ensure!(!Self::is_reserved_keyword(id.name()), "Program name is invalid: {}", id.name());
         // Ensure the program network-level domain is `aleo`.
@@ -88,15 +157,115 @@ impl<N: Network> Program<N> {
         Ok(Self {
             id,
             imports: IndexMap::new(),
-            identifiers: IndexMap::new(),
+            identifiers: IndexMap::with_capacity(structs + records + functions),
             mappings: IndexMap::new(),
-            structs: IndexMap::new(),
-            records: IndexMap::new(),
+            structs: IndexMap::with_capacity(structs),
+            records: IndexMap::with_capacity(records),
             closures: IndexMap::new(),
-            functions: IndexMap::new(),
+            functions: IndexMap::with_capacity(functions),
+            globals: IndexMap::new(),
+            function_attributes: IndexMap::new(),
+            instruction_spans: IndexMap::new(),
         })
     }
 
+    /// Initializes a program from the given structs, records, and functions, which may be supplied in any order.
+    /// Structs are added once all of the structs they reference have been added, followed by the records,
+    /// and then the functions, whose input and output types must reference defined structs and records.
+    ///
+    /// # Errors
+    /// This method will halt with every unresolved reference or invalid definition found, rather than the first.
+    pub fn from_parts(
+        id: ProgramID<N>,
+        structs: Vec<Struct<N>>,
+        records: Vec<RecordType<N>>,
+        functions: Vec<Function<N>>,
+    ) -> Result<Self> {
+        // Initialize the program.
+        let mut program = Self::with_capacity(id, structs.len(), records.len(), functions.len())?;
+        // Initialize a list of the errors found.
+        let mut errors = Vec::new();
+
+        // Add the structs, in an order where each struct is added after the structs in its members.
+        let mut pending = structs;
+        loop {
+            // Split the pending structs into those whose member structs are all defined, and the rest.
+            let (ready, blocked): (Vec<_>, Vec<_>) = pending.into_iter().partition(|struct_: &Struct<N>| {
+                struct_.members().values().all(|member_type| match member_type {
+                    PlaintextType::Literal(..) => true,
+                    PlaintextType::Struct(identifier) => program.structs.contains_key(identifier),
+                })
+            });
+            pending = blocked;
+            // If no struct can be added, then the remaining structs are unresolvable.
+            if ready.is_empty() {
+                break;
+            }
+            for struct_ in ready {
+                if let Err(error) = program.add_struct(struct_) {
+                    errors.push(error.to_string());
+                }
+            }
+        }
+        // Report the structs with undefined or cyclic member structs.
+        for struct_ in pending {
+            for member_type in struct_.members().values() {
+                if let PlaintextType::Struct(identifier) = member_type {
+                    if !program.structs.contains_key(identifier) {
+                        errors.push(format!("'{identifier}' in struct '{}' is not defined.", struct_.name()));
+                    }
+                }
+            }
+        }
+
+        // Add the records.
+        for record in records {
+            if let Err(error) = program.add_record(record) {
+                errors.push(error.to_string());
+            }
+        }
+
+        // Add the functions.
+        for function in functions {
+            // Ensure the input and output types of the function are defined.
+            let value_types = function
+                .inputs()
+                .iter()
+                .map(|input| input.value_type())
+                .chain(function.outputs().iter().map(|output| output.value_type()));
+            let mut is_resolved = true;
+            for value_type in value_types {
+                let (kind, identifier, is_defined) = match value_type {
+                    ValueType::Constant(PlaintextType::Struct(identifier))
+                    | ValueType::Public(PlaintextType::Struct(identifier))
+                    | ValueType::Private(PlaintextType::Struct(identifier)) => {
+                        ("Struct", identifier, program.structs.contains_key(identifier))
+                    }
+                    ValueType::Record(identifier) => ("Record", identifier, program.records.contains_key(identifier)),
+                    _ => continue,
+                };
+                if !is_defined {
+                    errors.push(format!("{kind} '{identifier}' in function '{}' is not defined.", function.name()));
+                    is_resolved = false;
+                }
+            }
+            if is_resolved {
+                if let Err(error) = program.add_function(function) {
+                    errors.push(error.to_string());
+                }
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(program),
+            false => bail!(
+                "Failed to construct program '{id}' from parts, found {} error(s):\n{}",
+                errors.len(),
+                errors.iter().map(|error| format!("  - {error}")).collect::<Vec<_>>().join("\n")
+            ),
+        }
+    }
+
     /// Initializes the credits program.
     #[inline]
     pub fn credits() -> Result<Self> {
@@ -175,6 +344,11 @@ function fee:
         &self.functions
     }
 
+    /// Returns the global constants in the program.
+    pub const fn globals(&self) -> &IndexMap<Identifier<N>, (PlaintextType<N>, Plaintext<N>)> {
+        &self.globals
+    }
+
     /// Returns `true` if the program contains an import with the given program ID.
     pub fn contains_import(&self, id: &ProgramID<N>) -> bool {
         self.imports.contains_key(id)
@@ -205,6 +379,11 @@ function fee:
         self.functions.contains_key(name)
     }
 
+    /// Returns `true` if the program contains a global constant with the given name.
+    pub fn contains_global(&self, name: &Identifier<N>) -> bool {
+        self.globals.contains_key(name)
+    }
+
     /// Returns the mapping with the given name.
     pub fn get_mapping(&self, name: &Identifier<N>) -> Result<Mapping<N>> {
         // Attempt to retrieve the mapping.
@@ -227,6 +406,107 @@ function fee:
         Ok(struct_)
     }
 
+    /// Returns the leaf members of the struct with the given name, as a list of dotted paths
+    /// (rooted at the struct name) and their literal types, recursing through nested structs.
+    ///
+    /// For example, a struct `message` with members `first` and `second` has the leaf paths
+    /// `message.first` and `message.second`.
+    pub fn struct_leaf_paths(&self, name: &Identifier<N>) -> Result<Vec<(String, LiteralType)>> {
+        /// Appends the leaf paths of the given struct under the given prefix.
+        fn append<N: Network>(
+            program: &Program<N>,
+            prefix: &str,
+            struct_: &Struct<N>,
+            paths: &mut Vec<(String, LiteralType)>,
+        ) -> Result<()> {
+            for (member_name, member_type) in struct_.members() {
+                // Compute the path of the member.
+                let path = format!("{prefix}.{member_name}");
+                match member_type {
+                    PlaintextType::Literal(literal_type) => paths.push((path, *literal_type)),
+                    PlaintextType::Struct(struct_name) => {
+                        append(program, &path, &program.get_struct(struct_name)?, paths)?
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        let mut paths = Vec::new();
+        append(self, &name.to_string(), &self.get_struct(name)?, &mut paths)?;
+        Ok(paths)
+    }
+
+    /// Returns the members of the struct with the given name, with each nested struct member
+    /// resolved to its definition in the program.
+    ///
+    /// # Errors
+    /// This method will halt if the struct, or a nested struct, is not defined in the program.
+    pub fn resolved_members(&self, name: &Identifier<N>) -> Result<Vec<(Identifier<N>, ResolvedType<N>)>> {
+        self.get_struct(name)?
+            .members()
+            .iter()
+            .map(|(member_name, member_type)| {
+                let resolved = match member_type {
+                    PlaintextType::Literal(literal_type) => ResolvedType::Literal(*literal_type),
+                    PlaintextType::Struct(struct_name) => ResolvedType::Struct(self.get_struct(struct_name)?),
+                };
+                Ok((*member_name, resolved))
+            })
+            .collect()
+    }
+
+    /// Returns the value type parsed from the given string, ensuring the struct or record it references
+    /// is defined in the program, or in the case of an external record, that its program is imported.
+    ///
+    /// # Errors
+    /// This method will halt if the string is not a value type.
+    /// This method will halt if the referenced struct, record, or import is not defined in the program.
+    pub fn resolve_value_type(&self, string: &str) -> Result<ValueType<N>> {
+        // Parse the value type.
+        let value_type = ValueType::from_str(string)?;
+        match &value_type {
+            ValueType::Constant(plaintext_type)
+            | ValueType::Public(plaintext_type)
+            | ValueType::Private(plaintext_type) => {
+                if let PlaintextType::Struct(struct_name) = plaintext_type {
+                    // Ensure a record is not used as a plaintext type.
+                    ensure!(
+                        !self.contains_record(struct_name),
+                        "'{struct_name}' is a record in '{}', and must be used as '{struct_name}.record'",
+                        self.id
+                    );
+                    // Ensure the struct is defined in the program.
+                    ensure!(
+                        self.contains_struct(struct_name),
+                        "Struct '{struct_name}' is not defined in '{}'",
+                        self.id
+                    );
+                }
+            }
+            ValueType::Record(record_name) => {
+                // Ensure a struct is not used as a record type.
+                ensure!(
+                    !self.contains_struct(record_name),
+                    "'{record_name}' is a struct in '{}', and cannot be used as a record",
+                    self.id
+                );
+                // Ensure the record is defined in the program.
+                ensure!(self.contains_record(record_name), "Record '{record_name}' is not defined in '{}'", self.id);
+            }
+            ValueType::ExternalRecord(locator) => {
+                // Ensure the program of the external record is imported.
+                ensure!(
+                    self.contains_import(locator.program_id()),
+                    "External record '{locator}' references '{}', which is not imported by '{}'",
+                    locator.program_id(),
+                    self.id
+                );
+            }
+        }
+        Ok(value_type)
+    }
+
     /// Returns the record with the given name.
     pub fn get_record(&self, name: &Identifier<N>) -> Result<RecordType<N>> {
         // Attempt to retrieve the record.
@@ -270,6 +550,404 @@ function fee:
         // Return the function.
         Ok(function)
     }
+
+    /// Returns the attributes declared on the function with the given name, i.e. the `@{name}` lines
+    /// preceding the function. Attributes do not affect evaluation, and are source-only,
+    /// i.e. they are not serialized to bytes, and do not affect the equality of programs.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    pub fn function_attributes(&self, name: &Identifier<N>) -> Result<&[Identifier<N>]> {
+        // Ensure the function exists.
+        ensure!(self.contains_function(name), "Function '{name}' is not defined.");
+        // Return the attributes of the function.
+        Ok(self.function_attributes.get(name).map(Vec::as_slice).unwrap_or_default())
+    }
+
+    /// Returns the source span of the instruction at the given index in the function with the given name,
+    /// as the start and end byte offsets into the string the program was parsed from.
+    ///
+    /// Returns `None` if the program was not parsed from a string, if the function does not exist,
+    /// if the function has been edited since parsing, or if the index is out of bounds.
+    pub fn instruction_span(&self, function_name: &Identifier<N>, index: usize) -> Option<(usize, usize)> {
+        self.instruction_spans.get(function_name)?.get(index).copied()
+    }
+
+    /// Returns the type and value of the global constant with the given name.
+    pub fn get_global(&self, name: &Identifier<N>) -> Result<(PlaintextType<N>, Plaintext<N>)> {
+        // Attempt to retrieve the global constant.
+        self.globals.get(name).cloned().ok_or_else(|| anyhow!("Global '{name}' is not defined."))
+    }
+
+    /// Returns the hash of the function with the given name, computed over its canonical (byte) form.
+    ///
+    /// The hash depends only on the function itself, and is the function hash used in the
+    /// transaction leaves of a deployment.
+    pub fn function_hash(&self, function_name: &Identifier<N>) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.get_function(function_name)?.to_bytes_le()?.to_bits_le())
+    }
+
+    /// Returns the number of outputs of the given function that are records.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    pub fn output_record_count(&self, function_name: &Identifier<N>) -> Result<usize> {
+        Ok(Self::num_output_records(&self.get_function(function_name)?))
+    }
+
+    /// Returns the record types of the outputs of the given function that are records, in output order.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if an output is an external record, as its record type is defined in another program.
+    pub fn output_record_types(&self, function_name: &Identifier<N>) -> Result<Vec<RecordType<N>>> {
+        self.get_function(function_name)?
+            .outputs()
+            .iter()
+            .filter_map(|output| match output.value_type() {
+                ValueType::Record(record_name) => Some(self.get_record(record_name)),
+                ValueType::ExternalRecord(locator) => {
+                    Some(Err(anyhow!("Cannot resolve the external record '{locator}' in '{}'", self.id)))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the commitment of the given record, as a record of the given name in the program.
+    /// The record entries are put in the canonical order of the record type before committing.
+    ///
+    /// # Errors
+    /// This method will halt if the record does not exist in the program.
+    /// This method will halt if the record entries do not match the entries declared in the record type.
+    pub fn record_commitment(&self, record_name: &Identifier<N>, record: &Record<N, Plaintext<N>>) -> Result<Field<N>> {
+        // Retrieve the record type.
+        let record_type = self.get_record(record_name)?;
+        // Compute the commitment of the canonical record.
+        record.to_canonical(&record_type)?.to_commitment(&self.id, record_name)
+    }
+
+    /// Returns the total number of leaf (literal) fields in the struct with the given name,
+    /// counting the fields of nested structs recursively.
+    ///
+    /// # Errors
+    /// This method will halt if the struct, or any nested struct, is not defined in the program.
+    pub fn total_field_count(&self, struct_name: &Identifier<N>) -> Result<usize> {
+        self.plaintext_field_count(&PlaintextType::Struct(*struct_name))
+    }
+
+    /// Returns the complexity of the function with the given name, computed as the sum of
+    /// `N::opcode_cost` over its instructions, including the instructions of any called closures.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if the function reaches a closure that calls itself, directly or indirectly.
+    pub fn complexity(&self, function_name: &Identifier<N>) -> Result<u64> {
+        // Ensure the function exists.
+        ensure!(self.contains_function(function_name), "Function '{function_name}' is not defined.");
+        // Compute the complexity, using the network opcode costs.
+        self.complexity_with(function_name, N::opcode_cost)
+    }
+
+    /// Returns the complexity of the closure or function with the given name, using the given opcode costs.
+    fn complexity_with(&self, name: &Identifier<N>, opcode_cost: fn(&'static str) -> u64) -> Result<u64> {
+        self.complexity_on_path(name, opcode_cost, &mut Vec::new())
+    }
+
+    /// Returns the complexity of the closure or function with the given name, using the given opcode costs,
+    /// where `path` is the chain of closures and functions whose calls reached it.
+    ///
+    /// # Errors
+    /// This method will halt if the closure or function calls itself, directly or indirectly.
+    fn complexity_on_path(
+        &self,
+        name: &Identifier<N>,
+        opcode_cost: fn(&'static str) -> u64,
+        path: &mut Vec<Identifier<N>>,
+    ) -> Result<u64> {
+        // Ensure the closure or function is not already on the call path.
+        ensure!(!path.contains(name), "Closure or function '{name}' calls itself, so its complexity is unbounded.");
+        // Retrieve the instructions of the closure or function.
+        let instructions = match (self.closures.get(name), self.functions.get(name)) {
+            (Some(closure), _) => closure.instructions(),
+            (None, Some(function)) => function.instructions(),
+            (None, None) => bail!("Closure or function '{name}' is not defined."),
+        };
+        path.push(*name);
+        let complexity = instructions.iter().try_fold(0u64, |complexity, instruction| {
+            // Compute the cost of the instruction.
+            let mut cost = opcode_cost(*instruction.opcode());
+            // If the instruction calls a local resource, add the complexity of the resource.
+            // Note: The complexity of an external call is accounted for by its own program.
+            if let Instruction::Call(call) = instruction {
+                if let CallOperator::Resource(resource) = call.operator() {
+                    cost = cost.saturating_add(self.complexity_on_path(resource, opcode_cost, path)?);
+                }
+            }
+            Ok::<_, Error>(complexity.saturating_add(cost))
+        })?;
+        path.pop();
+        Ok(complexity)
+    }
+
+    /// Ensures no two functions in the program share the same signature (input and output types).
+    ///
+    /// # Errors
+    /// This method will halt if two functions have identical signatures, naming both functions.
+    pub fn assert_unique_signatures(&self) -> Result<()> {
+        // Initialize a map from each signature to the first function with the signature.
+        let mut signatures = IndexMap::with_capacity(self.functions.len());
+        for (name, function) in &self.functions {
+            // Ensure the signature has not been seen before.
+            if let Some(existing) = signatures.insert(function.signature(), name) {
+                let (input_types, output_types) = function.signature();
+                let format =
+                    |types: Vec<ValueType<N>>| types.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+                bail!(
+                    "Functions '{existing}' and '{name}' share the signature ({}) -> ({})",
+                    format(input_types),
+                    format(output_types)
+                )
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the hash of the given inputs to the given function, computed as
+    /// `Hash(function ID || inputs)`, where the function ID is `Hash(network ID, program ID, function name)`.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if the number of inputs does not match the function.
+    /// This method will halt if an input is a record where a plaintext is expected, or vice versa.
+    pub fn function_inputs_hash(&self, function_name: &Identifier<N>, inputs: &[Value<N>]) -> Result<Field<N>> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+        // Ensure the number of inputs matches the function.
+        ensure!(
+            function.inputs().len() == inputs.len(),
+            "Function '{function_name}' expects {} inputs, found {} inputs",
+            function.inputs().len(),
+            inputs.len()
+        );
+
+        // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+        let function_id =
+            N::hash_bhp1024(&(U16::<N>::new(N::ID), self.id.name(), self.id.network(), function_name).to_bits_le())?;
+
+        // Construct the preimage as `(function ID || inputs)`.
+        let mut preimage = vec![function_id];
+        for (index, (input, input_type)) in inputs.iter().zip_eq(function.input_types()).enumerate() {
+            // Ensure the input is a record if and only if the input type is a record.
+            match (input, input_type) {
+                (Value::Plaintext(..), ValueType::Constant(..) | ValueType::Public(..) | ValueType::Private(..))
+                | (Value::Record(..), ValueType::Record(..) | ValueType::ExternalRecord(..)) => (),
+                _ => bail!("Input #{index} of function '{function_name}' expects a '{input_type}', found '{input}'"),
+            }
+            preimage.extend(input.to_fields()?);
+        }
+        // Hash the preimage to a field element.
+        N::hash_psd8(&preimage)
+    }
+
+    /// Returns the call graph of the program, as a map from each closure and function name
+    /// to the set of closures it directly calls, in order of declaration.
+    ///
+    /// Note: Only local calls are included; calls to external programs are not part of this graph.
+    pub fn call_graph(&self) -> IndexMap<Identifier<N>, IndexSet<Identifier<N>>> {
+        // A helper to collect the local resources called by the given instructions.
+        let callees = |instructions: &[Instruction<N>]| {
+            instructions
+                .iter()
+                .filter_map(|instruction| match instruction {
+                    Instruction::Call(call) => match call.operator() {
+                        CallOperator::Resource(resource) => Some(*resource),
+                        CallOperator::Locator(..) => None,
+                    },
+                    _ => None,
+                })
+                .collect::<IndexSet<_>>()
+        };
+
+        // Construct the call graph, in order of declaration.
+        self.identifiers
+            .iter()
+            .filter_map(|(name, definition)| match definition {
+                ProgramDefinition::Closure => {
+                    self.closures.get(name).map(|closure| (*name, callees(closure.instructions())))
+                }
+                ProgramDefinition::Function => {
+                    self.functions.get(name).map(|function| (*name, callees(function.instructions())))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the function with the given name is deterministic, meaning neither the function
+    /// nor any local closure it (transitively) calls contains a nondeterministic instruction.
+    ///
+    /// Note: Calls to external programs are not inspected, as their programs are not available here.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    pub fn function_is_deterministic(&self, function_name: &Identifier<N>) -> Result<bool> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+        if !function.is_deterministic() {
+            return Ok(false);
+        }
+
+        // Traverse the local closures reachable from the function.
+        let call_graph = self.call_graph();
+        let mut visited = IndexSet::new();
+        let mut stack = call_graph.get(function_name).into_iter().flatten().copied().collect::<Vec<_>>();
+        while let Some(name) = stack.pop() {
+            if !visited.insert(name) {
+                continue;
+            }
+            // Ensure the closure is deterministic.
+            if let Some(closure) = self.closures.get(&name) {
+                if !closure.instructions().iter().all(Instruction::is_deterministic) {
+                    return Ok(false);
+                }
+            }
+            stack.extend(call_graph.get(&name).into_iter().flatten().copied());
+        }
+        Ok(true)
+    }
+
+    /// Returns the literal operands of the instructions in the program, as a list of the owning
+    /// closure or function name, the index of the instruction, and the literal, in order of declaration.
+    pub fn literal_operands(&self) -> Vec<(Identifier<N>, usize, Literal<N>)> {
+        // A helper to collect the literal operands of the given instructions.
+        let literals = |name: &Identifier<N>, instructions: &[Instruction<N>]| {
+            instructions
+                .iter()
+                .enumerate()
+                .flat_map(|(index, instruction)| {
+                    instruction.operands().iter().filter_map(move |operand| match operand {
+                        Operand::Literal(literal) => Some((*name, index, literal.clone())),
+                        _ => None,
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Collect the literal operands, in order of declaration.
+        self.identifiers
+            .iter()
+            .flat_map(|(name, definition)| match definition {
+                ProgramDefinition::Closure => {
+                    self.closures.get(name).map(|closure| literals(name, closure.instructions())).unwrap_or_default()
+                }
+                ProgramDefinition::Function => {
+                    self.functions.get(name).map(|function| literals(name, function.instructions())).unwrap_or_default()
+                }
+                _ => Vec::new(),
+            })
+            .collect()
+    }
+
+    /// Returns the opcodes used by the closures and functions (including their finalize scopes)
+    /// in the program, in order of first appearance.
+    pub fn opcodes_used(&self) -> IndexSet<String> {
+        let mut opcodes = IndexSet::new();
+        for (name, definition) in self.identifiers.iter() {
+            match definition {
+                ProgramDefinition::Closure => {
+                    if let Some(closure) = self.closures.get(name) {
+                        opcodes
+                            .extend(closure.instructions().iter().map(|instruction| instruction.opcode().to_string()));
+                    }
+                }
+                ProgramDefinition::Function => {
+                    if let Some(function) = self.functions.get(name) {
+                        opcodes
+                            .extend(function.instructions().iter().map(|instruction| instruction.opcode().to_string()));
+                        if let Some(finalize) = function.finalize_logic() {
+                            opcodes.insert(FinalizeCommand::<N>::opcode().to_string());
+                            opcodes.extend(finalize.commands().iter().map(|command| match command {
+                                Command::Decrement(..) => Decrement::<N>::opcode().to_string(),
+                                Command::Increment(..) => Increment::<N>::opcode().to_string(),
+                                Command::Instruction(instruction) => instruction.opcode().to_string(),
+                            }));
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        opcodes
+    }
+
+    /// Returns the number of instructions of each opcode across the functions in the program,
+    /// in order of first appearance.
+    pub fn opcode_histogram(&self) -> IndexMap<String, usize> {
+        let mut histogram = IndexMap::new();
+        for instruction in self.functions.values().flat_map(|function| function.instructions()) {
+            *histogram.entry(instruction.opcode().to_string()).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// Returns a map from each function name to the set of struct, record, closure, mapping, and global names
+    /// it references that are not defined in the program, in order of declaration.
+    /// Functions without unresolved references are omitted.
+    ///
+    /// Note: References to external records are resolved through imports, and are not included.
+    pub fn unresolved_references(&self) -> IndexMap<Identifier<N>, IndexSet<Identifier<N>>> {
+        self.functions
+            .keys()
+            .filter_map(|function_name| {
+                // Retrieve the names referenced by the function.
+                let mut names = self.referenced_names(function_name, &ProgramDefinition::Function);
+                // Retain the names that are not defined in the program.
+                names.retain(|name| !self.identifiers.contains_key(name));
+                match names.is_empty() {
+                    true => None,
+                    false => Some((*function_name, names)),
+                }
+            })
+            .collect()
+    }
+
+    /// Retains the given functions, along with the mappings, structs, records, closures, and globals they
+    /// depend on (transitively), and removes all other definitions from the program.
+    ///
+    /// Note: The imports of the program are left unchanged.
+    ///
+    /// # Errors
+    /// This method will halt if any of the given names is not a function in the program.
+    pub fn retain_functions(&mut self, keep: &IndexSet<Identifier<N>>) -> Result<()> {
+        // Ensure each of the given names is a function.
+        for name in keep {
+            ensure!(self.contains_function(name), "Cannot retain '{name}', as it is not a function in the program");
+        }
+
+        // Compute the transitive dependencies of the given functions.
+        let mut retained = IndexSet::new();
+        let mut queue = keep.iter().copied().collect::<Vec<_>>();
+        while let Some(name) = queue.pop() {
+            if let Some(definition) = self.identifiers.get(&name) {
+                if retained.insert(name) {
+                    queue.extend(self.referenced_names(&name, definition));
+                }
+            }
+        }
+
+        // Remove all other definitions.
+        self.identifiers.retain(|name, _| retained.contains(name));
+        self.mappings.retain(|name, _| retained.contains(name));
+        self.structs.retain(|name, _| retained.contains(name));
+        self.records.retain(|name, _| retained.contains(name));
+        self.closures.retain(|name, _| retained.contains(name));
+        self.functions.retain(|name, _| retained.contains(name));
+        self.globals.retain(|name, _| retained.contains(name));
+        self.function_attributes.retain(|name, _| retained.contains(name));
+        self.instruction_spans.retain(|name, _| retained.contains(name));
+        Ok(())
+    }
 }
 
 impl<N: Network> Program<N> {
@@ -387,7 +1065,9 @@ impl<N: Network> Program<N> {
     /// This method will halt if the record was previously added.
     /// This method will halt if the record name is already in use in the program.
     /// This method will halt if the record name is a reserved opcode or keyword.
+    /// This method will halt if the record owner does not have the mode required by the network.
     /// This method will halt if any records in the record's members are not already defined.
+    /// This method will halt if the record payload exceeds the maximum data size.
     #[inline]
     fn add_record(&mut self, record: RecordType<N>) -> Result<()> {
         // For now, ensure only one record type exists in the program.
@@ -403,6 +1083,9 @@ impl<N: Network> Program<N> {
         // Ensure the record name is not a reserved keyword.
         ensure!(!Self::is_reserved_keyword(&record_name), "'{record_name}' is a reserved keyword.");
 
+        // Ensure the record owner is private, if the network requires it.
+        Self::check_record_owner(&record, N::REQUIRE_PRIVATE_OWNER)?;
+
         // Ensure all record entries are well-formed.
         // Note: This design ensures cyclic references are not possible.
         for (identifier, entry_type) in record.entries() {
@@ -424,6 +1107,9 @@ impl<N: Network> Program<N> {
             }
         }
 
+        // Ensure the record payload fits within the maximum data size.
+        self.check_record_size(&record, N::MAX_DATA_SIZE_IN_FIELDS)?;
+
         // Add the record name to the identifiers.
         if self.identifiers.insert(record_name, ProgramDefinition::Record).is_some() {
             bail!("'{record_name}' already exists in the program.")
@@ -448,6 +1134,7 @@ impl<N: Network> Program<N> {
     /// This method will halt if a destination register already exists in memory.
     /// This method will halt if an output register does not already exist.
     /// This method will halt if an output type references a non-existent definition.
+    /// This method will halt if a literal operand is not well-formed for its type.
     #[inline]
     fn add_closure(&mut self, closure: Closure<N>) -> Result<()> {
         // Retrieve the closure name.
@@ -468,6 +1155,8 @@ impl<N: Network> Program<N> {
         ensure!(!closure.instructions().is_empty(), "Cannot evaluate a closure without instructions");
         // Ensure the number of outputs is within the allowed range.
         ensure!(closure.outputs().len() <= N::MAX_OUTPUTS, "Closure exceeds maximum number of outputs");
+        // Ensure each literal operand is well-formed.
+        Self::check_literal_operands(&closure_name, closure.instructions())?;
 
         // Add the function name to the identifiers.
         if self.identifiers.insert(closure_name, ProgramDefinition::Closure).is_some() {
@@ -493,6 +1182,8 @@ impl<N: Network> Program<N> {
     /// This method will halt if a destination register already exists in memory.
     /// This method will halt if an output register does not already exist.
     /// This method will halt if an output type references a non-existent definition.
+    /// This method will halt if an instruction uses an opcode that is not allowed by the network.
+    /// This method will halt if a literal operand is not well-formed for its type.
     #[inline]
     fn add_function(&mut self, function: Function<N>) -> Result<()> {
         // Retrieve the function name.
@@ -508,12 +1199,8 @@ impl<N: Network> Program<N> {
         // Ensure the function name is not a reserved keyword.
         ensure!(!Self::is_reserved_keyword(&function_name), "'{function_name}' is a reserved keyword.");
 
-        // Ensure the number of inputs is within the allowed range.
-        ensure!(function.inputs().len() <= N::MAX_INPUTS, "Function exceeds maximum number of inputs");
-        // Ensure the number of instructions is within the allowed range.
-        ensure!(function.instructions().len() <= N::MAX_INSTRUCTIONS, "Function exceeds maximum instructions");
-        // Ensure the number of outputs is within the allowed range.
-        ensure!(function.outputs().len() <= N::MAX_OUTPUTS, "Function exceeds maximum number of outputs");
+        // Ensure the function is well-formed.
+        Self::check_function(&function, &NetworkLimits::of::<N>())?;
 
         // Add the function name to the identifiers.
         if self.identifiers.insert(function_name, ProgramDefinition::Function).is_some() {
@@ -525,6 +1212,76 @@ impl<N: Network> Program<N> {
         }
         Ok(())
     }
+
+    /// Adds the given attributes to the function with the given name.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if an attribute is declared more than once on the function.
+    #[inline]
+    fn add_function_attributes(&mut self, function_name: &Identifier<N>, attributes: Vec<Identifier<N>>) -> Result<()> {
+        // Ensure the function exists.
+        ensure!(self.contains_function(function_name), "Function '{function_name}' is not defined.");
+        // Ensure each attribute is declared once.
+        for (i, attribute) in attributes.iter().enumerate() {
+            ensure!(
+                !attributes[..i].contains(attribute),
+                "Attribute '@{attribute}' is declared more than once on function '{function_name}'"
+            );
+        }
+        // Add the attributes to the program.
+        if !attributes.is_empty() {
+            self.function_attributes.insert(*function_name, attributes);
+        }
+        Ok(())
+    }
+
+    /// Adds a new global constant to the program.
+    ///
+    /// # Errors
+    /// This method will halt if the global name is already in use in the program.
+    /// This method will halt if the global name is a reserved opcode or keyword.
+    /// This method will halt if the global name begins with a register, such as `r0`.
+    /// This method will halt if the global type references a struct that is not already defined.
+    /// This method will halt if the global value is not of the global type.
+    #[inline]
+    fn add_global(&mut self, name: Identifier<N>, plaintext_type: PlaintextType<N>, value: Plaintext<N>) -> Result<()> {
+        // Ensure the global name is new.
+        ensure!(self.is_unique_name(&name), "'{name}' is already in use.");
+        // Ensure the global name is not a reserved opcode.
+        ensure!(!Self::is_reserved_opcode(&name.to_string()), "'{name}' is a reserved opcode.");
+        // Ensure the global name is not a reserved keyword.
+        ensure!(!Self::is_reserved_keyword(&name), "'{name}' is a reserved keyword.");
+        // Ensure the global name does not begin with a register (e.g. `r0`),
+        // as the register would shadow it as an operand.
+        ensure!(Register::<N>::parse(&name.to_string()).is_err(), "'{name}' would be parsed as a register.");
+
+        // Ensure the global value is of the global type.
+        // Note: The members of a struct value are checked against the struct when the program is added to a stack.
+        match (&plaintext_type, &value) {
+            (PlaintextType::Literal(literal_type), Plaintext::Literal(literal, ..)) => ensure!(
+                literal.to_type() == *literal_type,
+                "Global '{name}' expects a '{literal_type}', but found '{value}'"
+            ),
+            (PlaintextType::Struct(struct_name), Plaintext::Struct(..)) => {
+                ensure!(
+                    self.structs.contains_key(struct_name),
+                    "Struct '{struct_name}' in global '{name}' is not defined."
+                )
+            }
+            _ => bail!("Global '{name}' expects a '{plaintext_type}', but found '{value}'"),
+        }
+
+        // Add the global name to the identifiers.
+        if self.identifiers.insert(name, ProgramDefinition::Global).is_some() {
+            bail!("'{name}' already exists in the program.")
+        }
+        // Add the global to the program.
+        if self.globals.insert(name, (plaintext_type, value)).is_some() {
+            bail!("'{name}' already exists in the program.")
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network> Program<N> {
@@ -602,52 +1359,449 @@ impl<N: Network> Program<N> {
         !self.identifiers.contains_key(name)
     }
 
-    /// Returns `true` if the given name is a reserved opcode.
-    pub fn is_reserved_opcode(name: &str) -> bool {
-        // Check if the given name matches any opcode (in its entirety; including past the first '.' if it exists).
-        Instruction::<N>::OPCODES.iter().any(|opcode| **opcode == name)
+    /// Returns the maximum number of bits in the payload of the given record type.
+    fn record_size_in_bits(&self, record: &RecordType<N>) -> Result<usize> {
+        // Account for the owner and gates of the record.
+        let size_in_bits =
+            LiteralType::Address.size_in_bits::<N>() as usize + LiteralType::U64.size_in_bits::<N>() as usize;
+        // Account for the entries of the record.
+        record.entries().values().try_fold(size_in_bits, |size_in_bits, entry_type| match entry_type {
+            EntryType::Constant(plaintext_type)
+            | EntryType::Public(plaintext_type)
+            | EntryType::Private(plaintext_type) => {
+                Ok(size_in_bits.saturating_add(self.plaintext_size_in_bits(plaintext_type)?))
+            }
+        })
     }
 
-    /// Returns `true` if the given name uses a reserved keyword.
-    pub fn is_reserved_keyword(name: &Identifier<N>) -> bool {
-        // Convert the given name to a string.
-        let name = name.to_string();
-        // Check if the name is a keyword.
-        Self::KEYWORDS.iter().any(|keyword| *keyword == name)
+    /// Checks that the owner of the given record type is private, if `required` is `true`.
+    ///
+    /// # Errors
+    /// This method will halt if `required` is `true`, and the record owner is not private.
+    fn check_record_owner(record: &RecordType<N>, required: bool) -> Result<()> {
+        if required {
+            ensure!(
+                record.owner().is_private(),
+                "Record '{}' declares its owner as 'address.{}', but the network requires 'address.private'",
+                record.name(),
+                record.owner()
+            );
+        }
+        Ok(())
     }
 
-    /// Returns `true` if the given program ID and function name corresponds to a coinbase function.
-    #[inline]
-    pub fn is_coinbase(program_id: &ProgramID<N>, function_name: &Identifier<N>) -> bool {
-        program_id.to_string() == "credits.aleo" && function_name.to_string() == "mint"
+    /// Checks that the payload of the given record type fits within `max_size_in_fields`.
+    ///
+    /// # Errors
+    /// This method will halt if the record payload exceeds the maximum data size.
+    fn check_record_size(&self, record: &RecordType<N>, max_size_in_fields: u32) -> Result<()> {
+        let record_size_in_bits = self.record_size_in_bits(record)?;
+        let max_size_in_bits = (max_size_in_fields as usize).saturating_mul(Field::<N>::SIZE_IN_DATA_BITS);
+        ensure!(
+            record_size_in_bits <= max_size_in_bits,
+            "Record '{}' is {record_size_in_bits} bits, which exceeds the maximum payload size of {max_size_in_bits} bits",
+            record.name()
+        );
+        Ok(())
     }
-}
 
-impl<N: Network> TypeName for Program<N> {
-    /// Returns the type name as a string.
-    #[inline]
-    fn type_name() -> &'static str {
-        "program"
+    /// Returns the maximum number of bits in the given plaintext type.
+    fn plaintext_size_in_bits(&self, plaintext_type: &PlaintextType<N>) -> Result<usize> {
+        match plaintext_type {
+            PlaintextType::Literal(literal_type) => Ok(literal_type.size_in_bits::<N>() as usize),
+            PlaintextType::Struct(struct_name) => {
+                // Retrieve the struct.
+                let struct_ =
+                    self.structs.get(struct_name).ok_or_else(|| anyhow!("Struct '{struct_name}' is not defined."))?;
+                // Sum the sizes of the struct members.
+                struct_.members().values().try_fold(0usize, |size_in_bits, member_type| {
+                    Ok(size_in_bits.saturating_add(self.plaintext_size_in_bits(member_type)?))
+                })
+            }
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{CallStack, Execution, Inclusion};
-    use circuit::network::AleoV0;
-    use console::{
-        account::{Address, PrivateKey},
-        network::Testnet3,
-        program::{Locator, Plaintext, Record, Value, ValueType},
-        types::Field,
-    };
+    /// Returns the number of leaf (literal) fields in the given plaintext type.
+    fn plaintext_field_count(&self, plaintext_type: &PlaintextType<N>) -> Result<usize> {
+        match plaintext_type {
+            PlaintextType::Literal(..) => Ok(1),
+            PlaintextType::Struct(struct_name) => {
+                // Retrieve the struct.
+                let struct_ =
+                    self.structs.get(struct_name).ok_or_else(|| anyhow!("Struct '{struct_name}' is not defined."))?;
+                // Sum the field counts of the struct members.
+                struct_.members().values().try_fold(0usize, |num_fields, member_type| {
+                    Ok(num_fields.saturating_add(self.plaintext_field_count(member_type)?))
+                })
+            }
+        }
+    }
 
-    use parking_lot::RwLock;
-    use std::sync::Arc;
+    /// Returns the names of the structs, records, mappings, closures, and globals referenced by the given definition,
+    /// in order of appearance. Note: The returned names are not guaranteed to be defined in the program.
+    fn referenced_names(&self, name: &Identifier<N>, definition: &ProgramDefinition) -> IndexSet<Identifier<N>> {
+        // A helper to retrieve the name referenced by a plaintext type.
+        let from_plaintext = |plaintext_type: &PlaintextType<N>| match plaintext_type {
+            PlaintextType::Literal(..) => None,
+            PlaintextType::Struct(struct_name) => Some(*struct_name),
+        };
+        // A helper to retrieve the name referenced by a register type.
+        let from_register = |register_type: &RegisterType<N>| match register_type {
+            RegisterType::Plaintext(plaintext_type) => from_plaintext(plaintext_type),
+            RegisterType::Record(record_name) => Some(*record_name),
+            RegisterType::ExternalRecord(..) => None,
+        };
+        // A helper to retrieve the name referenced by a finalize type.
+        let from_finalize = |finalize_type: &FinalizeType<N>| match finalize_type {
+            FinalizeType::Public(plaintext_type) => from_plaintext(plaintext_type),
+            FinalizeType::Record(record_name) => Some(*record_name),
+            FinalizeType::ExternalRecord(..) => None,
+        };
+        // A helper to retrieve the name referenced by an instruction.
+        let from_instruction = |instruction: &Instruction<N>| match instruction {
+            Instruction::Call(call) => match call.operator() {
+                CallOperator::Resource(resource) => Some(*resource),
+                CallOperator::Locator(..) => None,
+            },
+            Instruction::Cast(cast) => from_register(cast.register_type()),
+            _ => None,
+        };
+        // A helper to retrieve the name referenced by an operand.
+        let from_operand = |operand: &Operand<N>| match operand {
+            Operand::Global(global_name) => Some(*global_name),
+            _ => None,
+        };
 
-    type CurrentNetwork = Testnet3;
-    type CurrentAleo = AleoV0;
+        let mut names = IndexSet::new();
+        match definition {
+            ProgramDefinition::Mapping => {
+                if let Some(mapping) = self.mappings.get(name) {
+                    names.extend(from_finalize(mapping.key().finalize_type()));
+                    names.extend(from_finalize(mapping.value().finalize_type()));
+                }
+            }
+            ProgramDefinition::Struct => {
+                if let Some(struct_) = self.structs.get(name) {
+                    names.extend(struct_.members().values().filter_map(from_plaintext));
+                }
+            }
+            ProgramDefinition::Record => {
+                if let Some(record) = self.records.get(name) {
+                    names.extend(record.entries().values().filter_map(|entry_type| match entry_type {
+                        EntryType::Constant(plaintext_type)
+                        | EntryType::Public(plaintext_type)
+                        | EntryType::Private(plaintext_type) => from_plaintext(plaintext_type),
+                    }));
+                }
+            }
+            ProgramDefinition::Closure => {
+                if let Some(closure) = self.closures.get(name) {
+                    names.extend(closure.inputs().iter().filter_map(|input| from_register(input.register_type())));
+                    names.extend(closure.instructions().iter().filter_map(from_instruction));
+                    names
+                        .extend(closure.instructions().iter().flat_map(Instruction::operands).filter_map(from_operand));
+                    names.extend(closure.outputs().iter().filter_map(|output| from_register(output.register_type())));
+                }
+            }
+            ProgramDefinition::Function => {
+                if let Some(function) = self.functions.get(name) {
+                    names.extend(
+                        function.input_types().into_iter().filter_map(|value_type| from_register(&value_type.into())),
+                    );
+                    names.extend(function.instructions().iter().filter_map(from_instruction));
+                    names.extend(
+                        function.instructions().iter().flat_map(Instruction::operands).filter_map(from_operand),
+                    );
+                    names.extend(
+                        function.output_types().into_iter().filter_map(|value_type| from_register(&value_type.into())),
+                    );
+                    if let Some(finalize) = function.finalize_logic() {
+                        names.extend(finalize.input_types().iter().filter_map(from_finalize));
+                        names.extend(finalize.commands().iter().filter_map(|command| match command {
+                            Command::Decrement(decrement) => Some(*decrement.mapping_name()),
+                            Command::Increment(increment) => Some(*increment.mapping_name()),
+                            Command::Instruction(instruction) => from_instruction(instruction),
+                        }));
+                        names.extend(
+                            finalize
+                                .commands()
+                                .iter()
+                                .flat_map(|command| match command {
+                                    Command::Decrement(decrement) => decrement.operands(),
+                                    Command::Increment(increment) => increment.operands(),
+                                    Command::Instruction(instruction) => instruction.operands().to_vec(),
+                                })
+                                .filter_map(|operand| from_operand(&operand)),
+                        );
+                        names.extend(finalize.output_types().iter().filter_map(from_finalize));
+                    }
+                }
+            }
+            ProgramDefinition::Global => {
+                if let Some((plaintext_type, _)) = self.globals.get(name) {
+                    names.extend(from_plaintext(plaintext_type));
+                }
+            }
+        }
+        names
+    }
+
+    /// Checks that the given function is well-formed, and within the given limits of the network.
+    ///
+    /// # Errors
+    /// This method will halt if the function exceeds the maximum number of inputs, instructions, or outputs.
+    /// This method will halt if the function exceeds the maximum number of output records.
+    /// This method will halt if an instruction exceeds the maximum number of operands.
+    /// This method will halt if an output register is an input register, and the network is strict.
+    /// This method will halt if an instruction uses an opcode that is not allowed on the network.
+    /// This method will halt if the function has no outputs or side effects, and the network requires them.
+    /// This method will halt if a literal output does not match its declared type.
+    /// This method will halt if a literal operand is not well-formed for its type.
+    fn check_function(function: &Function<N>, limits: &NetworkLimits) -> Result<()> {
+        // Ensure the number of inputs is within the allowed range.
+        ensure!(function.inputs().len() <= limits.max_inputs, "Function exceeds maximum number of inputs");
+        // Ensure the number of instructions is within the allowed range.
+        ensure!(function.instructions().len() <= limits.max_instructions, "Function exceeds maximum instructions");
+        // Ensure the number of outputs is within the allowed range.
+        ensure!(function.outputs().len() <= limits.max_outputs, "Function exceeds maximum number of outputs");
+        // Ensure the number of record outputs is within the allowed range.
+        ensure!(
+            Self::num_output_records(function) <= limits.max_output_records,
+            "Function exceeds maximum number of output records"
+        );
+        // Ensure the number of operands of each instruction is within the allowed range.
+        Self::check_operand_counts(function, limits.max_operands)?;
+        // Ensure no output register is an input register, if the network requires it.
+        Self::check_output_not_input(function, limits.strict_output_registers)?;
+        // Ensure the function only uses opcodes that are allowed by the network.
+        Self::check_opcodes_allowed(function, limits.is_opcode_allowed)?;
+        // Ensure the function has an output or a side effect, if the network requires it.
+        Self::check_has_outputs(function, limits.require_outputs)?;
+        // Ensure each literal output matches its declared output type.
+        Self::check_output_literals(function)?;
+        // Ensure each literal operand, including in the finalize scope, is well-formed.
+        let finalize_instructions = function.finalize_logic().into_iter().flat_map(|finalize| {
+            finalize.commands().iter().filter_map(|command| match command {
+                Command::Instruction(instruction) => Some(instruction),
+                _ => None,
+            })
+        });
+        Self::check_literal_operands(function.name(), function.instructions().iter().chain(finalize_instructions))?;
+        Ok(())
+    }
+
+    /// Returns the number of outputs of the given function that are records.
+    fn num_output_records(function: &Function<N>) -> usize {
+        function
+            .outputs()
+            .iter()
+            .filter(|output| matches!(output.value_type(), ValueType::Record(..) | ValueType::ExternalRecord(..)))
+            .count()
+    }
+
+    /// Checks that each instruction in the given function has at most `max_operands` operands.
+    ///
+    /// Note: Casts are exempt, as they are bounded by the number of data entries of the cast type.
+    ///
+    /// # Errors
+    /// This method will halt if an instruction exceeds the maximum number of operands.
+    fn check_operand_counts(function: &Function<N>, max_operands: usize) -> Result<()> {
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            if !matches!(instruction, Instruction::Cast(..)) {
+                ensure!(
+                    instruction.operands().len() <= max_operands,
+                    "Instruction {index} ('{}') in function '{}' exceeds the maximum of {max_operands} operands",
+                    instruction.opcode(),
+                    function.name()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that each instruction in the given function, including its finalize scope, uses an allowed opcode.
+    ///
+    /// # Errors
+    /// This method will halt if an instruction uses an opcode that is not allowed.
+    fn check_opcodes_allowed(function: &Function<N>, is_allowed: impl Fn(&str) -> bool) -> Result<()> {
+        // Retrieve the instructions of the function and its finalize scope.
+        let finalize_instructions = function.finalize_logic().into_iter().flat_map(|finalize| {
+            finalize.commands().iter().filter_map(|command| match command {
+                Command::Instruction(instruction) => Some(instruction),
+                _ => None,
+            })
+        });
+        for instruction in function.instructions().iter().chain(finalize_instructions) {
+            let opcode = instruction.opcode();
+            ensure!(
+                is_allowed(&opcode),
+                "Opcode '{opcode}' in function '{}' is not allowed on this network",
+                function.name()
+            );
+        }
+        Ok(())
+    }
+
+    /// Checks that the given function has at least one output, if `required` is `true`.
+    /// A function without outputs is accepted if it has a side effect, i.e. an `assert` instruction or a finalize scope.
+    ///
+    /// # Errors
+    /// This method will halt if `required` is `true`, and the function has neither an output nor a side effect.
+    fn check_has_outputs(function: &Function<N>, required: bool) -> Result<()> {
+        if required && function.outputs().is_empty() {
+            // Determine whether the function has a side effect.
+            let has_assert = function
+                .instructions()
+                .iter()
+                .any(|instruction| matches!(instruction, Instruction::AssertEq(..) | Instruction::AssertNeq(..)));
+            ensure!(
+                has_assert || function.finalize().is_some(),
+                "Function '{}' has no outputs, and no 'assert' instruction or finalize scope",
+                function.name()
+            );
+        }
+        Ok(())
+    }
+
+    /// Checks that each literal output of the given function is declared as a plaintext literal of the same type.
+    ///
+    /// # Errors
+    /// This method will halt if a literal output is declared as a struct or record type.
+    /// This method will halt if a literal output does not match its declared literal type.
+    fn check_output_literals(function: &Function<N>) -> Result<()> {
+        for output in function.outputs() {
+            if let Some(literal) = output.literal() {
+                match output.value_type() {
+                    ValueType::Constant(PlaintextType::Literal(literal_type))
+                    | ValueType::Public(PlaintextType::Literal(literal_type))
+                    | ValueType::Private(PlaintextType::Literal(literal_type)) => ensure!(
+                        literal.to_type() == *literal_type,
+                        "Output '{literal}' in function '{}' does not match the declared type '{literal_type}'",
+                        function.name()
+                    ),
+                    value_type => bail!(
+                        "Output '{literal}' in function '{}' must be declared as a literal type, found '{value_type}'",
+                        function.name()
+                    ),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that each literal operand of the given instructions is well-formed for its type,
+    /// by ensuring it round-trips through its byte encoding (e.g. that a group element is on the curve).
+    ///
+    /// Note: A parsed or deserialized literal is always well-formed, so this only rejects a literal that
+    /// was constructed directly. Checking here means the registers do not re-check literals on every load.
+    ///
+    /// # Errors
+    /// This method will halt if a literal operand cannot be represented as a value of its type.
+    fn check_literal_operands<'a>(
+        name: &Identifier<N>,
+        instructions: impl IntoIterator<Item = &'a Instruction<N>>,
+    ) -> Result<()> {
+        for (index, instruction) in instructions.into_iter().enumerate() {
+            for operand in instruction.operands() {
+                if let Operand::Literal(literal) = operand {
+                    let is_well_formed = literal
+                        .to_bytes_le()
+                        .and_then(|bytes| Literal::<N>::from_bytes_le(&bytes))
+                        .map_or(false, |candidate| &candidate == literal);
+                    ensure!(
+                        is_well_formed,
+                        "Literal operand '{literal}' of instruction {index} in '{name}' is not a well-formed '{}'",
+                        literal.to_type()
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that no output of the given function is one of its input registers, if `strict` is `true`.
+    /// Otherwise, such an output is reported as a warning by the `output-is-input` lint.
+    ///
+    /// # Errors
+    /// This method will halt if `strict` is `true` and an output register is an input register.
+    fn check_output_not_input(function: &Function<N>, strict: bool) -> Result<()> {
+        if strict {
+            for register in function.outputs().iter().filter_map(|output| output.register()) {
+                ensure!(
+                    !function.inputs().iter().any(|input| input.register() == register),
+                    "Output register '{register}' in function '{}' is also an input",
+                    function.name()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the given name is a reserved opcode.
+    pub fn is_reserved_opcode(name: &str) -> bool {
+        // Check if the given name matches any opcode (in its entirety; including past the first '.' if it exists).
+        Instruction::<N>::OPCODES.iter().any(|opcode| **opcode == name)
+    }
+
+    /// Returns `true` if the given name uses a reserved keyword.
+    pub fn is_reserved_keyword(name: &Identifier<N>) -> bool {
+        // Convert the given name to a string.
+        let name = name.to_string();
+        // Check if the name is a keyword.
+        Self::KEYWORDS.iter().any(|keyword| *keyword == name)
+    }
+
+    /// Returns `true` if the given program ID and function name corresponds to a coinbase function.
+    #[inline]
+    pub fn is_coinbase(program_id: &ProgramID<N>, function_name: &Identifier<N>) -> bool {
+        program_id.to_string() == "credits.aleo" && function_name.to_string() == "mint"
+    }
+}
+
+impl<N: Network> PartialEq for Program<N> {
+    /// Returns `true` if the programs are equal.
+    /// Note: The function attributes and instruction spans are excluded, as they describe the source of the program,
+    /// not the program itself.
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.imports == other.imports
+            && self.identifiers == other.identifiers
+            && self.mappings == other.mappings
+            && self.structs == other.structs
+            && self.records == other.records
+            && self.closures == other.closures
+            && self.functions == other.functions
+            && self.globals == other.globals
+    }
+}
+
+impl<N: Network> Eq for Program<N> {}
+
+impl<N: Network> TypeName for Program<N> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "program"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CallStack, Execution, Inclusion};
+    use circuit::network::AleoV0;
+    use console::{
+        account::{Address, PrivateKey},
+        network::Testnet3,
+        program::{Locator, Register, Value, ValueType},
+        types::Field,
+    };
+
+    use parking_lot::RwLock;
+    use std::sync::Arc;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
 
     #[test]
     fn test_program_mapping() -> Result<()> {
@@ -695,6 +1849,227 @@ struct message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_struct_leaf_paths() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program leaves.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+struct message:
+    first as field;
+    second as point;
+    third as boolean;",
+        )?;
+
+        // Ensure the leaf paths of a flat struct are its members.
+        let candidate = program.struct_leaf_paths(&Identifier::from_str("point")?)?;
+        assert_eq!(candidate, vec![
+            ("point.x".to_string(), LiteralType::Field),
+            ("point.y".to_string(), LiteralType::Field),
+        ]);
+
+        // Ensure the leaf paths of a nested struct are enumerated in order.
+        let candidate = program.struct_leaf_paths(&Identifier::from_str("message")?)?;
+        assert_eq!(candidate, vec![
+            ("message.first".to_string(), LiteralType::Field),
+            ("message.second.x".to_string(), LiteralType::Field),
+            ("message.second.y".to_string(), LiteralType::Field),
+            ("message.third".to_string(), LiteralType::Boolean),
+        ]);
+
+        // Ensure an undefined struct fails.
+        assert!(program.struct_leaf_paths(&Identifier::from_str("missing")?).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_resolved_members() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program resolved.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+struct message:
+    first as field;
+    second as point;",
+        )?;
+
+        // Ensure the nested struct member is returned resolved.
+        let candidate = program.resolved_members(&Identifier::from_str("message")?)?;
+        assert_eq!(candidate, vec![
+            (Identifier::from_str("first")?, ResolvedType::Literal(LiteralType::Field)),
+            (
+                Identifier::from_str("second")?,
+                ResolvedType::Struct(program.get_struct(&Identifier::from_str("point")?)?)
+            ),
+        ]);
+
+        // Ensure an undefined struct fails.
+        assert!(program.resolved_members(&Identifier::from_str("missing")?).is_err());
+
+        Ok(())
+    }
+
+    /// Returns a new program, with the given function added to it.
+    fn sample_program_with_function(function: &str) -> Result<Program<CurrentNetwork>> {
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("checks.aleo")?)?;
+        program.add_function(Function::from_str(function)?)?;
+        Ok(program)
+    }
+
+    /// Checks the given function against the given network limits.
+    fn check_function_with(function: &str, limits: &NetworkLimits) -> Result<()> {
+        Program::<CurrentNetwork>::check_function(&Function::from_str(function)?, limits)
+    }
+
+    #[test]
+    fn test_program_check_opcodes_allowed() -> Result<()> {
+        // Initialize a function that uses `mul`.
+        let function = r"
+function scale:
+    input r0 as u64.private;
+    mul r0 r0 into r1;
+    output r1 as u64.private;";
+
+        // Ensure the default network allows every opcode.
+        assert!(Instruction::<CurrentNetwork>::OPCODES.iter().all(|opcode| CurrentNetwork::is_opcode_allowed(opcode)));
+        sample_program_with_function(function)?;
+
+        // Ensure a network that disallows `mul` rejects the function, naming the opcode.
+        let limits =
+            NetworkLimits { is_opcode_allowed: |opcode| opcode != "mul", ..NetworkLimits::of::<CurrentNetwork>() };
+        let error = check_function_with(function, &limits).unwrap_err();
+        assert_eq!(error.to_string(), "Opcode 'mul' in function 'scale' is not allowed on this network");
+
+        // Ensure an opcode in the finalize scope is also checked.
+        let function = r"
+function scale_public:
+    input r0 as u64.public;
+    finalize r0;
+
+finalize scale_public:
+    input r0 as u64.public;
+    mul r0 r0 into r1;";
+        sample_program_with_function(function)?;
+        let error = check_function_with(function, &limits).unwrap_err();
+        assert_eq!(error.to_string(), "Opcode 'mul' in function 'scale_public' is not allowed on this network");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_check_has_outputs() -> Result<()> {
+        // Initialize a function without outputs, asserts, or a finalize scope.
+        let function = r"
+function discard:
+    input r0 as field.private;
+    add r0 r0 into r1;";
+
+        // Ensure the default network accepts the function.
+        sample_program_with_function(function)?;
+        // Ensure a network that requires outputs rejects the function.
+        let limits = NetworkLimits { require_outputs: true, ..NetworkLimits::of::<CurrentNetwork>() };
+        let error = check_function_with(function, &limits).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Function 'discard' has no outputs, and no 'assert' instruction or finalize scope"
+        );
+
+        // Ensure a function without outputs, but with an assert, is accepted.
+        check_function_with(
+            r"
+function check:
+    input r0 as field.private;
+    input r1 as field.private;
+    assert.eq r0 r1;",
+            &limits,
+        )?;
+
+        // Ensure a function with outputs is accepted.
+        check_function_with(
+            r"
+function twice:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+            &limits,
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_check_output_not_input() -> Result<()> {
+        // Initialize a function that outputs an input register.
+        let function = r"
+function echo:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+    output r0 as field.private;";
+
+        // Ensure the default network accepts the function, and the lint reports it as a warning.
+        let program = sample_program_with_function(function)?;
+        let findings = program.lint(&LintRules::none().with(LintRule::OutputIsInput));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity(), LintSeverity::Warning);
+        assert_eq!(findings[0].message(), "Output register 'r0' is also an input");
+        // Ensure a network with strict output registers rejects the function.
+        let limits = NetworkLimits { strict_output_registers: true, ..NetworkLimits::of::<CurrentNetwork>() };
+        let error = check_function_with(function, &limits).unwrap_err();
+        assert_eq!(error.to_string(), "Output register 'r0' in function 'echo' is also an input");
+
+        // Ensure a function that does not output an input register is accepted.
+        check_function_with(
+            r"
+function twice:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+            &limits,
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_check_operand_counts() -> Result<()> {
+        // Initialize a function with a ternary instruction.
+        let function = r"
+function compute:
+    input r0 as boolean.private;
+    input r1 as field.private;
+    add r1 r1 into r2;
+    ternary r0 r1 r2 into r3;
+    output r3 as field.private;";
+
+        // Ensure the default network accepts the function.
+        let program = sample_program_with_function(function)?;
+        assert_eq!(program.get_function(&Identifier::from_str("compute")?)?.max_operand_count(), 3);
+        // Ensure a network with a lower limit rejects the function.
+        let limits = NetworkLimits { max_operands: 2, ..NetworkLimits::of::<CurrentNetwork>() };
+        assert!(check_function_with(function, &limits).is_err());
+
+        // Ensure a cast is exempt from the limit.
+        check_function_with(
+            r"
+function build:
+    input r0 as field.private;
+    cast r0 r0 r0 into r1 as point;
+    output r1 as point.private;",
+            &limits,
+        )?;
+        Ok(())
+    }
+
     #[test]
     fn test_program_record() -> Result<()> {
         // Create a new record.
@@ -720,6 +2095,32 @@ record foo:
         Ok(())
     }
 
+    #[test]
+    fn test_program_record_exceeds_payload_size() -> Result<()> {
+        // Create a struct with the maximum number of string members.
+        let members = (0..CurrentNetwork::MAX_DATA_ENTRIES).map(|i| format!("    m{i} as string;")).collect::<Vec<_>>();
+        let struct_ = Struct::<CurrentNetwork>::from_str(&format!("struct text:\n{}", members.join("\n")))?;
+
+        // Create a record with enough struct entries to exceed the payload size.
+        let entries = (0..17).map(|i| format!("    e{i} as text.private;")).collect::<Vec<_>>();
+        let record = RecordType::<CurrentNetwork>::from_str(&format!(
+            "record huge:\n    owner as address.private;\n    gates as u64.private;\n{}",
+            entries.join("\n")
+        ))?;
+
+        // Initialize a new program.
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("unknown.aleo")?)?;
+        program.add_struct(struct_)?;
+
+        // Ensure the oversized record is rejected.
+        let error = program.add_record(record).unwrap_err().to_string();
+        assert!(error.starts_with("Record 'huge' is 1110077 bits"), "{error}");
+        // Ensure the record was not added.
+        assert!(!program.contains_record(&Identifier::from_str("huge")?));
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_function() -> Result<()> {
         // Create a new function.
@@ -746,42 +2147,947 @@ function compute:
     }
 
     #[test]
-    fn test_program_import() -> Result<()> {
+    fn test_program_with_capacity() -> Result<()> {
+        let id = ProgramID::<CurrentNetwork>::from_str("capacity.aleo")?;
+
+        // Construct the same program, from `new` and from `with_capacity`.
+        let mut expected = Program::<CurrentNetwork>::new(id)?;
+        let mut candidate = Program::<CurrentNetwork>::with_capacity(id, 100, 100, 1000)?;
+        assert_eq!(expected, candidate);
+        assert!(candidate.structs.capacity() >= 100);
+        assert!(candidate.records.capacity() >= 100);
+        assert!(candidate.functions.capacity() >= 1000);
+
+        for i in 0..10 {
+            let struct_ = Struct::from_str(&format!("struct message{i}:\n    first as field;\n    second as field;"))?;
+            let function = Function::from_str(&format!(
+                "function compute{i}:\n    input r0 as message{i}.private;\n    add r0.first r0.second into r1;\n    output r1 as field.private;"
+            ))?;
+            for program in [&mut expected, &mut candidate] {
+                program.add_struct(struct_.clone())?;
+                program.add_function(function.clone())?;
+            }
+        }
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_string(), candidate.to_string());
+        assert_eq!(expected.to_bytes_le()?, candidate.to_bytes_le()?);
+
+        // Ensure an invalid program ID is rejected.
+        assert!(Program::<CurrentNetwork>::with_capacity(ProgramID::from_str("capacity.other")?, 1, 1, 1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_from_parts() -> Result<()> {
+        let id = ProgramID::<CurrentNetwork>::from_str("parts.aleo")?;
+
+        // Declare the parts, where each struct depends on the struct that follows it.
+        let outer = Struct::from_str("struct outer:\n    inner as middle;\n    amount as field;")?;
+        let middle = Struct::from_str("struct middle:\n    inner as innermost;")?;
+        let innermost = Struct::from_str("struct innermost:\n    amount as u64;")?;
+        let record = RecordType::from_str(
+            "record token:\n    owner as address.private;\n    gates as u64.private;\n    data as outer.private;",
+        )?;
+        let function = Function::from_str(
+            "function unwrap:\n    input r0 as token.record;\n    cast r0.data.inner.inner into r1 as middle;\n    output r1 as middle.private;",
+        )?;
+
+        // Construct the program from parts supplied in dependency-violating order.
+        let candidate = Program::from_parts(
+            id,
+            vec![outer.clone(), middle.clone(), innermost.clone()],
+            vec![record.clone()],
+            vec![function.clone()],
+        )?;
+
+        // Ensure it matches the program constructed in dependency order.
+        let mut expected = Program::<CurrentNetwork>::new(id)?;
+        expected.add_struct(innermost.clone())?;
+        expected.add_struct(middle.clone())?;
+        expected.add_struct(outer.clone())?;
+        expected.add_record(record.clone())?;
+        expected.add_function(function.clone())?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_string(), candidate.to_string());
+
+        // Ensure every unresolved reference is reported.
+        let error = Program::from_parts(id, vec![outer, middle], vec![record], vec![function]).unwrap_err().to_string();
+        assert!(error.contains("found 5 error(s)"), "{error}");
+        assert!(error.contains("'innermost' in struct 'middle' is not defined."), "{error}");
+        assert!(error.contains("'middle' in struct 'outer' is not defined."), "{error}");
+        assert!(error.contains("Struct 'outer' in record 'token' is not defined."), "{error}");
+        assert!(error.contains("Record 'token' in function 'unwrap' is not defined."), "{error}");
+        assert!(error.contains("Struct 'middle' in function 'unwrap' is not defined."), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_call_graph() -> Result<()> {
         // Initialize a new program.
         let program = Program::<CurrentNetwork>::from_str(
             r"
-import eth.aleo;
-import usdc.aleo;
+program example_graph.aleo;
 
-program swap.aleo;
+closure add_self:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
 
-// The `swap` function transfers ownership of the record
-// for token A to the record owner of token B, and vice-versa.
-function swap:
-    // Input the record for token A.
-    input r0 as eth.aleo/eth.record;
-    // Input the record for token B.
-    input r1 as usdc.aleo/usdc.record;
+closure mul_self:
+    input r0 as field;
+    mul r0 r0 into r1;
+    output r1 as field;
 
-    // Send the record for token A to the owner of token B.
-    call eth.aleo/transfer r0 r1.owner r0.amount into r2 r3;
+function compute:
+    input r0 as field.private;
+    call add_self r0 into r1;
+    call mul_self r1 into r2;
+    call add_self r2 into r3;
+    output r3 as field.private;
 
-    // Send the record for token B to the owner of token A.
-    call usdc.aleo/transfer r1 r0.owner r1.amount into r4 r5;
+function identity:
+    input r0 as field.private;
+    add r0 0field into r1;
+    output r1 as field.private;",
+        )?;
 
-    // Output the new record for token A.
-    output r2 as eth.aleo/eth.record;
-    // Output the new record for token B.
-    output r4 as usdc.aleo/usdc.record;
-    ",
-        )
-        .unwrap();
+        // Retrieve the names.
+        let add_self = Identifier::from_str("add_self")?;
+        let mul_self = Identifier::from_str("mul_self")?;
+        let compute = Identifier::from_str("compute")?;
+        let identity = Identifier::from_str("identity")?;
+
+        // Compute the call graph.
+        let call_graph = program.call_graph();
+        assert_eq!(call_graph.len(), 4);
+        // Ensure the nodes are in order of declaration.
+        assert_eq!(call_graph.keys().copied().collect::<Vec<_>>(), vec![add_self, mul_self, compute, identity]);
+        // Ensure the closures do not call anything.
+        assert!(call_graph[&add_self].is_empty());
+        assert!(call_graph[&mul_self].is_empty());
+        // Ensure the calling function lists its callees (in order of first call).
+        assert_eq!(call_graph[&compute], IndexSet::from([add_self, mul_self]));
+        // Ensure the leaf function does not call anything.
+        assert!(call_graph[&identity].is_empty());
+
+        // Initialize a program with a chain of three calling functions.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program example_chain.aleo;
 
-        // Ensure the program imports exist.
-        assert!(program.contains_import(&ProgramID::from_str("eth.aleo")?));
-        assert!(program.contains_import(&ProgramID::from_str("usdc.aleo")?));
+function third:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
 
-        // Retrieve the 'swap' function.
+function second:
+    input r0 as field.private;
+    call third r0 into r1;
+    output r1 as field.private;
+
+function first:
+    input r0 as field.private;
+    call second r0 into r1;
+    output r1 as field.private;",
+        )?;
+        let first = Identifier::from_str("first")?;
+        let second = Identifier::from_str("second")?;
+        let third = Identifier::from_str("third")?;
+
+        // Ensure each function lists only the function it calls directly.
+        let expected = IndexMap::from([
+            (third, IndexSet::new()),
+            (second, IndexSet::from([third])),
+            (first, IndexSet::from([second])),
+        ]);
+        assert_eq!(program.call_graph(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_record_commitment() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program token_commitment.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    token_amount as u64.private;
+
+function compute:
+    input r0 as token.record;
+    add r0.token_amount r0.token_amount into r1;
+    output r1 as u64.private;",
+        )?;
+        let token = Identifier::from_str("token")?;
+
+        // Initialize the records.
+        let owner = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+        let record = |amount: u64| {
+            Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+                "{{ owner: {owner}.private, gates: 5u64.private, token_amount: {amount}u64.private, _nonce: 0group.public }}"
+            ))
+        };
+
+        // Ensure the commitment is deterministic.
+        let commitment = program.record_commitment(&token, &record(100)?)?;
+        assert_eq!(commitment, program.record_commitment(&token, &record(100)?)?);
+        // Ensure the commitment matches the record commitment.
+        assert_eq!(commitment, record(100)?.to_commitment(program.id(), &token)?);
+        // Ensure records differing in one entry have different commitments.
+        assert_ne!(commitment, program.record_commitment(&token, &record(101)?)?);
+        // Ensure a missing record is rejected.
+        assert!(program.record_commitment(&Identifier::from_str("missing")?, &record(100)?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_function_is_deterministic() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program example_deterministic.aleo;
+
+closure add_self:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as field.private;
+    call add_self r0 into r1;
+    output r1 as field.private;
+
+function sum:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+
+        // Ensure the functions are deterministic, including through a local call.
+        assert!(program.function_is_deterministic(&Identifier::from_str("sum")?)?);
+        assert!(program.function_is_deterministic(&Identifier::from_str("compute")?)?);
+        // Ensure a missing function is rejected.
+        assert!(program.function_is_deterministic(&Identifier::from_str("missing")?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_resolve_value_type() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+import token.aleo;
+
+program resolve.aleo;
+
+struct message:
+    first as field;
+
+record ticket:
+    owner as address.private;
+    gates as u64.private;
+
+function compute:
+    input r0 as message.private;
+    add r0.first r0.first into r1;
+    output r1 as field.private;",
+        )?;
+
+        // Ensure defined types are resolved.
+        assert_eq!(program.resolve_value_type("ticket.record")?, ValueType::from_str("ticket.record")?);
+        assert_eq!(program.resolve_value_type("message.private")?, ValueType::from_str("message.private")?);
+        assert_eq!(program.resolve_value_type("field.public")?, ValueType::from_str("field.public")?);
+        assert_eq!(
+            program.resolve_value_type("token.aleo/token.record")?,
+            ValueType::from_str("token.aleo/token.record")?
+        );
+
+        // Ensure undefined and misused types are rejected with a precise error.
+        let check = |string: &str, message: &str| {
+            assert_eq!(program.resolve_value_type(string).unwrap_err().to_string(), message);
+        };
+        check("envelope.private", "Struct 'envelope' is not defined in 'resolve.aleo'");
+        check("envelope.record", "Record 'envelope' is not defined in 'resolve.aleo'");
+        check("ticket.private", "'ticket' is a record in 'resolve.aleo', and must be used as 'ticket.record'");
+        check("message.record", "'message' is a struct in 'resolve.aleo', and cannot be used as a record");
+        check(
+            "coin.aleo/coin.record",
+            "External record 'coin.aleo/coin' references 'coin.aleo', which is not imported by 'resolve.aleo'",
+        );
+        assert!(program.resolve_value_type("ticket").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_function_hash() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program function_hash.aleo;
+
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+        let compute = Identifier::from_str("compute")?;
+        let hash = program.function_hash(&compute)?;
+
+        // Ensure whitespace-only edits to the function yield the same hash.
+        let reformatted = Program::<CurrentNetwork>::from_str(
+            r"
+program function_hash.aleo;
+function   compute:
+  input r0 as field.public;    // The first input.
+        input r1 as field.private;
+    add   r0   r1   into   r2;
+
+    output r2 as field.private;",
+        )?;
+        assert_eq!(hash, reformatted.function_hash(&compute)?);
+
+        // Ensure the hash is independent of the rest of the program.
+        let extended = Program::<CurrentNetwork>::from_str(
+            r"
+program other_hash.aleo;
+
+struct message:
+    first as field;
+
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;
+
+function reduce:
+    input r0 as field.public;
+    sub r0 r0 into r1;
+    output r1 as field.private;",
+        )?;
+        assert_eq!(hash, extended.function_hash(&compute)?);
+        assert_ne!(hash, extended.function_hash(&Identifier::from_str("reduce")?)?);
+
+        // Ensure a change to the function body changes the hash.
+        let modified = Program::<CurrentNetwork>::from_str(&program.to_string().replace("add", "mul"))?;
+        assert_ne!(hash, modified.function_hash(&compute)?);
+
+        // Ensure a missing function is rejected.
+        assert!(program.function_hash(&Identifier::from_str("missing")?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_output_record_count() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program output_records.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+
+function split:
+    input r0 as token.record;
+    input r1 as u64.private;
+    cast r0.owner r0.gates into r2 as token.record;
+    add r1 r1 into r3;
+    output r2 as token.record;
+    output r3 as u64.private;
+
+function sum:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+        )?;
+
+        // Ensure only the record outputs are counted.
+        assert_eq!(1, program.output_record_count(&Identifier::from_str("split")?)?);
+        assert_eq!(0, program.output_record_count(&Identifier::from_str("sum")?)?);
+
+        // Ensure a missing function is rejected.
+        assert!(program.output_record_count(&Identifier::from_str("missing")?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_check_output_records() -> Result<()> {
+        // Initialize a function with two record outputs.
+        let function = r"
+function split:
+    input r0 as token.record;
+    cast r0.owner r0.gates into r1 as token.record;
+    cast r0.owner 0u64 into r2 as token.record;
+    output r1 as token.record;
+    output r2 as token.record;";
+
+        // Ensure the default network accepts the function.
+        let program = sample_program_with_function(function)?;
+        assert_eq!(2, program.output_record_count(&Identifier::from_str("split")?)?);
+        // Ensure a network that allows one record output rejects the function.
+        let limits = NetworkLimits { max_output_records: 1, ..NetworkLimits::of::<CurrentNetwork>() };
+        let error = check_function_with(function, &limits).unwrap_err();
+        assert_eq!(error.to_string(), "Function exceeds maximum number of output records");
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_output_record_types() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program output_record_types.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    amount as u64.public;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.public;
+    cast r0 0u64 r1 into r2 as token.record;
+    add r1 r1 into r3;
+    output r3 as u64.private;
+    output r2 as token.record;
+
+function sum:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+        )?;
+
+        // Ensure the record outputs resolve to the declared record type.
+        let expected = program.get_record(&Identifier::from_str("token")?)?;
+        assert_eq!(vec![expected], program.output_record_types(&Identifier::from_str("mint")?)?);
+        assert!(program.output_record_types(&Identifier::from_str("sum")?)?.is_empty());
+
+        // Ensure a missing function is rejected.
+        assert!(program.output_record_types(&Identifier::from_str("missing")?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_complexity() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program complexity.aleo;
+
+closure scale:
+    input r0 as field;
+    mul r0 r0 into r1;
+    hash.psd2 r1 into r2;
+    output r2 as field;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    call scale r1 into r2;
+    add r1 r2 into r3;
+    output r3 as field.private;",
+        )?;
+        let compute = Identifier::from_str("compute")?;
+
+        // Ensure the complexity uses the network opcode costs, including those of the called closure.
+        let expected = 2 * CurrentNetwork::opcode_cost("add")
+            + CurrentNetwork::opcode_cost("call")
+            + CurrentNetwork::opcode_cost("mul")
+            + CurrentNetwork::opcode_cost("hash.psd2");
+        assert_eq!(expected, program.complexity(&compute)?);
+        assert_eq!(54, program.complexity(&compute)?);
+
+        // Ensure a network that prices an opcode differently is reflected in the complexity.
+        let custom_cost = |opcode: &'static str| match opcode {
+            "add" => 7,
+            opcode => CurrentNetwork::opcode_cost(opcode),
+        };
+        assert_eq!(expected + 12, program.complexity_with(&compute, custom_cost)?);
+
+        // Ensure a missing function and a closure are rejected.
+        assert!(program.complexity(&Identifier::from_str("missing")?).is_err());
+        assert!(program.complexity(&Identifier::from_str("scale")?).is_err());
+
+        // Ensure a closure called twice, but not recursively, is counted twice.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program complexity.aleo;
+
+closure scale:
+    input r0 as field;
+    mul r0 r0 into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as field.private;
+    call scale r0 into r1;
+    call scale r1 into r2;
+    output r2 as field.private;",
+        )?;
+        let expected = 2 * (CurrentNetwork::opcode_cost("call") + CurrentNetwork::opcode_cost("mul"));
+        assert_eq!(expected, program.complexity(&compute)?);
+
+        // Ensure mutually recursive closures are rejected, rather than overflowing the stack.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program complexity.aleo;
+
+closure ping:
+    input r0 as field;
+    call pong r0 into r1;
+    output r1 as field;
+
+closure pong:
+    input r0 as field;
+    call ping r0 into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as field.private;
+    call ping r0 into r1;
+    output r1 as field.private;",
+        )?;
+        let error = program.complexity(&compute).unwrap_err();
+        assert_eq!(error.to_string(), "Closure or function 'ping' calls itself, so its complexity is unbounded.");
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_assert_unique_signatures() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program signatures.aleo;
+
+function sum:
+    input r0 as u64.private;
+    input r1 as u64.public;
+    add r0 r1 into r2;
+    output r2 as u64.private;
+
+function product:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    mul r0 r1 into r2;
+    output r2 as u64.private;",
+        )?;
+        // Ensure functions that differ in visibility have distinct signatures.
+        program.assert_unique_signatures()?;
+
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program signatures.aleo;
+
+function sum:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    output r2 as u64.private;
+
+function product:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    mul r0 r1 into r2;
+    output r2 as u64.private;",
+        )?;
+        // Ensure the error names both functions.
+        let error = program.assert_unique_signatures().unwrap_err().to_string();
+        assert!(error.contains("'sum'") && error.contains("'product'"), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_record_owner_mode() -> Result<()> {
+        let private_owner = r"
+program owners.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.public;";
+        let public_owner = r"
+program owners.aleo;
+
+record token:
+    owner as address.public;
+    gates as u64.private;";
+
+        // Ensure the network accepts a record with a private owner.
+        let program = Program::<CurrentNetwork>::from_str(private_owner)?;
+        assert!(program.contains_record(&Identifier::from_str("token")?));
+        // Ensure the network rejects a record with a public owner, by default.
+        assert!(Program::<CurrentNetwork>::from_str(public_owner).is_err());
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("owners.aleo")?)?;
+        let record = RecordType::from_str(
+            r"
+record token:
+    owner as address.public;
+    gates as u64.private;",
+        )?;
+        let error = program.add_record(record).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Record 'token' declares its owner as 'address.public', but the network requires 'address.private'"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_total_field_count() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program fields.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+struct segment:
+    start as point;
+    end as point;
+    label as u8;",
+        )?;
+
+        // Ensure the nested struct fields are counted as leaves.
+        assert_eq!(2, program.total_field_count(&Identifier::from_str("point")?)?);
+        assert_eq!(5, program.total_field_count(&Identifier::from_str("segment")?)?);
+        // Ensure an undefined struct is rejected.
+        assert!(program.total_field_count(&Identifier::from_str("missing")?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_opcode_histogram() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program histogram.aleo;
+
+function sum:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    add r2 r1 into r3;
+    output r3 as u64.private;",
+        )?;
+        assert_eq!(program.opcode_histogram(), IndexMap::from([("add".to_string(), 2)]));
+
+        // Ensure the opcodes are tallied across functions, in order of first appearance.
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            r"{program}
+function product:
+    input r0 as u64.private;
+    mul r0 r0 into r1;
+    add r1 r0 into r2;
+    output r2 as u64.private;"
+        ))?;
+        assert_eq!(program.opcode_histogram(), IndexMap::from([("add".to_string(), 3), ("mul".to_string(), 1)]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_function_inputs_hash() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program inputs_hash.aleo;
+
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+
+        let function_name = Identifier::from_str("compute")?;
+        let inputs = [Value::from_str("3field")?, Value::from_str("5field")?];
+
+        // Ensure the hash is deterministic.
+        let hash = program.function_inputs_hash(&function_name, &inputs)?;
+        assert_eq!(hash, program.function_inputs_hash(&function_name, &inputs)?);
+
+        // Ensure the hash depends on the order and values of the inputs.
+        let swapped = [inputs[1].clone(), inputs[0].clone()];
+        assert_ne!(hash, program.function_inputs_hash(&function_name, &swapped)?);
+
+        // Ensure the hash depends on the program.
+        let other = Program::<CurrentNetwork>::from_str(&program.to_string().replace("inputs_hash", "other_hash"))?;
+        assert_ne!(hash, other.function_inputs_hash(&function_name, &inputs)?);
+
+        // Ensure invalid inputs are rejected.
+        assert!(program.function_inputs_hash(&function_name, &inputs[..1]).is_err());
+        assert!(program.function_inputs_hash(&Identifier::from_str("missing")?, &inputs).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_opcodes_used() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program opcodes.aleo;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+        assert_eq!(program.opcodes_used(), IndexSet::from(["add".to_string()]));
+
+        // Ensure the opcodes are listed once, in order of first appearance.
+        let credits = Program::<CurrentNetwork>::credits()?;
+        assert_eq!(credits.opcodes_used(), IndexSet::from(["cast".to_string(), "sub".to_string(), "add".to_string()]));
+
+        // Ensure the opcodes of closures and finalize scopes are included.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program opcodes.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+closure twice:
+    input r0 as u64;
+    mul r0 2u64 into r1;
+    output r1 as u64;
+
+function deposit:
+    input r0 as u64.public;
+    call twice r0 into r1;
+    finalize self.caller r1;
+
+finalize deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment account[r0] by r1;",
+        )?;
+        let expected = ["mul", "call", "finalize", "increment"].map(String::from);
+        assert_eq!(program.opcodes_used(), IndexSet::from(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_literal_operands() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program literals.aleo;
+
+closure scale:
+    input r0 as u64;
+    mul r0 10u64 into r1;
+    output r1 as u64;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    call scale r1 into r2;
+    add 42u64 r2 into r3;
+    sub r3 7u64 into r4;
+    output r4 as u64.private;",
+        )?;
+
+        let scale = Identifier::from_str("scale")?;
+        let compute = Identifier::from_str("compute")?;
+        let expected = vec![
+            (scale, 0, Literal::from_str("10u64")?),
+            (compute, 2, Literal::from_str("42u64")?),
+            (compute, 3, Literal::from_str("7u64")?),
+        ];
+        assert_eq!(program.literal_operands(), expected);
+
+        // Ensure a program without literal operands returns nothing.
+        assert!(Program::<CurrentNetwork>::credits()?.literal_operands().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_check_literal_operands() -> Result<()> {
+        // Initialize a function that doubles a group element.
+        let mut function = Function::<CurrentNetwork>::from_str(
+            r"
+function twice:
+    input r0 as group.private;
+    add r0 r0 into r1;
+    output r1 as group.private;",
+        )?;
+        // Ensure the function is accepted.
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("literals.aleo")?)?;
+        program.add_function(function.clone())?;
+
+        // Replace the operands with a group element that is not on the curve.
+        // Note: Such a literal cannot be parsed or deserialized, only constructed directly.
+        let (x, y) = (console::types::Field::from_u64(1), console::types::Field::from_u64(1));
+        let literal = Literal::Group(console::types::Group::from_xy_coordinates_unchecked(x, y));
+        let mut rename = |register: &Register<CurrentNetwork>| match register {
+            Register::Locator(0) => Ok(Operand::Literal(literal.clone())),
+            register => Ok(Operand::Register(register.clone())),
+        };
+        function.instructions_mut()[0] = function.instructions()[0].rename_registers(&mut rename)?;
+
+        // Ensure the malformed literal is rejected when the function is added.
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("literals.aleo")?)?;
+        let error = program.add_function(function).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!("Literal operand '{literal}' of instruction 0 in 'twice' is not a well-formed 'group'")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_unresolved_references() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program unresolved.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function send:
+    input r0 as message.private;
+    input r1 as envelope.private;
+    cast r0.first r1.inner into r2 as receipt;
+    output r2 as receipt.private;
+
+function identity:
+    input r0 as message.private;
+    cast r0.first r0.second into r1 as message;
+    output r1 as message.private;",
+        )?;
+
+        // Compute the unresolved references.
+        let unresolved = program.unresolved_references();
+        // Ensure only the function with unresolved references is included.
+        assert_eq!(unresolved.len(), 1);
+        // Ensure every missing struct is listed, in order of appearance.
+        let expected = IndexSet::from([Identifier::from_str("envelope")?, Identifier::from_str("receipt")?]);
+        assert_eq!(unresolved[&Identifier::from_str("send")?], expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_retain_functions() -> Result<()> {
+        // Initialize a new program.
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r"
+program retained.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+struct point:
+    x as field;
+    y as field;
+
+struct message:
+    first as field;
+    second as field;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    contents as message.private;
+
+closure sum:
+    input r0 as point;
+    add r0.x r0.y into r1;
+    output r1 as field;
+
+function measure:
+    input r0 as point.private;
+    call sum r0 into r1;
+    output r1 as field.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as message.private;
+    cast r0 0u64 r1 into r2 as token.record;
+    output r2 as token.record;",
+        )?;
+
+        // Ensure a name that is not a function cannot be retained.
+        let point = Identifier::from_str("point")?;
+        assert!(program.clone().retain_functions(&IndexSet::from([point])).is_err());
+
+        // Retain the 'measure' function.
+        let measure = Identifier::from_str("measure")?;
+        program.retain_functions(&IndexSet::from([measure]))?;
+
+        // Ensure the function and its dependencies are retained.
+        assert!(program.contains_function(&measure));
+        assert!(program.contains_closure(&Identifier::from_str("sum")?));
+        assert!(program.contains_struct(&point));
+        // Ensure the unused definitions are pruned.
+        assert!(!program.contains_function(&Identifier::from_str("mint")?));
+        assert!(!program.contains_record(&Identifier::from_str("token")?));
+        assert!(!program.contains_struct(&Identifier::from_str("message")?));
+        assert!(!program.contains_mapping(&Identifier::from_str("account")?));
+
+        // Ensure the pruned program is well-formed.
+        let expected = Program::<CurrentNetwork>::from_str(
+            r"
+program retained.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+closure sum:
+    input r0 as point;
+    add r0.x r0.y into r1;
+    output r1 as field;
+
+function measure:
+    input r0 as point.private;
+    call sum r0 into r1;
+    output r1 as field.private;",
+        )?;
+        assert_eq!(expected, program);
+        assert_eq!(expected, Program::from_str(&program.to_string())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_import() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+import eth.aleo;
+import usdc.aleo;
+
+program swap.aleo;
+
+// The `swap` function transfers ownership of the record
+// for token A to the record owner of token B, and vice-versa.
+function swap:
+    // Input the record for token A.
+    input r0 as eth.aleo/eth.record;
+    // Input the record for token B.
+    input r1 as usdc.aleo/usdc.record;
+
+    // Send the record for token A to the owner of token B.
+    call eth.aleo/transfer r0 r1.owner r0.amount into r2 r3;
+
+    // Send the record for token B to the owner of token A.
+    call usdc.aleo/transfer r1 r0.owner r1.amount into r4 r5;
+
+    // Output the new record for token A.
+    output r2 as eth.aleo/eth.record;
+    // Output the new record for token B.
+    output r4 as usdc.aleo/usdc.record;
+    ",
+        )
+        .unwrap();
+
+        // Ensure the program imports exist.
+        assert!(program.contains_import(&ProgramID::from_str("eth.aleo")?));
+        assert!(program.contains_import(&ProgramID::from_str("usdc.aleo")?));
+
+        // Retrieve the 'swap' function.
         let function = program.get_function(&Identifier::from_str("swap")?)?;
 
         // Ensure there are two inputs.
@@ -799,29 +3105,91 @@ function swap:
         assert_eq!(function.instructions()[0].opcode(), Opcode::Call);
         assert_eq!(function.instructions()[1].opcode(), Opcode::Call);
 
-        // Ensure there are two outputs.
-        assert_eq!(function.outputs().len(), 2);
-        assert_eq!(function.output_types().len(), 2);
+        // Ensure there are two outputs.
+        assert_eq!(function.outputs().len(), 2);
+        assert_eq!(function.output_types().len(), 2);
+
+        // Ensure the outputs are external records.
+        assert_eq!(function.output_types()[0], ValueType::ExternalRecord(Locator::from_str("eth.aleo/eth")?));
+        assert_eq!(function.output_types()[1], ValueType::ExternalRecord(Locator::from_str("usdc.aleo/usdc")?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_evaluate_function() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+    program example.aleo;
+
+    function foo:
+        input r0 as field.public;
+        input r1 as field.private;
+        add r0 r1 into r2;
+        output r2 as field.private;
+    ",
+        )
+        .unwrap();
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("foo").unwrap();
+        // Declare the function inputs.
+        let inputs = [
+            Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("2field").unwrap()),
+            Value::Plaintext(Plaintext::from_str("3field").unwrap()),
+        ];
+
+        // Construct the process.
+        let process = crate::process::test_helpers::sample_process(&program);
+
+        // Compute the authorization.
+        let authorization = {
+            // Initialize an RNG.
+            let rng = &mut TestRng::default();
+
+            // Initialize caller private key.
+            let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+                .unwrap();
+            assert_eq!(authorization.len(), 1);
+            authorization
+        };
+
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id()).unwrap();
 
-        // Ensure the outputs are external records.
-        assert_eq!(function.output_types()[0], ValueType::ExternalRecord(Locator::from_str("eth.aleo/eth")?));
-        assert_eq!(function.output_types()[1], ValueType::ExternalRecord(Locator::from_str("usdc.aleo/usdc")?));
+        // Declare the expected output.
+        let expected = Value::Plaintext(Plaintext::<CurrentNetwork>::from_str("5field").unwrap());
 
-        Ok(())
+        // Run the function.
+        let response =
+            stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap()).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(1, candidate.len());
+        assert_eq!(expected, candidate[0]);
+
+        // Re-run to ensure state continues to work.
+        let response = stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap()).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(1, candidate.len());
+        assert_eq!(expected, candidate[0]);
     }
 
     #[test]
-    fn test_program_evaluate_function() {
+    fn test_program_evaluate_function_with_registers() {
         let program = Program::<CurrentNetwork>::from_str(
             r"
-    program example.aleo;
+program example.aleo;
 
-    function foo:
-        input r0 as field.public;
-        input r1 as field.private;
-        add r0 r1 into r2;
-        output r2 as field.private;
-    ",
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    double r2 into r3;
+    output r3 as field.private;",
         )
         .unwrap();
 
@@ -855,21 +3223,34 @@ function swap:
         // Retrieve the stack.
         let stack = process.get_stack(program.id()).unwrap();
 
-        // Declare the expected output.
-        let expected = Value::Plaintext(Plaintext::<CurrentNetwork>::from_str("5field").unwrap());
-
         // Run the function.
-        let response =
-            stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap()).unwrap();
-        let candidate = response.outputs();
-        assert_eq!(1, candidate.len());
-        assert_eq!(expected, candidate[0]);
+        let call_stack = CallStack::evaluate(authorization.replicate()).unwrap();
+        let (response, registers) = stack.evaluate_function_with_registers::<CurrentAleo>(call_stack).unwrap();
 
-        // Re-run to ensure state continues to work.
-        let response = stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap()).unwrap();
+        // Ensure the output is correct.
+        let expected = Value::Plaintext(Plaintext::<CurrentNetwork>::from_str("10field").unwrap());
         let candidate = response.outputs();
         assert_eq!(1, candidate.len());
         assert_eq!(expected, candidate[0]);
+
+        // Ensure every register is returned, in order of assignment.
+        assert_eq!(registers.len(), 4);
+        assert_eq!(registers.keys().cloned().collect::<Vec<_>>(), [
+            Register::Locator(0),
+            Register::Locator(1),
+            Register::Locator(2),
+            Register::Locator(3)
+        ]);
+        // Ensure the intermediate register is correct.
+        assert_eq!(registers[&Register::Locator(2)], Value::Plaintext(Plaintext::from_str("5field").unwrap()));
+        assert_eq!(registers[&Register::Locator(3)], expected);
+
+        // Ensure the inputs and outputs are captured during evaluation.
+        let (response, input_values, output_values) =
+            stack.evaluate_function_with_values::<CurrentAleo>(CallStack::evaluate(authorization).unwrap()).unwrap();
+        assert_eq!(input_values, inputs);
+        assert_eq!(output_values, [expected]);
+        assert_eq!(output_values, response.outputs());
     }
 
     #[test]
@@ -970,7 +3351,7 @@ function compute:
             "{{ owner: {caller}.private, gates: 5u64.private, token_amount: 100u64.private, _nonce: 0group.public }}"
         ))
         .unwrap();
-        let input = Value::<CurrentNetwork>::Record(input_record);
+        let input = Value::<CurrentNetwork>::from(input_record);
 
         // Declare the expected output value.
         let expected = Value::Plaintext(Plaintext::from_str("200u64").unwrap());
@@ -1124,6 +3505,340 @@ function compute:
         assert_eq!(r4, candidate[2]);
     }
 
+    #[test]
+    fn test_program_evaluate_cast_into_struct() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program struct_with_cast.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function pack:
+    input r0 as field.private;
+    input r1 as field.private;
+    cast r0 r1 into r2 as message;
+    output r2 as message.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("pack").unwrap();
+        // Declare the input values.
+        let inputs = [
+            Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("2field").unwrap()),
+            Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("3field").unwrap()),
+        ];
+        // Declare the expected output value.
+        let expected = Value::Plaintext(Plaintext::from_str("{ first: 2field, second: 3field }").unwrap());
+
+        // Construct the process.
+        let mut process = crate::process::test_helpers::sample_process(&program);
+
+        // Compute the authorization.
+        let authorization = {
+            // Initialize an RNG.
+            let rng = &mut TestRng::default();
+
+            // Initialize caller private key.
+            let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+                .unwrap();
+            assert_eq!(authorization.len(), 1);
+            authorization
+        };
+
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id()).unwrap();
+
+        // Compute the output value.
+        let response = stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap()).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(1, candidate.len());
+        assert_eq!(expected, candidate[0]);
+
+        // Ensure a cast with fewer operands than struct members is rejected.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program struct_with_short_cast.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function pack:
+    input r0 as field.private;
+    cast r0 into r1 as message;
+    output r1 as message.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert!(process.add_program(&program).is_err());
+
+        // Ensure a cast with mismatched operand types is rejected.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program struct_with_bad_cast.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function pack:
+    input r0 as field.private;
+    input r1 as u8.private;
+    cast r0 r1 into r2 as message;
+    output r2 as message.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert!(process.add_program(&program).is_err());
+    }
+
+    #[test]
+    fn test_program_global() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program globals.aleo;
+
+global PI as field = 3field;
+
+struct point:
+    x as u8;
+    y as u8;
+
+global ORIGIN as point = { x: 0u8, y: 0u8 };
+
+function shift:
+    input r0 as field.private;
+    add r0 PI into r1;
+    output r1 as field.private;",
+        )?;
+
+        // Ensure the global constants are stored.
+        let pi = Identifier::from_str("PI")?;
+        assert!(program.contains_global(&pi));
+        assert_eq!(program.globals().len(), 2);
+        assert_eq!(program.get_global(&pi)?, (PlaintextType::from_str("field")?, Plaintext::from_str("3field")?));
+        assert!(program.get_global(&Identifier::from_str("TAU")?).is_err());
+
+        // Ensure the global constants are printed in order of declaration.
+        let expected = r"program globals.aleo;
+
+global PI as field = 3field;
+
+struct point:
+    x as u8;
+    y as u8;
+
+global ORIGIN as point = {
+  x: 0u8,
+  y: 0u8
+};
+
+function shift:
+    input r0 as field.private;
+    add r0 PI into r1;
+    output r1 as field.private;
+";
+        assert_eq!(program.to_string(), expected);
+        assert_eq!(program, Program::from_str(&program.to_string())?);
+        assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le()?)?);
+
+        // Ensure a global value of the wrong type is rejected.
+        assert!(Program::<CurrentNetwork>::from_str(
+            r"
+program globals.aleo;
+
+global PI as u8 = 3field;
+
+function shift:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;"
+        )
+        .is_err());
+
+        // Ensure a global name cannot be reused.
+        assert!(Program::<CurrentNetwork>::from_str(
+            r"
+program globals.aleo;
+
+global shift as field = 3field;
+
+function shift:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;"
+        )
+        .is_err());
+
+        // Ensure a global name that would be parsed as a register is rejected, but one that begins with 'r' is not.
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("globals.aleo")?)?;
+        for name in ["r0", "r12", "r1x"] {
+            let error = program
+                .add_global(
+                    Identifier::from_str(name)?,
+                    PlaintextType::from_str("field")?,
+                    Plaintext::from_str("3field")?,
+                )
+                .unwrap_err();
+            assert_eq!(error.to_string(), format!("'{name}' would be parsed as a register."));
+        }
+        program.add_global(
+            Identifier::from_str("rate")?,
+            PlaintextType::from_str("field")?,
+            Plaintext::from_str("3field")?,
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_evaluate_global() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program globals.aleo;
+
+global PI as field = 3field;
+
+function shift:
+    input r0 as field.private;
+    add r0 PI into r1;
+    output r1 as field.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("shift").unwrap();
+        // Declare the input value.
+        let inputs = [Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("2field").unwrap())];
+        // Declare the expected output value.
+        let expected = Value::Plaintext(Plaintext::from_str("5field").unwrap());
+
+        // Construct the process.
+        let mut process = crate::process::test_helpers::sample_process(&program);
+
+        // Compute the authorization.
+        let authorization = {
+            // Initialize an RNG.
+            let rng = &mut TestRng::default();
+
+            // Initialize caller private key.
+            let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+                .unwrap();
+            assert_eq!(authorization.len(), 1);
+            authorization
+        };
+
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id()).unwrap();
+
+        // Compute the output value.
+        let response = stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap()).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(1, candidate.len());
+        assert_eq!(expected, candidate[0]);
+
+        // Ensure a global used with a mismatched type is rejected.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program globals_with_bad_add.aleo;
+
+global LIMIT as u8 = 3u8;
+
+function shift:
+    input r0 as field.private;
+    add r0 LIMIT into r1;
+    output r1 as field.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert!(process.add_program(&program).is_err());
+    }
+
+    #[test]
+    fn test_program_evaluate_literal_output() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program literal_output.aleo;
+
+function shift:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+    output 5field as field.constant;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Ensure the function round-trips through its string and byte representations.
+        let function = program.get_function(&Identifier::from_str("shift").unwrap()).unwrap();
+        assert_eq!(function, Function::from_str(&function.to_string()).unwrap());
+        assert_eq!(function, Function::read_le(&function.to_bytes_le().unwrap()[..]).unwrap());
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("shift").unwrap();
+        // Declare the input value.
+        let inputs = [Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("2field").unwrap())];
+        // Declare the expected output values.
+        let expected = [
+            Value::Plaintext(Plaintext::from_str("4field").unwrap()),
+            Value::Plaintext(Plaintext::from_str("5field").unwrap()),
+        ];
+
+        // Construct the process.
+        let process = crate::process::test_helpers::sample_process(&program);
+
+        // Compute the authorization.
+        let authorization = {
+            // Initialize an RNG.
+            let rng = &mut TestRng::default();
+
+            // Initialize caller private key.
+            let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+                .unwrap();
+            assert_eq!(authorization.len(), 1);
+            authorization
+        };
+
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id()).unwrap();
+
+        // Compute the output values.
+        let response = stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap()).unwrap();
+        assert_eq!(response.outputs(), expected);
+
+        // Ensure a literal output with a mismatched type is rejected.
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("literal_output.aleo").unwrap()).unwrap();
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function shift:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output 5u8 as field.constant;",
+        )
+        .unwrap();
+        let error = program.add_function(function).unwrap_err();
+        assert_eq!(error.to_string(), "Output '5u8' in function 'shift' does not match the declared type 'field'");
+    }
+
     #[test]
     fn test_program_evaluate_cast() {
         // Initialize a new program.
@@ -1159,7 +3874,7 @@ function compute:
             "{{ owner: {caller}.private, gates: 5u64.private, token_amount: 100u64.private, _nonce: 0group.public }}"
         ))
         .unwrap();
-        let input = Value::<CurrentNetwork>::Record(input_record);
+        let input = Value::<CurrentNetwork>::from(input_record);
 
         // Construct the process.
         let process = crate::process::test_helpers::sample_process(&program);
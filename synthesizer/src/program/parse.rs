@@ -26,9 +26,65 @@ impl<N: Network> Parser for Program<N> {
             I(Struct<N>),
             R(RecordType<N>),
             C(Closure<N>),
-            F(Function<N>),
+            F(Box<Function<N>>, Vec<(usize, usize)>),
+            A(Identifier<N>),
+            G(Identifier<N>, PlaintextType<N>, Plaintext<N>),
         }
 
+        // A helper to parse a global constant, i.e. `global {name} as {type} = {value};`.
+        fn parse_global<N: Network>(string: &str) -> ParserResult<(Identifier<N>, PlaintextType<N>, Plaintext<N>)> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the 'global' keyword from the string.
+            let (string, _) = tag("global")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the global name from the string.
+            let (string, name) = Identifier::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the 'as' keyword from the string.
+            let (string, _) = tag("as")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the global type from the string.
+            let (string, plaintext_type) = PlaintextType::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the '=' from the string.
+            let (string, _) = tag("=")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the global value from the string.
+            let (string, value) = Plaintext::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the semicolon ';' keyword from the string.
+            let (string, _) = tag(";")(string)?;
+            // Return the global constant.
+            Ok((string, (name, plaintext_type, value)))
+        }
+
+        // A helper to parse a function, along with the length of the string it was parsed from,
+        // and the spans of its instructions relative to that string.
+        #[allow(clippy::type_complexity)]
+        fn parse_function<N: Network>(string: &str) -> ParserResult<'_, (Function<N>, usize, Vec<(usize, usize)>)> {
+            let (remainder, (function, spans)) = Function::parse_with_spans(string)?;
+            Ok((remainder, (function, string.len(), spans)))
+        }
+
+        // Ensure the program string is within the maximum size.
+        if string.len() > N::MAX_PROGRAM_SIZE {
+            return map_res(take(0usize), |_| {
+                let error = anyhow!("Program exceeds the maximum size of {} bytes", N::MAX_PROGRAM_SIZE);
+                eprintln!("{error}");
+                Err(error)
+            })(string);
+        }
+
+        // Retain the input, to compute the source spans of the instructions.
+        let input = string;
+
         // Parse the imports from the string.
         let (string, imports) = many0(Import::parse)(string)?;
         // Parse the whitespace and comments from the string.
@@ -44,14 +100,59 @@ impl<N: Network> Parser for Program<N> {
         // Parse the semicolon ';' keyword from the string.
         let (string, _) = tag(";")(string)?;
 
-        // Parse the struct or function from the string.
-        let (string, components) = many1(alt((
+        // A helper to parse a definition or a function attribute.
+        let mut parse_component = alt((
             map(Mapping::parse, |mapping| P::<N>::M(mapping)),
             map(Struct::parse, |struct_| P::<N>::I(struct_)),
             map(RecordType::parse, |record| P::<N>::R(record)),
             map(Closure::parse, |closure| P::<N>::C(closure)),
-            map(Function::parse, |function| P::<N>::F(function)),
-        )))(string)?;
+            map(parse_function, |(function, length, spans)| {
+                // Offset the spans of the instructions to be relative to the program string.
+                let offset = input.len() - length;
+                P::<N>::F(
+                    Box::new(function),
+                    spans.into_iter().map(|(start, end)| (offset + start, offset + end)).collect(),
+                )
+            }),
+            // Parse a function attribute, i.e. `@{name}`, which applies to the next function.
+            map(
+                |string| {
+                    // Parse the whitespace and comments from the string.
+                    let (string, _) = Sanitizer::parse(string)?;
+                    // Parse the '@' from the string.
+                    let (string, _) = tag("@")(string)?;
+                    // Parse the attribute name from the string.
+                    Identifier::parse(string)
+                },
+                |attribute| P::<N>::A(attribute),
+            ),
+            map(parse_global, |(name, plaintext_type, value)| P::<N>::G(name, plaintext_type, value)),
+        ));
+
+        // Parse the components from the string, ensuring the number of definitions is within the allowed range.
+        let mut string = string;
+        let mut components = Vec::new();
+        let mut num_definitions = 0usize;
+        while let (remaining, Some(component)) = opt(&mut parse_component)(string)? {
+            // Count the definitions, as attributes are not definitions.
+            if !matches!(component, P::A(..)) {
+                num_definitions += 1;
+            }
+            // Stop as soon as the number of definitions exceeds the maximum.
+            if num_definitions > N::MAX_PROGRAM_DEFINITIONS {
+                return map_res(take(0usize), |_| {
+                    let error = anyhow!("Program exceeds the maximum of {} definitions", N::MAX_PROGRAM_DEFINITIONS);
+                    eprintln!("{error}");
+                    Err(error)
+                })(string);
+            }
+            components.push(component);
+            string = remaining;
+        }
+        // Ensure there is at least one component.
+        if components.is_empty() {
+            return fail(string);
+        }
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
 
@@ -66,13 +167,28 @@ impl<N: Network> Parser for Program<N> {
                 }
             };
             // Construct the program with the parsed components.
+            let mut attributes = Vec::new();
             for component in components.iter() {
+                // Ensure the pending attributes precede a function.
+                if !attributes.is_empty() && !matches!(component, P::F(..) | P::A(..)) {
+                    let error = anyhow!("Attributes must precede a function");
+                    eprintln!("{error}");
+                    return Err(error);
+                }
                 let result = match component {
                     P::M(mapping) => program.add_mapping(mapping.clone()),
                     P::I(struct_) => program.add_struct(struct_.clone()),
                     P::R(record) => program.add_record(record.clone()),
                     P::C(closure) => program.add_closure(closure.clone()),
-                    P::F(function) => program.add_function(function.clone()),
+                    P::F(function, spans) => program.add_function(*function.clone()).and_then(|_| {
+                        program.instruction_spans.insert(*function.name(), spans.clone());
+                        program.add_function_attributes(function.name(), std::mem::take(&mut attributes))
+                    }),
+                    P::A(attribute) => {
+                        attributes.push(*attribute);
+                        Ok(())
+                    }
+                    P::G(name, plaintext_type, value) => program.add_global(*name, *plaintext_type, value.clone()),
                 };
 
                 match result {
@@ -83,6 +199,12 @@ impl<N: Network> Parser for Program<N> {
                     }
                 }
             }
+            // Ensure there are no trailing attributes.
+            if !attributes.is_empty() {
+                let error = anyhow!("Attributes must precede a function");
+                eprintln!("{error}");
+                return Err(error);
+            }
             // Lastly, add the imports (if any) to the program.
             for import in imports.iter() {
                 match program.add_import(import.clone()) {
@@ -104,6 +226,12 @@ impl<N: Network> FromStr for Program<N> {
 
     /// Returns a program from a string literal.
     fn from_str(string: &str) -> Result<Self> {
+        // Ensure the program string is within the maximum size.
+        ensure!(
+            string.len() <= N::MAX_PROGRAM_SIZE,
+            "Program exceeds the maximum size of {} bytes",
+            N::MAX_PROGRAM_SIZE
+        );
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
@@ -116,6 +244,61 @@ impl<N: Network> FromStr for Program<N> {
     }
 }
 
+impl<N: Network> Program<N> {
+    /// Returns a program from a string in canonical form, i.e. the form printed by `Display`.
+    ///
+    /// Unlike `from_str`, this method rejects the cosmetic variations tolerated by the parser,
+    /// such as comments, additional blank lines, inconsistent indentation, trailing whitespace,
+    /// and a missing final newline. As such, each program has exactly one accepted textual encoding.
+    ///
+    /// # Errors
+    /// This method will halt if the string is not a valid program.
+    /// This method will halt on the first line that differs from the canonical form.
+    pub fn parse_strict(string: &str) -> Result<Self> {
+        // Parse the program.
+        let program = Self::from_str(string)?;
+        // Print the program in canonical form.
+        let canonical = program.to_string();
+
+        // A helper to compute the indentation of a line.
+        let indentation = |line: &str| line.len() - line.trim_start().len();
+
+        // Ensure each line matches the canonical form.
+        // Note: As the canonical form ends with a newline, its last line is empty.
+        let (mut lines, mut expected_lines) = (string.split('\n'), canonical.split('\n'));
+        for number in 1.. {
+            let (line, expected) = match (lines.next(), expected_lines.next()) {
+                (None, None) => break,
+                (Some(""), None) => bail!("Line {number}: found a blank line after the end of the program"),
+                (Some(line), None) => bail!("Line {number}: expected the end of the program, found '{line}'"),
+                (None, Some("")) => bail!("Line {number}: expected a newline at the end of the program"),
+                (None, Some(expected)) => bail!("Line {number}: expected '{expected}', found the end of the program"),
+                (Some(line), Some(expected)) => (line, expected),
+            };
+
+            if line == expected {
+                continue;
+            } else if expected.is_empty() {
+                bail!("Line {number}: expected a blank line, found '{line}'")
+            } else if line.trim().is_empty() {
+                bail!("Line {number}: expected '{expected}', found a blank line")
+            } else if line.trim_end() == expected {
+                bail!("Line {number}: found trailing whitespace after '{expected}'")
+            } else if line.trim_start() == expected.trim_start() {
+                bail!(
+                    "Line {number}: expected an indentation of {} spaces, found {} characters of indentation in '{}'",
+                    indentation(expected),
+                    indentation(line),
+                    line.trim_start()
+                )
+            } else {
+                bail!("Line {number}: expected '{expected}', found '{line}'")
+            }
+        }
+        Ok(program)
+    }
+}
+
 impl<N: Network> Debug for Program<N> {
     /// Prints the program as a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -174,12 +357,27 @@ impl<N: Network> Display for Program<N> {
                     }
                 },
                 ProgramDefinition::Function => match self.functions.get(identifier) {
-                    Some(function) => program.push_str(&format!("{function}\n\n")),
+                    Some(function) => {
+                        // Print the attributes of the function.
+                        for attribute in self.function_attributes.get(identifier).into_iter().flatten() {
+                            program.push_str(&format!("@{attribute}\n"));
+                        }
+                        program.push_str(&format!("{function}\n\n"))
+                    }
                     None => {
                         eprintln!("Function '{}' is not defined.", identifier);
                         return Err(fmt::Error);
                     }
                 },
+                ProgramDefinition::Global => match self.globals.get(identifier) {
+                    Some((plaintext_type, value)) => {
+                        program.push_str(&format!("global {identifier} as {plaintext_type} = {value};\n\n"))
+                    }
+                    None => {
+                        eprintln!("Global '{}' is not defined.", identifier);
+                        return Err(fmt::Error);
+                    }
+                },
             }
         }
         // Remove the last newline.
@@ -263,4 +461,263 @@ function compute:
 
         Ok(())
     }
+
+    #[test]
+    fn test_program_display_insertion_order() -> Result<()> {
+        // Construct a program by adding a record, then a struct, then a function.
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("ordering.aleo")?)?;
+        program.add_record(RecordType::from_str(
+            r"
+record token:
+    owner as address.private;
+    gates as u64.private;",
+        )?)?;
+        program.add_struct(Struct::from_str(
+            r"
+struct message:
+    first as field;",
+        )?)?;
+        program.add_function(Function::from_str(
+            r"
+function compute:
+    input r0 as message.private;
+    add r0.first r0.first into r1;
+    output r1 as field.private;",
+        )?)?;
+
+        // Ensure the definitions are printed in the order they were added, rather than grouped by kind.
+        let expected = r"program ordering.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+
+struct message:
+    first as field;
+
+function compute:
+    input r0 as message.private;
+    add r0.first r0.first into r1;
+    output r1 as field.private;
+";
+        assert_eq!(program.to_string(), expected);
+
+        // Ensure the order is preserved through the string and byte encodings.
+        assert_eq!(Program::<CurrentNetwork>::from_str(expected)?.to_string(), expected);
+        assert_eq!(Program::<CurrentNetwork>::from_bytes_le(&program.to_bytes_le()?)?.to_string(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_parse_strict() -> Result<()> {
+        let canonical = r"program to_parse.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function compute:
+    input r0 as message.private;
+    add r0.first r0.second into r1;
+    output r1 as field.private;
+";
+        // Ensure a canonically-formatted program parses in strict mode.
+        let program = Program::<CurrentNetwork>::parse_strict(canonical)?;
+        assert_eq!(program, Program::from_str(canonical)?);
+
+        // A helper to check that the given variant is rejected in strict mode, but accepted in lenient mode.
+        let check = |variant: &str, message: &str| {
+            assert_eq!(program, Program::<CurrentNetwork>::from_str(variant).unwrap());
+            let error = Program::<CurrentNetwork>::parse_strict(variant).unwrap_err();
+            assert_eq!(error.to_string(), message);
+        };
+
+        // Ensure doubled blank lines are rejected.
+        check(&canonical.replace("\n\n", "\n\n\n"), "Line 3: expected 'struct message:', found a blank line");
+        // Ensure inconsistent indentation is rejected.
+        check(
+            &canonical.replace("    second", "  second"),
+            "Line 5: expected an indentation of 4 spaces, found 2 characters of indentation in 'second as field;'",
+        );
+        // Ensure trailing whitespace is rejected.
+        check(
+            &canonical.replace("field;\n\n", "field;  \n\n"),
+            "Line 5: found trailing whitespace after '    second as field;'",
+        );
+        // Ensure comments are rejected.
+        check(
+            &canonical.replace("function", "// The compute function.\nfunction"),
+            "Line 7: expected 'function compute:', found '// The compute function.'",
+        );
+        // Ensure a missing final newline is rejected.
+        check(canonical.trim_end(), "Line 11: expected a newline at the end of the program");
+        // Ensure an additional final newline is rejected.
+        check(&format!("{canonical}\n"), "Line 12: found a blank line after the end of the program");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_parse_limits() -> Result<()> {
+        // A helper to construct a program with the given definitions.
+        let program = |definitions: &str| format!("program limits.aleo;\n\n{definitions}");
+
+        // Ensure a program string that exceeds the maximum size is rejected.
+        let padding = format!("// {}\n", "a".repeat(CurrentNetwork::MAX_PROGRAM_SIZE));
+        let error =
+            Program::<CurrentNetwork>::from_str(&program(&format!("{padding}global A as u8 = 1u8;"))).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!("Program exceeds the maximum size of {} bytes", CurrentNetwork::MAX_PROGRAM_SIZE)
+        );
+        assert!(Program::<CurrentNetwork>::parse(&program(&format!("{padding}global A as u8 = 1u8;"))).is_err());
+
+        // Ensure a program with the maximum number of definitions is accepted, and one more is rejected.
+        let globals = |num_globals: usize| {
+            (0..num_globals).map(|i| format!("global G{i} as u8 = 1u8;")).collect::<Vec<_>>().join("\n")
+        };
+        assert!(
+            Program::<CurrentNetwork>::from_str(&program(&globals(CurrentNetwork::MAX_PROGRAM_DEFINITIONS))).is_ok()
+        );
+        assert!(Program::<CurrentNetwork>::from_str(&program(&globals(CurrentNetwork::MAX_PROGRAM_DEFINITIONS + 1)))
+            .is_err());
+        // Ensure the parser rejects the program at the first definition past the maximum,
+        // even if the remaining definitions are well-formed.
+        let excess = format!("{}\n{}", globals(CurrentNetwork::MAX_PROGRAM_DEFINITIONS + 1), "global G as u8 = 1u8;");
+        assert!(Program::<CurrentNetwork>::parse(&program(&excess)).is_err());
+
+        // Ensure a struct with the maximum number of members is accepted, and one more is rejected.
+        let struct_ = |num_members: usize| {
+            let members = (0..num_members).map(|i| format!("    m{i} as field;")).collect::<Vec<_>>();
+            format!("struct wide:\n{}", members.join("\n"))
+        };
+        assert!(Program::<CurrentNetwork>::from_str(&program(&struct_(CurrentNetwork::MAX_DATA_ENTRIES))).is_ok());
+        assert!(Program::<CurrentNetwork>::from_str(&program(&struct_(CurrentNetwork::MAX_DATA_ENTRIES + 1))).is_err());
+
+        // Ensure a function that exceeds the maximum number of instructions is rejected.
+        let instructions = vec!["    add r0 r0 into r1;"; CurrentNetwork::MAX_INSTRUCTIONS + 1];
+        let function = Function::<CurrentNetwork>::from_str(&format!(
+            "function long:\n    input r0 as field.private;\n{}\n    output r1 as field.private;",
+            instructions.join("\n")
+        ))?;
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("limits.aleo")?)?;
+        let error = program.add_function(function).unwrap_err();
+        assert_eq!(error.to_string(), "Function exceeds maximum instructions");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_instruction_span() -> Result<()> {
+        let source = r"import credits.aleo;
+program spans.aleo;
+
+function twice:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    // Multiply the sum by itself.
+    mul   r1 r1   into r2;
+    output r2 as field.private;
+
+closure helper:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as field.private;
+    call helper r0 into r1;
+    output r1 as field.private;";
+        // Parse the program.
+        let program = Program::<CurrentNetwork>::from_str(source)?;
+        let function_name = Identifier::from_str("twice")?;
+
+        // Ensure the span of the second instruction covers its source, excluding the preceding comment.
+        let (start, end) = program.instruction_span(&function_name, 1).unwrap();
+        assert_eq!(&source[start..end], "mul   r1 r1   into r2;");
+        // Ensure the spans of the other instructions cover their source.
+        let (start, end) = program.instruction_span(&function_name, 0).unwrap();
+        assert_eq!(&source[start..end], "add r0 r0 into r1;");
+        let (start, end) = program.instruction_span(&Identifier::from_str("compute")?, 0).unwrap();
+        assert_eq!(&source[start..end], "call helper r0 into r1;");
+
+        // Ensure an out-of-bounds index or an undefined function has no span.
+        assert_eq!(program.instruction_span(&function_name, 2), None);
+        assert_eq!(program.instruction_span(&Identifier::from_str("missing")?, 0), None);
+        // Ensure a program that was not parsed has no spans.
+        assert_eq!(
+            Program::<CurrentNetwork>::read_le(&program.to_bytes_le()?[..])?.instruction_span(&function_name, 0),
+            None
+        );
+        // Ensure the spans do not affect equality.
+        assert_eq!(program, Program::from_str(&program.to_string())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_function_attributes() -> Result<()> {
+        let expected = r"program attributes.aleo;
+
+@view
+@inline
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;
+
+function plain:
+    input r0 as field.public;
+    add r0 r0 into r1;
+    output r1 as field.public;
+";
+        // Parse an annotated program, with comments between the attributes.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program attributes.aleo;
+
+@view // Reads without side effects.
+@inline
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;
+
+function plain:
+    input r0 as field.public;
+    add r0 r0 into r1;
+    output r1 as field.public;",
+        )?;
+
+        // Ensure the attributes are stored.
+        let attributes = [Identifier::from_str("view")?, Identifier::from_str("inline")?];
+        assert_eq!(program.function_attributes(&Identifier::from_str("compute")?)?, attributes);
+        assert!(program.function_attributes(&Identifier::from_str("plain")?)?.is_empty());
+        assert!(program.function_attributes(&Identifier::from_str("missing")?).is_err());
+
+        // Ensure the attributes are re-emitted, and round-trip.
+        assert_eq!(expected, program.to_string());
+        assert_eq!(program, Program::from_str(expected)?);
+        assert_eq!(program, Program::parse_strict(expected)?);
+
+        // Ensure a duplicate attribute is rejected.
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("attributes.aleo")?)?;
+        program.add_function(Function::from_str(
+            "function plain:\n    input r0 as field.public;\n    add r0 r0 into r1;\n    output r1 as field.public;",
+        )?)?;
+        let error = program
+            .add_function_attributes(
+                &Identifier::from_str("plain")?,
+                attributes.iter().chain(&attributes).copied().collect(),
+            )
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Attribute '@view' is declared more than once on function 'plain'");
+
+        // Ensure a trailing attribute is rejected.
+        assert!(Program::<CurrentNetwork>::from_str(&format!("{expected}\n@view")).is_err());
+        // Ensure an attribute on a definition other than a function is rejected.
+        assert!(Program::<CurrentNetwork>::from_str("program attributes.aleo;\n\n@view\nmapping balances:\n    key owner as address.public;\n    value amount as u64.public;").is_err());
+        Ok(())
+    }
 }
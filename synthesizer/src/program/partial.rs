@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Process;
+use console::{account::PrivateKey, program::Register};
+
+/// A function with some of its inputs bound, which may be evaluated on the remaining inputs.
+pub struct PartialEvaluation<'a, N: Network> {
+    /// The process, containing the stack of the program.
+    process: &'a Process<N>,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The inputs of the function, in declared order, where `None` marks an unbound input.
+    inputs: Vec<Option<Value<N>>>,
+}
+
+impl<N: Network> PartialEvaluation<'_, N> {
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the number of inputs that remain to be supplied.
+    pub fn num_remaining_inputs(&self) -> usize {
+        self.inputs.iter().filter(|input| input.is_none()).count()
+    }
+
+    /// Evaluates the function on the bound inputs and the given remaining inputs, and returns its outputs.
+    /// The remaining inputs are supplied in the declared order of the unbound inputs,
+    /// and the function is evaluated by a caller sampled from the given RNG.
+    ///
+    /// # Errors
+    /// This method will halt if the number of remaining inputs is incorrect, or if the evaluation fails.
+    pub fn evaluate<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        remaining: &[Value<N>],
+        rng: &mut R,
+    ) -> Result<Vec<Value<N>>> {
+        // Ensure the number of remaining inputs is correct.
+        ensure!(
+            remaining.len() == self.num_remaining_inputs(),
+            "Function '{}' expects {} remaining input(s), but {} were given",
+            self.function_name,
+            self.num_remaining_inputs(),
+            remaining.len()
+        );
+
+        // Fill in the unbound inputs, in order.
+        let mut remaining = remaining.iter();
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| match input {
+                Some(value) => Ok(value.clone()),
+                None => remaining.next().cloned().ok_or_else(|| {
+                    anyhow!("Function '{}' is missing a remaining input", self.function_name)
+                }),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Sample a random caller.
+        let private_key = PrivateKey::new(rng)?;
+        // Authorize the function call.
+        let authorization =
+            self.process.authorize::<A, _>(&private_key, self.program_id, self.function_name, inputs.iter(), rng)?;
+        // Evaluate the function.
+        let response = self.process.evaluate::<A>(authorization)?;
+        Ok(response.outputs().to_vec())
+    }
+}
+
+impl<N: Network> Program<N> {
+    /// Binds the given inputs of the function with the given name, and returns a partial evaluation
+    /// that may be evaluated on the remaining inputs. The bound inputs are keyed by their input register.
+    ///
+    /// The partial evaluation is evaluated in the given process, which must contain this program.
+    ///
+    /// # Errors
+    /// This method will halt if the process does not contain this program.
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if a bound register is not an input of the function.
+    /// This method will halt if a bound value does not match the declared type of its input.
+    pub fn partial<'a>(
+        &self,
+        process: &'a Process<N>,
+        function_name: &Identifier<N>,
+        bound: &IndexMap<Register<N>, Value<N>>,
+    ) -> Result<PartialEvaluation<'a, N>> {
+        // Retrieve the stack and the function.
+        let stack = self.get_stack_in(process)?;
+        let function = stack.get_function(function_name)?;
+
+        // Ensure every bound register is an input of the function.
+        for register in bound.keys() {
+            ensure!(
+                function.inputs().iter().any(|input| input.register() == register),
+                "Register '{register}' is not an input of function '{function_name}'"
+            );
+        }
+
+        // Collect the inputs in declared order, ensuring each bound value matches its declared type.
+        let inputs = function
+            .inputs()
+            .iter()
+            .map(|input| match bound.get(input.register()) {
+                Some(value) => {
+                    stack.matches_value_type(value, input.value_type())?;
+                    Ok(Some(value.clone()))
+                }
+                None => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(PartialEvaluation { process, program_id: *self.id(), function_name: *function_name, inputs })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::network::AleoV0;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_program_partial() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program partial.aleo;
+
+function sum:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+        let function_name = Identifier::from_str("sum")?;
+        let process = program.to_process()?;
+        let rng = &mut TestRng::default();
+
+        // Bind the first input, and evaluate on the second.
+        let bound = IndexMap::from([(Register::from_str("r0")?, Value::from_str("2field")?)]);
+        let partial = program.partial(&process, &function_name, &bound)?;
+        assert_eq!(partial.num_remaining_inputs(), 1);
+        assert_eq!(partial.evaluate::<CurrentAleo, _>(&[Value::from_str("3field")?], rng)?, vec![Value::from_str(
+            "5field"
+        )?]);
+        // Ensure the partial evaluation is reusable.
+        assert_eq!(partial.evaluate::<CurrentAleo, _>(&[Value::from_str("5field")?], rng)?, vec![Value::from_str(
+            "7field"
+        )?]);
+        // Ensure the evaluation fails on the wrong number of remaining inputs.
+        assert!(partial.evaluate::<CurrentAleo, _>(&[], rng).is_err());
+
+        // Bind the second input, and evaluate on the first.
+        let bound = IndexMap::from([(Register::from_str("r1")?, Value::from_str("3field")?)]);
+        let partial = program.partial(&process, &function_name, &bound)?;
+        assert_eq!(partial.evaluate::<CurrentAleo, _>(&[Value::from_str("4field")?], rng)?, vec![Value::from_str(
+            "7field"
+        )?]);
+
+        // Ensure a register that is not an input cannot be bound.
+        let bound = IndexMap::from([(Register::from_str("r2")?, Value::from_str("2field")?)]);
+        assert!(program.partial(&process, &function_name, &bound).is_err());
+        // Ensure a value of the wrong type cannot be bound.
+        let bound = IndexMap::from([(Register::from_str("r0")?, Value::from_str("2u8")?)]);
+        assert!(program.partial(&process, &function_name, &bound).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Process;
+use console::{account::PrivateKey, program::Record};
+
+/// A source of records that are known to exist, such as a ledger, keyed by their record commitment.
+pub trait RecordProvider<N: Network> {
+    /// Returns the record with the given commitment.
+    ///
+    /// # Errors
+    /// This method will halt if the record does not exist.
+    fn get_record(&self, commitment: &Field<N>) -> Result<Record<N, Plaintext<N>>>;
+
+    /// Returns `true` if a record with the given commitment exists.
+    fn contains(&self, commitment: &Field<N>) -> bool;
+}
+
+/// An input to a function evaluated against a record provider.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProviderInput<'a, N: Network> {
+    /// The input value.
+    Value(&'a Value<N>),
+    /// The commitment of an input record, which is resolved through the record provider.
+    Commitment(Field<N>),
+}
+
+impl<N: Network> Program<N> {
+    /// Evaluates the function with the given name on the given inputs, by the given caller, and returns its outputs.
+    /// Each input record given by its commitment is resolved through the given record provider.
+    ///
+    /// The function is evaluated in the given process, which must contain this program.
+    ///
+    /// # Errors
+    /// This method will halt if the process does not contain this program.
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if the number of inputs does not match the function.
+    /// This method will halt if a commitment is given for an input that is not a (local) record.
+    /// This method will halt if a commitment is not in the record provider, or if the provided record does not match it.
+    /// This method will halt if the inputs do not match the function, or if the evaluation fails.
+    pub fn evaluate_with_provider<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        process: &Process<N>,
+        private_key: &PrivateKey<N>,
+        function_name: &Identifier<N>,
+        inputs: &[ProviderInput<'_, N>],
+        provider: &dyn RecordProvider<N>,
+        rng: &mut R,
+    ) -> Result<Vec<Value<N>>> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+        // Ensure the number of inputs matches the function.
+        ensure!(
+            function.inputs().len() == inputs.len(),
+            "Function '{function_name}' expects {} input(s), but {} were given",
+            function.inputs().len(),
+            inputs.len()
+        );
+
+        // Resolve the inputs.
+        let inputs = function
+            .input_types()
+            .iter()
+            .zip_eq(inputs)
+            .map(|(input_type, input)| match (input, input_type) {
+                (ProviderInput::Value(value), _) => Ok((*value).clone()),
+                (ProviderInput::Commitment(commitment), ValueType::Record(record_name)) => {
+                    // Ensure the record exists in the provider.
+                    ensure!(provider.contains(commitment), "Record '{commitment}' does not exist in the provider");
+                    // Retrieve the record, and ensure it matches the commitment.
+                    let record = provider.get_record(commitment)?;
+                    ensure!(
+                        self.record_commitment(record_name, &record)? == *commitment,
+                        "Record '{commitment}' from the provider does not match its commitment"
+                    );
+                    Ok(Value::from(record))
+                }
+                (ProviderInput::Commitment(commitment), _) => {
+                    bail!("Record '{commitment}' was given for input of type '{input_type}', which is not a record")
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Evaluate the function.
+        self.evaluate_as::<A, R>(process, private_key, function_name, &inputs, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::network::AleoV0;
+    use console::{account::Address, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// An in-memory record provider.
+    struct MemoryProvider(IndexMap<Field<CurrentNetwork>, Record<CurrentNetwork, Plaintext<CurrentNetwork>>>);
+
+    impl RecordProvider<CurrentNetwork> for MemoryProvider {
+        fn get_record(
+            &self,
+            commitment: &Field<CurrentNetwork>,
+        ) -> Result<Record<CurrentNetwork, Plaintext<CurrentNetwork>>> {
+            self.0.get(commitment).cloned().ok_or_else(|| anyhow!("Record '{commitment}' does not exist"))
+        }
+
+        fn contains(&self, commitment: &Field<CurrentNetwork>) -> bool {
+            self.0.contains_key(commitment)
+        }
+    }
+
+    #[test]
+    fn test_program_evaluate_with_provider() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program provider.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    token_amount as u64.private;
+
+function spend:
+    input r0 as token.record;
+    input r1 as u64.private;
+    add r0.token_amount r1 into r2;
+    output r2 as u64.private;",
+        )?;
+        let function_name = Identifier::from_str("spend")?;
+        let token = Identifier::from_str("token")?;
+
+        let process = program.to_process()?;
+        let rng = &mut TestRng::default();
+
+        // Initialize the caller, and a record they own.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let caller = Address::try_from(&private_key)?;
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {caller}.private, gates: 5u64.private, token_amount: 100u64.private, _nonce: 0group.public }}"
+        ))?;
+        let commitment = program.record_commitment(&token, &record)?;
+
+        // Initialize the provider.
+        let provider = MemoryProvider(IndexMap::from([(commitment, record)]));
+
+        // Ensure the record is resolved by its commitment.
+        let amount = Value::from_str("3u64")?;
+        let inputs = [ProviderInput::Commitment(commitment), ProviderInput::Value(&amount)];
+        let outputs = program.evaluate_with_provider::<CurrentAleo, _>(
+            &process,
+            &private_key,
+            &function_name,
+            &inputs,
+            &provider,
+            rng,
+        )?;
+        assert_eq!(outputs, vec![Value::from_str("103u64")?]);
+
+        // Ensure an unknown commitment is rejected.
+        let inputs = [ProviderInput::Commitment(Field::from_u64(1)), ProviderInput::Value(&amount)];
+        assert!(program
+            .evaluate_with_provider::<CurrentAleo, _>(&process, &private_key, &function_name, &inputs, &provider, rng)
+            .is_err());
+        // Ensure a commitment for a non-record input is rejected.
+        let inputs = [ProviderInput::Commitment(commitment), ProviderInput::Commitment(commitment)];
+        assert!(program
+            .evaluate_with_provider::<CurrentAleo, _>(&process, &private_key, &function_name, &inputs, &provider, rng)
+            .is_err());
+        // Ensure a record that does not match its commitment is rejected.
+        let other = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {caller}.private, gates: 5u64.private, token_amount: 999u64.private, _nonce: 0group.public }}"
+        ))?;
+        let provider = MemoryProvider(IndexMap::from([(commitment, other)]));
+        let inputs = [ProviderInput::Commitment(commitment), ProviderInput::Value(&amount)];
+        assert!(program
+            .evaluate_with_provider::<CurrentAleo, _>(&process, &private_key, &function_name, &inputs, &provider, rng)
+            .is_err());
+        Ok(())
+    }
+}
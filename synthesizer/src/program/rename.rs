@@ -0,0 +1,510 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::program::Register;
+
+impl<N: Network> Program<N> {
+    /// Renames the member `old_member` of the struct or record `type_name` to `new_member`,
+    /// and updates the register members, in all closures and functions, that access it.
+    /// The values of global constants of the given type are updated accordingly.
+    ///
+    /// # Errors
+    /// This method will halt if `type_name` is not a struct or record in the program.
+    /// This method will halt if `old_member` is not a member of the struct or record.
+    /// This method will halt if `new_member` is already a member of the struct or record.
+    /// This method will halt if `new_member` is a reserved keyword.
+    pub fn rename_member(
+        &mut self,
+        type_name: &Identifier<N>,
+        old_member: &Identifier<N>,
+        new_member: &Identifier<N>,
+    ) -> Result<()> {
+        // Ensure the new member name is not a reserved keyword.
+        ensure!(!Self::is_reserved_keyword(new_member), "'{new_member}' is a reserved keyword.");
+
+        // A helper to rename the member in the lines of a struct or record definition.
+        let rename_definition = |definition: String| {
+            definition
+                .split('\n')
+                .map(|line| match line.strip_prefix(&format!("    {old_member} as ")) {
+                    Some(rest) => format!("    {new_member} as {rest}"),
+                    None => line.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        // Rename the member in the struct or record definition.
+        let (struct_, record) = match (self.structs.get(type_name), self.records.get(type_name)) {
+            (Some(struct_), _) => {
+                ensure!(struct_.members().contains_key(old_member), "'{old_member}' is not a member of '{type_name}'");
+                ensure!(!struct_.members().contains_key(new_member), "'{new_member}' is already in '{type_name}'");
+                (Some(Struct::from_str(&rename_definition(struct_.to_string()))?), None)
+            }
+            (None, Some(record)) => {
+                ensure!(record.entries().contains_key(old_member), "'{old_member}' is not an entry of '{type_name}'");
+                ensure!(!record.entries().contains_key(new_member), "'{new_member}' is already in '{type_name}'");
+                (None, Some(RecordType::from_str(&rename_definition(record.to_string()))?))
+            }
+            (None, None) => bail!("'{type_name}' is not a struct or record in the program"),
+        };
+
+        // Construct the updated closures.
+        let closures = self
+            .closures
+            .values()
+            .map(|closure| {
+                // Determine the renamed registers of the closure.
+                let inputs = closure.inputs().iter().map(|input| (input.register().clone(), *input.register_type()));
+                let registers = closure
+                    .instructions()
+                    .iter()
+                    .flat_map(Instruction::operands)
+                    .filter_map(|operand| match operand {
+                        Operand::Register(register) => Some(register.clone()),
+                        _ => None,
+                    })
+                    .chain(closure.outputs().iter().map(|output| output.register().clone()));
+                let renamed = self.renamed_registers(
+                    inputs.collect(),
+                    closure.instructions(),
+                    registers,
+                    (type_name, old_member, new_member),
+                );
+
+                // Reconstruct the closure with the renamed registers.
+                let renamed = closure.rename_registers(&mut rename_with(&renamed))?;
+                Ok((*closure.name(), renamed))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Construct the updated functions.
+        let functions = self
+            .functions
+            .values()
+            .map(|function| {
+                // Determine the renamed registers of the function.
+                let inputs = function
+                    .inputs()
+                    .iter()
+                    .map(|input| (input.register().clone(), RegisterType::from(*input.value_type())));
+                let registers = function
+                    .instructions()
+                    .iter()
+                    .flat_map(Instruction::operands)
+                    .chain(function.finalize_command().map(|command| command.operands()).unwrap_or_default())
+                    .filter_map(|operand| match operand {
+                        Operand::Register(register) => Some(register.clone()),
+                        _ => None,
+                    })
+                    .chain(function.outputs().iter().filter_map(|output| output.register().cloned()));
+                let renamed = self.renamed_registers(
+                    inputs.collect(),
+                    function.instructions(),
+                    registers,
+                    (type_name, old_member, new_member),
+                );
+
+                // Reconstruct the function with the renamed registers.
+                let mut rename = rename_with(&renamed);
+                let inputs =
+                    function.inputs().iter().map(|input| input.rename_registers(&mut rename)).collect::<Result<_>>()?;
+                let instructions = function
+                    .instructions()
+                    .iter()
+                    .map(|instruction| instruction.rename_registers(&mut rename))
+                    .collect::<Result<_>>()?;
+                let outputs = function
+                    .outputs()
+                    .iter()
+                    .map(|output| output.rename_registers(&mut rename))
+                    .collect::<Result<_>>()?;
+
+                // Reconstruct the finalize scope with the renamed registers.
+                let finalize = match function.finalize() {
+                    Some((command, finalize)) => {
+                        // Determine the renamed registers of the finalize scope.
+                        let inputs = finalize
+                            .inputs()
+                            .iter()
+                            .map(|input| (input.register().clone(), RegisterType::from(*input.finalize_type())));
+                        let registers = finalize
+                            .commands()
+                            .iter()
+                            .flat_map(|command| match command {
+                                Command::Decrement(decrement) => decrement.operands(),
+                                Command::Increment(increment) => increment.operands(),
+                                Command::Instruction(instruction) => instruction.operands().to_vec(),
+                            })
+                            .filter_map(|operand| match operand {
+                                Operand::Register(register) => Some(register),
+                                _ => None,
+                            })
+                            .chain(finalize.outputs().iter().map(|output| output.register().clone()));
+                        let finalize_renamed = self.renamed_registers(
+                            inputs.collect(),
+                            &[],
+                            registers,
+                            (type_name, old_member, new_member),
+                        );
+
+                        let finalize = finalize.rename_registers(&mut rename_with(&finalize_renamed))?;
+                        Some((command.rename_registers(&mut rename)?, finalize))
+                    }
+                    None => None,
+                };
+                Ok((*function.name(), function.reconstruct(inputs, instructions, outputs, finalize)?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Construct the updated global constants.
+        let globals = self
+            .globals
+            .iter()
+            .map(|(name, (plaintext_type, value))| {
+                let value = self.rename_plaintext(plaintext_type, value, (type_name, old_member, new_member));
+                (*name, (*plaintext_type, value))
+            })
+            .collect::<Vec<_>>();
+
+        // Update the program.
+        if let Some(struct_) = struct_ {
+            self.structs.insert(*type_name, struct_);
+        }
+        if let Some(record) = record {
+            self.records.insert(*type_name, record);
+        }
+        closures.into_iter().for_each(|(name, closure)| {
+            self.closures.insert(name, closure);
+        });
+        functions.into_iter().for_each(|(name, function)| {
+            // Discard the source spans of the function, if its instructions changed.
+            if self.functions.get(&name) != Some(&function) {
+                self.instruction_spans.shift_remove(&name);
+            }
+            self.functions.insert(name, function);
+        });
+        globals.into_iter().for_each(|(name, global)| {
+            self.globals.insert(name, global);
+        });
+        Ok(())
+    }
+
+    /// Returns a map from each register in the given registers that accesses the member
+    /// `(type_name, old_member)` to the register that accesses `(type_name, new_member)` instead.
+    fn renamed_registers(
+        &self,
+        inputs: Vec<(Register<N>, RegisterType<N>)>,
+        instructions: &[Instruction<N>],
+        registers: impl Iterator<Item = Register<N>>,
+        (type_name, old_member, new_member): (&Identifier<N>, &Identifier<N>, &Identifier<N>),
+    ) -> IndexMap<Register<N>, Register<N>> {
+        // Determine the types of the registers that may contain a struct or record.
+        let mut types = inputs
+            .into_iter()
+            .map(|(register, register_type)| (register.locator(), register_type))
+            .collect::<IndexMap<_, _>>();
+        for instruction in instructions {
+            match instruction {
+                // The destination of a cast is of the cast type.
+                Instruction::Cast(cast) => {
+                    if let Some(destination) = cast.destinations().first() {
+                        types.insert(destination.locator(), *cast.register_type());
+                    }
+                }
+                // The destinations of a call to a local closure are of the closure output types.
+                Instruction::Call(call) => {
+                    if let CallOperator::Resource(resource) = call.operator() {
+                        if let Some(closure) = self.closures.get(resource) {
+                            for (destination, output) in call.destinations().iter().zip(closure.outputs()) {
+                                types.insert(destination.locator(), *output.register_type());
+                            }
+                        }
+                    }
+                }
+                // The destination of a ternary is of the type of its first branch.
+                Instruction::Ternary(ternary) => {
+                    if let (Some(Operand::Register(register)), Some(destination)) =
+                        (ternary.operands().get(1), ternary.destinations().first())
+                    {
+                        if let Some(register_type) = self.register_member_type(&types, register) {
+                            types.insert(destination.locator(), register_type);
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        // Rename the member in each register that accesses it.
+        let mut renamed = IndexMap::new();
+        for register in registers {
+            if let Register::Member(locator, path) = &register {
+                // Retrieve the name of the struct or record in the register.
+                let mut current = match types.get(locator) {
+                    Some(RegisterType::Plaintext(PlaintextType::Struct(name)) | RegisterType::Record(name)) => {
+                        Some(*name)
+                    }
+                    _ => None,
+                };
+                // Rename the member in the path.
+                let mut new_path = Vec::with_capacity(path.len());
+                for member in path {
+                    new_path.push(match current == Some(*type_name) && member == old_member {
+                        true => *new_member,
+                        false => *member,
+                    });
+                    current = current.and_then(|name| self.member_struct_name(&name, member));
+                }
+                if &new_path != path {
+                    renamed.insert(register.clone(), Register::Member(*locator, new_path));
+                }
+            }
+        }
+        renamed
+    }
+
+    /// Returns the type of the given register, if it is known from the given register types.
+    fn register_member_type(
+        &self,
+        types: &IndexMap<u64, RegisterType<N>>,
+        register: &Register<N>,
+    ) -> Option<RegisterType<N>> {
+        let register_type = *types.get(&register.locator())?;
+        match register {
+            Register::Locator(..) => Some(register_type),
+            Register::Member(_, path) => {
+                // Retrieve the name of the struct or record in the register.
+                let mut current = match register_type {
+                    RegisterType::Plaintext(PlaintextType::Struct(name)) | RegisterType::Record(name) => name,
+                    _ => return None,
+                };
+                // Retrieve the struct name of each member in the path, except the last.
+                for member in &path[..path.len() - 1] {
+                    current = self.member_struct_name(&current, member)?;
+                }
+                let member = path.last()?;
+                match (self.structs.get(&current), self.records.get(&current)) {
+                    (Some(struct_), _) => Some(RegisterType::Plaintext(*struct_.members().get(member)?)),
+                    (None, Some(record)) => match record.entries().get(member)? {
+                        EntryType::Constant(plaintext_type)
+                        | EntryType::Public(plaintext_type)
+                        | EntryType::Private(plaintext_type) => Some(RegisterType::Plaintext(*plaintext_type)),
+                    },
+                    (None, None) => None,
+                }
+            }
+        }
+    }
+
+    /// Returns the name of the struct of the given member in the given struct or record, if it is a struct.
+    fn member_struct_name(&self, name: &Identifier<N>, member: &Identifier<N>) -> Option<Identifier<N>> {
+        let plaintext_type = match (self.structs.get(name), self.records.get(name)) {
+            (Some(struct_), _) => *struct_.members().get(member)?,
+            (None, Some(record)) => match record.entries().get(member)? {
+                EntryType::Constant(plaintext_type)
+                | EntryType::Public(plaintext_type)
+                | EntryType::Private(plaintext_type) => *plaintext_type,
+            },
+            (None, None) => return None,
+        };
+        match plaintext_type {
+            PlaintextType::Literal(..) => None,
+            PlaintextType::Struct(struct_name) => Some(struct_name),
+        }
+    }
+
+    /// Returns the given plaintext of the given type, with the member `(type_name, old_member)` renamed to `new_member`.
+    fn rename_plaintext(
+        &self,
+        plaintext_type: &PlaintextType<N>,
+        plaintext: &Plaintext<N>,
+        (type_name, old_member, new_member): (&Identifier<N>, &Identifier<N>, &Identifier<N>),
+    ) -> Plaintext<N> {
+        match (plaintext_type, plaintext) {
+            (PlaintextType::Struct(struct_name), Plaintext::Struct(members, _)) => {
+                let members = members
+                    .iter()
+                    .map(|(member, value)| {
+                        // Rename the member of the value, recursing through nested structs.
+                        let value = match self.structs.get(struct_name).and_then(|s| s.members().get(member)) {
+                            Some(member_type) => {
+                                self.rename_plaintext(member_type, value, (type_name, old_member, new_member))
+                            }
+                            None => value.clone(),
+                        };
+                        match struct_name == type_name && member == old_member {
+                            true => (*new_member, value),
+                            false => (*member, value),
+                        }
+                    })
+                    .collect();
+                Plaintext::Struct(members, Default::default())
+            }
+            _ => plaintext.clone(),
+        }
+    }
+}
+
+/// Returns a function that replaces each register in `renamed`, and retains the other registers,
+/// for use with the `rename_registers` methods of the program components.
+pub(super) fn rename_with<N: Network>(
+    renamed: &IndexMap<Register<N>, Register<N>>,
+) -> impl FnMut(&Register<N>) -> Result<Operand<N>> + '_ {
+    |register| Ok(Operand::Register(renamed.get(register).unwrap_or(register).clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_rename_member() -> Result<()> {
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r"
+program rename.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+struct envelope:
+    first as message;
+
+global ORIGIN as message = { first: 0field, second: 1field };
+
+closure unwrap:
+    input r0 as envelope;
+    add r0.first.first r0.first.second into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as message.private;
+    input r1 as field.private;
+    add r0.first r0.second into r2;
+    cast r2 r1 into r3 as message;
+    add r3.first r1 into r4;
+    cast r3 into r5 as envelope;
+    call unwrap r5 into r6;
+    output r3.first as field.private;",
+        )?;
+
+        // Rename the member.
+        program.rename_member(
+            &Identifier::from_str("message")?,
+            &Identifier::from_str("first")?,
+            &Identifier::from_str("alpha")?,
+        )?;
+
+        let expected = r"program rename.aleo;
+
+struct message:
+    alpha as field;
+    second as field;
+
+struct envelope:
+    first as message;
+
+global ORIGIN as message = {
+  alpha: 0field,
+  second: 1field
+};
+
+closure unwrap:
+    input r0 as envelope;
+    add r0.first.alpha r0.first.second into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as message.private;
+    input r1 as field.private;
+    add r0.alpha r0.second into r2;
+    cast r2 r1 into r3 as message;
+    add r3.alpha r1 into r4;
+    cast r3 into r5 as envelope;
+    call unwrap r5 into r6;
+    output r3.alpha as field.private;
+";
+        assert_eq!(program.to_string(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_member_string_literal() -> Result<()> {
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r#"
+program rename.aleo;
+
+struct message:
+    first as string;
+    second as field;
+
+function compute:
+    input r0 as message.private;
+    is.eq r0.first " r0.first " into r1;
+    output r1 as boolean.private;"#,
+        )?;
+
+        // Rename the member.
+        program.rename_member(
+            &Identifier::from_str("message")?,
+            &Identifier::from_str("first")?,
+            &Identifier::from_str("alpha")?,
+        )?;
+
+        // Ensure the register is renamed, but the string literal that spells it is not.
+        let compute = program.get_function(&Identifier::from_str("compute")?)?;
+        assert_eq!(compute.instructions()[0].to_string(), r#"is.eq r0.alpha " r0.first " into r1;"#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_member_fails() -> Result<()> {
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r"
+program rename.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function compute:
+    input r0 as message.private;
+    add r0.first r0.second into r1;
+    output r1 as field.private;",
+        )?;
+        let message = Identifier::from_str("message")?;
+        let first = Identifier::from_str("first")?;
+
+        // Ensure a collision with an existing member is rejected.
+        assert!(program.rename_member(&message, &first, &Identifier::from_str("second")?).is_err());
+        // Ensure a missing member is rejected.
+        assert!(program.rename_member(&message, &Identifier::from_str("third")?, &first).is_err());
+        // Ensure a missing type is rejected.
+        assert!(program.rename_member(&Identifier::from_str("missing")?, &first, &first).is_err());
+        // Ensure a reserved keyword is rejected.
+        assert!(program.rename_member(&message, &first, &Identifier::from_str("gates")?).is_err());
+
+        // Ensure the program is unchanged.
+        assert_eq!(program, Program::from_str(&program.to_string())?);
+        assert!(program.get_struct(&message)?.members().contains_key(&first));
+        Ok(())
+    }
+}
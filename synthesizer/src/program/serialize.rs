@@ -16,6 +16,121 @@
 
 use super::*;
 
+use serde_json::{json, Value as JsonValue};
+
+impl<N: Network> Program<N> {
+    /// Returns a structured JSON representation of the program, for consumption by non-Rust frontends.
+    ///
+    /// Unlike the `Serialize` implementation, which serializes the program into its source string,
+    /// this representation describes each definition as an object. It is not intended to be deserialized.
+    pub fn to_json(&self) -> JsonValue {
+        // Returns the JSON representation of an instruction.
+        let instruction_to_json = |instruction: &Instruction<N>| {
+            json!({
+                "opcode": instruction.opcode().to_string(),
+                "operands": instruction.operands().iter().map(ToString::to_string).collect::<Vec<_>>(),
+                "destinations": instruction.destinations().iter().map(ToString::to_string).collect::<Vec<_>>(),
+                "instruction": instruction.to_string(),
+            })
+        };
+
+        let mappings = self.mappings.values().map(|mapping| {
+            json!({
+                "name": mapping.name().to_string(),
+                "key": {
+                    "name": mapping.key().name().to_string(),
+                    "type": mapping.key().finalize_type().to_string(),
+                },
+                "value": {
+                    "name": mapping.value().name().to_string(),
+                    "type": mapping.value().finalize_type().to_string(),
+                },
+            })
+        });
+
+        let structs = self.structs.values().map(|struct_| {
+            json!({
+                "name": struct_.name().to_string(),
+                "members": struct_.members().iter().map(|(name, plaintext_type)| {
+                    json!({ "name": name.to_string(), "type": plaintext_type.to_string() })
+                }).collect::<Vec<_>>(),
+            })
+        });
+
+        let records = self.records.values().map(|record| {
+            json!({
+                "name": record.name().to_string(),
+                "owner": { "type": "address", "mode": record.owner().to_string() },
+                "gates": { "type": "u64", "mode": record.gates().to_string() },
+                "entries": record.entries().iter().map(|(name, entry_type)| {
+                    let (plaintext_type, mode) = match entry_type {
+                        EntryType::Constant(plaintext_type) => (plaintext_type, "constant"),
+                        EntryType::Public(plaintext_type) => (plaintext_type, "public"),
+                        EntryType::Private(plaintext_type) => (plaintext_type, "private"),
+                    };
+                    json!({ "name": name.to_string(), "type": plaintext_type.to_string(), "mode": mode })
+                }).collect::<Vec<_>>(),
+            })
+        });
+
+        let globals = self.globals.iter().map(|(name, (plaintext_type, value))| {
+            json!({ "name": name.to_string(), "type": plaintext_type.to_string(), "value": value.to_string() })
+        });
+
+        let closures = self.closures.values().map(|closure| {
+            json!({
+                "name": closure.name().to_string(),
+                "inputs": closure.inputs().iter().map(|input| {
+                    json!({ "register": input.register().to_string(), "type": input.register_type().to_string() })
+                }).collect::<Vec<_>>(),
+                "instructions": closure.instructions().iter().map(instruction_to_json).collect::<Vec<_>>(),
+                "outputs": closure.outputs().iter().map(|output| {
+                    json!({ "register": output.register().to_string(), "type": output.register_type().to_string() })
+                }).collect::<Vec<_>>(),
+            })
+        });
+
+        let functions = self.functions.values().map(|function| {
+            // Construct the finalize scope, if one exists.
+            let finalize = function.finalize().map(|(command, finalize)| {
+                json!({
+                    "operands": command.operands().iter().map(ToString::to_string).collect::<Vec<_>>(),
+                    "inputs": finalize.inputs().iter().map(|input| {
+                        json!({ "register": input.register().to_string(), "type": input.finalize_type().to_string() })
+                    }).collect::<Vec<_>>(),
+                    "commands": finalize.commands().iter().map(ToString::to_string).collect::<Vec<_>>(),
+                    "outputs": finalize.outputs().iter().map(|output| {
+                        json!({ "register": output.register().to_string(), "type": output.finalize_type().to_string() })
+                    }).collect::<Vec<_>>(),
+                })
+            });
+            json!({
+                "name": function.name().to_string(),
+                "inputs": function.inputs().iter().map(|input| {
+                    json!({ "register": input.register().to_string(), "type": input.value_type().to_string() })
+                }).collect::<Vec<_>>(),
+                "instructions": function.instructions().iter().map(instruction_to_json).collect::<Vec<_>>(),
+                "outputs": function.outputs().iter().map(|output| match output.literal() {
+                    Some(literal) => json!({ "literal": literal.to_string(), "type": output.value_type().to_string() }),
+                    None => json!({ "register": output.operand().to_string(), "type": output.value_type().to_string() }),
+                }).collect::<Vec<_>>(),
+                "finalize": finalize,
+            })
+        });
+
+        json!({
+            "id": self.id.to_string(),
+            "imports": self.imports.keys().map(ToString::to_string).collect::<Vec<_>>(),
+            "mappings": mappings.collect::<Vec<_>>(),
+            "structs": structs.collect::<Vec<_>>(),
+            "records": records.collect::<Vec<_>>(),
+            "globals": globals.collect::<Vec<_>>(),
+            "closures": closures.collect::<Vec<_>>(),
+            "functions": functions.collect::<Vec<_>>(),
+        })
+    }
+}
+
 impl<N: Network> Serialize for Program<N> {
     /// Serializes the program into string or bytes.
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -71,6 +186,48 @@ function compute:
         Ok(())
     }
 
+    #[test]
+    fn test_to_json() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program to_parse.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function compute:
+    input r0 as message.private;
+    add r0.first r0.second into r1;
+    output r1 as field.private;
+",
+        )?;
+        let json = program.to_json();
+
+        // Ensure the top-level keys are present.
+        assert_eq!(json["id"], "to_parse.aleo");
+        for key in ["imports", "mappings", "structs", "records", "globals", "closures", "functions"] {
+            assert!(json[key].is_array(), "Missing key '{key}'");
+        }
+
+        // Ensure the struct is described by its members.
+        let message = &json["structs"][0];
+        assert_eq!(message["name"], "message");
+        assert_eq!(message["members"][0]["name"], "first");
+        assert_eq!(message["members"][1]["type"], "field");
+
+        // Ensure the function is described by its typed inputs, instructions, and outputs.
+        let compute = &json["functions"][0];
+        assert_eq!(compute["name"], "compute");
+        assert_eq!(compute["inputs"][0]["register"], "r0");
+        assert_eq!(compute["inputs"][0]["type"], "message.private");
+        assert_eq!(compute["instructions"][0]["opcode"], "add");
+        assert_eq!(compute["instructions"][0]["operands"][1], "r0.second");
+        assert_eq!(compute["instructions"][0]["destinations"][0], "r1");
+        assert_eq!(compute["outputs"][0]["type"], "field.private");
+        assert!(compute["finalize"].is_null());
+        Ok(())
+    }
+
     #[test]
     fn test_bincode() -> Result<()> {
         let program_string = r"program to_parse.aleo;
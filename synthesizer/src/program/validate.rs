@@ -0,0 +1,499 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The bounds and rules that a network imposes on its programs.
+///
+/// Note: The checks read the limits from this struct, rather than from `Network` directly,
+/// so that each limit can be checked against a value other than that of the network.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct NetworkLimits {
+    /// The maximum number of definitions in a program.
+    pub(crate) max_program_definitions: usize,
+    /// The maximum number of functions in a program.
+    pub(crate) max_functions: usize,
+    /// The maximum number of inputs of a closure or function.
+    pub(crate) max_inputs: usize,
+    /// The maximum number of instructions of a function.
+    pub(crate) max_instructions: usize,
+    /// The maximum number of outputs of a closure or function.
+    pub(crate) max_outputs: usize,
+    /// The maximum number of record outputs of a function.
+    pub(crate) max_output_records: usize,
+    /// The maximum number of operands of an instruction.
+    pub(crate) max_operands: usize,
+    /// The maximum nesting depth of a struct.
+    pub(crate) max_data_depth: usize,
+    /// The maximum payload size of a record, in fields.
+    pub(crate) max_data_size_in_fields: u32,
+    /// If `true`, the owner of every record must be private.
+    pub(crate) require_private_owner: bool,
+    /// If `true`, a function may not output one of its input registers.
+    pub(crate) strict_output_registers: bool,
+    /// If `true`, a function must have an output or a side effect.
+    pub(crate) require_outputs: bool,
+    /// Returns `true` if the given opcode is allowed.
+    pub(crate) is_opcode_allowed: fn(&str) -> bool,
+}
+
+impl NetworkLimits {
+    /// Returns the limits defined by the given network.
+    pub(crate) fn of<N: Network>() -> Self {
+        Self {
+            max_program_definitions: N::MAX_PROGRAM_DEFINITIONS,
+            max_functions: N::MAX_FUNCTIONS,
+            max_inputs: N::MAX_INPUTS,
+            max_instructions: N::MAX_INSTRUCTIONS,
+            max_outputs: N::MAX_OUTPUTS,
+            max_output_records: N::MAX_OUTPUT_RECORDS,
+            max_operands: N::MAX_OPERANDS,
+            max_data_depth: N::MAX_DATA_DEPTH,
+            max_data_size_in_fields: N::MAX_DATA_SIZE_IN_FIELDS,
+            require_private_owner: N::REQUIRE_PRIVATE_OWNER,
+            strict_output_registers: N::STRICT_OUTPUT_REGISTERS,
+            require_outputs: N::REQUIRE_OUTPUTS,
+            is_opcode_allowed: N::is_opcode_allowed,
+        }
+    }
+}
+
+/// An error or warning found while validating a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationIssue<N: Network> {
+    /// The name of the definition in which the issue occurs, if any.
+    name: Option<Identifier<N>>,
+    /// The message describing the issue.
+    message: String,
+}
+
+impl<N: Network> ValidationIssue<N> {
+    /// Returns the name of the definition in which the issue occurs, if any.
+    pub const fn name(&self) -> Option<&Identifier<N>> {
+        self.name.as_ref()
+    }
+
+    /// Returns the message describing the issue.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl<N: Network> Display for ValidationIssue<N> {
+    /// Prints the issue as `'<name>': <message>`, or as `<message>` if it is not in a definition.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "'{name}': {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// A report of every error and warning found while validating a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationReport<N: Network> {
+    /// The errors, which make the program invalid.
+    errors: Vec<ValidationIssue<N>>,
+    /// The warnings, which are legal but likely unintended.
+    warnings: Vec<ValidationIssue<N>>,
+}
+
+impl<N: Network> ValidationReport<N> {
+    /// Returns `true` if the report has no errors. Warnings do not affect validity.
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the errors.
+    pub fn errors(&self) -> &[ValidationIssue<N>] {
+        &self.errors
+    }
+
+    /// Returns the warnings.
+    pub fn warnings(&self) -> &[ValidationIssue<N>] {
+        &self.warnings
+    }
+}
+
+impl<N: Network> Display for ValidationReport<N> {
+    /// Prints each error and warning on its own line, followed by a summary line.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for error in &self.errors {
+            writeln!(f, "error: {error}")?;
+        }
+        for warning in &self.warnings {
+            writeln!(f, "warning: {warning}")?;
+        }
+        write!(f, "{} error(s), {} warning(s)", self.errors.len(), self.warnings.len())
+    }
+}
+
+impl<N: Network> Program<N> {
+    /// Runs every structural check on the program, and returns a report of all errors and warnings found,
+    /// rather than stopping at the first error.
+    ///
+    /// The errors are the violations of the network bounds (see `validate_against_network`),
+    /// and the references to undefined definitions. The warnings are the lint findings of warning severity.
+    pub fn validation_report(&self) -> ValidationReport<N> {
+        // Collect the errors.
+        let mut errors = self.network_violations(&NetworkLimits::of::<N>());
+        for (function_name, names) in self.unresolved_references() {
+            for name in names {
+                errors.push(ValidationIssue {
+                    name: Some(function_name),
+                    message: format!("References '{name}', which is not defined"),
+                });
+            }
+        }
+
+        // Collect the warnings.
+        let warnings = self
+            .lint(&LintRules::all())
+            .into_iter()
+            .filter(|finding| finding.severity() == LintSeverity::Warning)
+            .map(|finding| {
+                let (name, index) = finding.location();
+                let message = match index {
+                    Some(index) => format!("[{}] (instruction {index}) {}", finding.rule().id(), finding.message()),
+                    None => format!("[{}] {}", finding.rule().id(), finding.message()),
+                };
+                ValidationIssue { name: Some(*name), message }
+            })
+            .collect();
+
+        ValidationReport { errors, warnings }
+    }
+
+    /// Checks the program against every bound and rule defined by the network, and reports all violations at once.
+    ///
+    /// The checked bounds are the number of definitions and functions,
+    /// the number of inputs and outputs of each closure,
+    /// the number of inputs, instructions, outputs, and output records of each function,
+    /// the number of operands of each instruction, the payload size of each record,
+    /// and the nesting depth of each struct.
+    /// The checked rules are those enforced when adding a definition to the program, i.e. the allowed opcodes,
+    /// `N::REQUIRE_OUTPUTS`, `N::STRICT_OUTPUT_REGISTERS`, and `N::REQUIRE_PRIVATE_OWNER`.
+    ///
+    /// # Errors
+    /// This method will halt if the program violates any bound, listing each violation in the error.
+    pub fn validate_against_network(&self) -> Result<()> {
+        self.validate_against(&NetworkLimits::of::<N>())
+    }
+
+    /// Checks the program against the given limits, and reports all violations at once
+    /// (see `validate_against_network`).
+    ///
+    /// # Errors
+    /// This method will halt if the program violates any limit, listing each violation in the error.
+    pub(crate) fn validate_against(&self, limits: &NetworkLimits) -> Result<()> {
+        let violations = self.network_violations(limits);
+        match violations.is_empty() {
+            true => Ok(()),
+            false => bail!(
+                "Program '{}' violates {} network limit(s):\n{}",
+                self.id,
+                violations.len(),
+                violations.iter().map(|violation| format!("  - {}", violation.message)).collect::<Vec<_>>().join("\n")
+            ),
+        }
+    }
+
+    /// Returns every violation of the given limits (see `validate_against_network`).
+    fn network_violations(&self, limits: &NetworkLimits) -> Vec<ValidationIssue<N>> {
+        let mut violations = Vec::new();
+
+        // Check the number of definitions.
+        if self.identifiers.len() > limits.max_program_definitions {
+            violations.push(ValidationIssue {
+                name: None,
+                message: format!(
+                    "Program has {} definitions, which exceeds the maximum of {}",
+                    self.identifiers.len(),
+                    limits.max_program_definitions
+                ),
+            });
+        }
+
+        // Check the number of functions.
+        if self.functions.len() > limits.max_functions {
+            violations.push(ValidationIssue {
+                name: None,
+                message: format!(
+                    "Program has {} functions, which exceeds the maximum of {}",
+                    self.functions.len(),
+                    limits.max_functions
+                ),
+            });
+        }
+
+        // Check the bounds of each closure.
+        for (closure_name, closure) in &self.closures {
+            for (kind, count, max) in [
+                ("inputs", closure.inputs().len(), limits.max_inputs),
+                ("outputs", closure.outputs().len(), limits.max_outputs),
+            ] {
+                if count > max {
+                    violations.push(ValidationIssue {
+                        name: Some(*closure_name),
+                        message: format!(
+                            "Closure '{closure_name}' has {count} {kind}, which exceeds the maximum of {max}"
+                        ),
+                    });
+                }
+            }
+        }
+
+        // Check the bounds and rules of each function.
+        for (function_name, function) in &self.functions {
+            // A helper to record a violation in the function.
+            let mut violation =
+                |message: String| violations.push(ValidationIssue { name: Some(*function_name), message });
+            // A helper to check that a count is within the given bound.
+            let mut check_count = |kind: &str, count: usize, max: usize| {
+                if count > max {
+                    violation(format!(
+                        "Function '{function_name}' has {count} {kind}, which exceeds the maximum of {max}"
+                    ));
+                }
+            };
+            check_count("inputs", function.inputs().len(), limits.max_inputs);
+            check_count("instructions", function.instructions().len(), limits.max_instructions);
+            check_count("outputs", function.outputs().len(), limits.max_outputs);
+            check_count("output records", Self::num_output_records(function), limits.max_output_records);
+            // Check the number of operands of each instruction, and the rules checked by `Program::add_function`.
+            let checks = [
+                Self::check_operand_counts(function, limits.max_operands),
+                Self::check_output_not_input(function, limits.strict_output_registers),
+                Self::check_opcodes_allowed(function, limits.is_opcode_allowed),
+                Self::check_has_outputs(function, limits.require_outputs),
+                Self::check_output_literals(function),
+            ];
+            for error in checks.into_iter().filter_map(Result::err) {
+                violation(error.to_string());
+            }
+        }
+
+        // Check the owner and payload size of each record.
+        for (record_name, record) in &self.records {
+            let checks = [
+                Self::check_record_owner(record, limits.require_private_owner),
+                self.check_record_size(record, limits.max_data_size_in_fields),
+            ];
+            for error in checks.into_iter().filter_map(Result::err) {
+                violations.push(ValidationIssue { name: Some(*record_name), message: error.to_string() });
+            }
+        }
+
+        // Check the nesting depth of each struct.
+        for struct_name in self.structs.keys() {
+            let message = match self.plaintext_depth(&PlaintextType::Struct(*struct_name)) {
+                Ok(depth) if depth > limits.max_data_depth => format!(
+                    "Struct '{struct_name}' has a depth of {depth}, which exceeds the maximum of {}",
+                    limits.max_data_depth
+                ),
+                Ok(..) => continue,
+                Err(error) => error.to_string(),
+            };
+            violations.push(ValidationIssue { name: Some(*struct_name), message });
+        }
+
+        violations
+    }
+
+    /// Returns the nesting depth of the given plaintext type, where a literal has a depth of 0.
+    fn plaintext_depth(&self, plaintext_type: &PlaintextType<N>) -> Result<usize> {
+        match plaintext_type {
+            PlaintextType::Literal(..) => Ok(0),
+            PlaintextType::Struct(struct_name) => {
+                // Retrieve the struct.
+                let struct_ =
+                    self.structs.get(struct_name).ok_or_else(|| anyhow!("Struct '{struct_name}' is not defined."))?;
+                // Compute the maximum depth of the struct members.
+                struct_.members().values().try_fold(1usize, |depth, member_type| {
+                    Ok(depth.max(self.plaintext_depth(member_type)?.saturating_add(1)))
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_validate_against_network() -> Result<()> {
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r"
+program limits.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        )?;
+        // Ensure a valid program passes.
+        program.validate_against_network()?;
+
+        // Note: The limits are enforced when adding definitions, so the violating definitions are inserted directly.
+        // Exceed the maximum number of functions.
+        for i in 0..CurrentNetwork::MAX_FUNCTIONS {
+            let function = Function::<CurrentNetwork>::from_str(&format!(
+                "function extra_{i}:\n    input r0 as field.private;\n    add r0 r0 into r1;\n    output r1 as field.private;"
+            ))?;
+            program.functions.insert(*function.name(), function);
+        }
+        // Exceed the maximum struct depth.
+        for i in 0..CurrentNetwork::MAX_DATA_DEPTH {
+            let member_type = match i {
+                0 => "point".to_string(),
+                _ => format!("nested_{}", i - 1),
+            };
+            let struct_ =
+                Struct::<CurrentNetwork>::from_str(&format!("struct nested_{i}:\n    inner as {member_type};"))?;
+            program.structs.insert(*struct_.name(), struct_);
+        }
+
+        // Ensure both violations are reported.
+        let error = program.validate_against_network().unwrap_err().to_string();
+        assert!(error.contains("violates 2 network limit(s)"), "{error}");
+        let num_functions = CurrentNetwork::MAX_FUNCTIONS + 1;
+        assert!(error.contains(&format!("Program has {num_functions} functions")), "{error}");
+        let depth = CurrentNetwork::MAX_DATA_DEPTH + 1;
+        assert!(error.contains(&format!("Struct 'nested_{}' has a depth of {depth}", depth - 2)), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_against_network_rules() -> Result<()> {
+        // Initialize limits that are stricter than those of the current network.
+        let limits = NetworkLimits {
+            max_program_definitions: 8,
+            strict_output_registers: true,
+            require_outputs: true,
+            is_opcode_allowed: |opcode| opcode != "mul",
+            ..NetworkLimits::of::<CurrentNetwork>()
+        };
+
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("rules.aleo")?)?;
+        // Use the `mul` opcode, which the limits disallow.
+        program.add_function(Function::from_str(
+            "function multiply:\n    input r0 as field.private;\n    mul r0 r0 into r1;\n    output r1 as field.private;",
+        )?)?;
+        // Output an input register, which the limits disallow.
+        program.add_function(Function::from_str(
+            "function echo:\n    input r0 as field.private;\n    add r0 r0 into r1;\n    output r0 as field.private;",
+        )?)?;
+        // Declare no outputs, which the limits disallow.
+        program.add_function(Function::from_str(
+            "function silent:\n    input r0 as field.private;\n    add r0 r0 into r1;",
+        )?)?;
+        // Note: The owner is enforced when adding a record, so the violating record is inserted directly.
+        // Declare a public record owner, which the current network disallows.
+        let record = RecordType::<CurrentNetwork>::from_str(
+            "record token:\n    owner as address.public;\n    gates as u64.private;",
+        )?;
+        program.identifiers.insert(*record.name(), ProgramDefinition::Record);
+        program.records.insert(*record.name(), record);
+        // Exceed the maximum number of definitions.
+        let num_definitions = program.identifiers.len();
+        for i in num_definitions..=limits.max_program_definitions {
+            let name = Identifier::from_str(&format!("G{i}"))?;
+            program.identifiers.insert(name, ProgramDefinition::Global);
+            program.globals.insert(name, (PlaintextType::from_str("u8")?, Plaintext::from_str("1u8")?));
+        }
+
+        // Ensure the current network only reports the record owner.
+        let error = program.validate_against_network().unwrap_err().to_string();
+        assert!(error.contains("violates 1 network limit(s)"), "{error}");
+        // Ensure every violation of the limits is reported.
+        let error = program.validate_against(&limits).unwrap_err().to_string();
+        assert!(error.contains("violates 5 network limit(s)"), "{error}");
+        let num_definitions = limits.max_program_definitions + 1;
+        assert!(error.contains(&format!("Program has {num_definitions} definitions")), "{error}");
+        assert!(error.contains("Opcode 'mul' in function 'multiply' is not allowed on this network"), "{error}");
+        assert!(error.contains("Output register 'r0' in function 'echo' is also an input"), "{error}");
+        assert!(error.contains("Function 'silent' has no outputs"), "{error}");
+        assert!(error.contains("Record 'token' declares its owner as 'address.public'"), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_validation_report() -> Result<()> {
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r"
+program report.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+function compute:
+    input r0 as point.private;
+    add r0.x r0.y into r1;
+    output r1 as field.private;",
+        )?;
+        // Ensure a valid program has no errors or warnings.
+        let report = program.validation_report();
+        assert!(report.is_ok());
+        assert!(report.errors().is_empty() && report.warnings().is_empty(), "{report}");
+
+        // Add a function with a dead register, which is a warning.
+        program.add_function(Function::<CurrentNetwork>::from_str(
+            r"
+function dead:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    add r0 r1 into r2;
+    output r1 as field.private;",
+        )?)?;
+        // Exceed the maximum struct depth, which is an error.
+        // Note: The limits are enforced when adding definitions, so the violating definitions are inserted directly.
+        for i in 0..CurrentNetwork::MAX_DATA_DEPTH {
+            let member_type = match i {
+                0 => "point".to_string(),
+                _ => format!("nested_{}", i - 1),
+            };
+            let struct_ =
+                Struct::<CurrentNetwork>::from_str(&format!("struct nested_{i}:\n    inner as {member_type};"))?;
+            program.structs.insert(*struct_.name(), struct_);
+        }
+
+        // Ensure both the error and the warning are reported, with their locations.
+        let report = program.validation_report();
+        assert!(!report.is_ok());
+        assert_eq!(report.errors().len(), 1, "{report}");
+        let error = &report.errors()[0];
+        assert_eq!(
+            error.name(),
+            Some(&Identifier::from_str(&format!("nested_{}", CurrentNetwork::MAX_DATA_DEPTH - 1))?)
+        );
+        assert!(error.message().contains("exceeds the maximum"), "{error}");
+        assert_eq!(report.warnings().len(), 1, "{report}");
+        let warning = &report.warnings()[0];
+        assert_eq!(warning.name(), Some(&Identifier::from_str("dead")?));
+        assert_eq!(warning.message(), "[dead-register] (instruction 1) Register 'r2' is assigned but never read");
+        assert!(report.to_string().ends_with("1 error(s), 1 warning(s)"), "{report}");
+        Ok(())
+    }
+}
@@ -304,7 +304,7 @@ function compute:
                         "credits.aleo",
                         "transfer",
                         [
-                            Value::<CurrentNetwork>::Record(record),
+                            Value::<CurrentNetwork>::from(record),
                             Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
                             Value::<CurrentNetwork>::from_str("1u64").unwrap(),
                         ]